@@ -69,6 +69,32 @@ impl InkE2ETest {
             }
         };
 
+        let test_body = if self.test.config.dump_storage_on_failure() {
+            let output = match fn_return_type {
+                syn::ReturnType::Default => quote! { () },
+                syn::ReturnType::Type(_, ret_type) => quote! { #ret_type },
+            };
+            quote! {{
+                let __outcome: ::std::thread::Result<#output> =
+                    ::ink_e2e::CatchUnwind::new(async { #block }).await;
+                let __failed = match &__outcome {
+                    Ok(__ret) => ::ink_e2e::TestOutcome::is_failure(__ret),
+                    Err(_) => true,
+                };
+                if __failed {
+                    ::ink_e2e::log_contracts_storage::<#environment, _>(&mut client).await;
+                }
+                match __outcome {
+                    Ok(__ret) => __ret,
+                    Err(__payload) => ::std::panic::resume_unwind(__payload),
+                }
+            }}
+        } else {
+            quote! {{
+                #block
+            }}
+        };
+
         quote! {
             #( #attrs )*
             #[test]
@@ -89,9 +115,7 @@ impl InkE2ETest {
                 let run = async {
                     #client_building
 
-                    let __ret = {
-                        #block
-                    };
+                    let __ret = #test_body;
                     __ret
                 };
 
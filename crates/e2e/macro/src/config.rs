@@ -95,6 +95,9 @@ pub struct E2EConfig {
     /// The type of the architecture that should be used to run test.
     #[darling(default)]
     backend: Backend,
+    /// Log the storage of all instantiated contracts if the test fails.
+    #[darling(default)]
+    dump_storage_on_failure: bool,
 }
 
 impl E2EConfig {
@@ -107,6 +110,11 @@ impl E2EConfig {
     pub fn backend(&self) -> Backend {
         self.backend.clone()
     }
+
+    /// Whether the storage of all instantiated contracts is logged if the test fails.
+    pub fn dump_storage_on_failure(&self) -> bool {
+        self.dump_storage_on_failure
+    }
 }
 
 #[cfg(test)]
@@ -133,6 +141,19 @@ mod tests {
         );
 
         assert_eq!(config.backend(), Backend::RuntimeOnly(RuntimeOnly::Default));
+        assert!(!config.dump_storage_on_failure());
+    }
+
+    #[test]
+    fn config_works_dump_storage_on_failure() {
+        let input = quote! {
+            backend(runtime_only),
+            dump_storage_on_failure,
+        };
+        let config =
+            E2EConfig::from_list(&NestedMeta::parse_meta_list(input).unwrap()).unwrap();
+
+        assert!(config.dump_storage_on_failure());
     }
 
     #[test]
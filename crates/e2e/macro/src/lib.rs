@@ -74,6 +74,18 @@ use syn::Result;
 /// in our documentation for more details.
 /// For a full example [see here](https://github.com/use-ink/ink-examples/tree/v5.x.x/e2e-runtime-only-backend).
 ///
+/// ### Dumping Storage on Failure
+///
+/// If the test fails, i.e. it panics or returns an `Err`, the storage of all contracts
+/// that were instantiated during the test can be logged:
+///
+/// ```ignore
+/// #[ink_e2e::test(dump_storage_on_failure)]
+/// ```
+///
+/// The raw value of each storage cell is logged hex encoded, labelled by the storage
+/// field it belongs to according to the contract's metadata.
+///
 /// # Example
 ///
 /// ```
@@ -27,6 +27,18 @@ where
         amount: BalanceOf<T::Runtime>,
     ) -> Result<BalanceOf<T::Runtime>, DispatchError>;
 
+    /// Set the free balance of an account, returning the balance actually set.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the account to set the balance of.
+    /// * `amount` - The new free balance.
+    fn set_balance(
+        &mut self,
+        address: &AccountIdFor<T::Runtime>,
+        amount: BalanceOf<T::Runtime>,
+    ) -> BalanceOf<T::Runtime>;
+
     /// Return the free balance of an account.
     ///
     /// # Arguments
@@ -53,6 +65,16 @@ where
         })
     }
 
+    fn set_balance(
+        &mut self,
+        address: &AccountIdFor<T::Runtime>,
+        amount: BalanceOf<T::Runtime>,
+    ) -> BalanceOf<T::Runtime> {
+        self.execute_with(|| {
+            pallet_balances::Pallet::<T::Runtime>::set_balance(address, amount)
+        })
+    }
+
     fn free_balance(
         &mut self,
         address: &AccountIdFor<T::Runtime>,
@@ -79,4 +101,13 @@ mod test {
             balance + 100
         );
     }
+
+    #[test]
+    fn set_balance_works() {
+        let mut sandbox = DefaultSandbox::default();
+        let account = DefaultSandbox::default_actor();
+
+        assert_eq!(sandbox.set_balance(&account, 1_234), 1_234);
+        assert_eq!(sandbox.free_balance(&account), 1_234);
+    }
 }
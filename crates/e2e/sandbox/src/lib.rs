@@ -76,6 +76,9 @@ pub type RuntimeOf<S> = <S as Sandbox>::Runtime;
 /// Alias for the runtime event of a sandbox.
 pub type RuntimeEventOf<S> = <RuntimeOf<S> as frame_system::Config>::RuntimeEvent;
 
+/// A snapshot of the storage of a sandbox, see [`Sandbox::take_snapshot`].
+pub type Snapshot = sp_state_machine::InMemoryBackend<sp_core::Blake2Hasher>;
+
 /// Sandbox defines the API of a sandboxed runtime.
 pub trait Sandbox {
     /// The runtime associated with the sandbox.
@@ -87,6 +90,12 @@ pub trait Sandbox {
     /// Dry run an action without modifying the storage.
     fn dry_run<T>(&mut self, action: impl FnOnce(&mut Self) -> T) -> T;
 
+    /// Take a snapshot of the storage, including all changes made so far.
+    fn take_snapshot(&mut self) -> Snapshot;
+
+    /// Restore the storage to the given `snapshot`, discarding all changes made since.
+    fn restore_snapshot(&mut self, snapshot: Snapshot);
+
     /// Register an extension.
     fn register_extension<E: Any + Extension>(&mut self, ext: E);
 
@@ -259,6 +259,17 @@ mod construct_runtime {
             result
         }
 
+        fn take_snapshot(&mut self) -> $crate::Snapshot {
+            self.ext.commit_all().expect("Failed to commit changes");
+            self.ext.as_backend()
+        }
+
+        fn restore_snapshot(&mut self, snapshot: $crate::Snapshot) {
+            // Ensure, that there are no pending changes that would affect the restored backend.
+            self.ext.commit_all().expect("Failed to commit changes");
+            self.ext.backend = snapshot;
+        }
+
         fn register_extension<E: ::core::any::Any + $crate::Extension>(&mut self, ext: E) {
             self.ext.register_extension(ext);
         }
@@ -34,11 +34,13 @@ use ink_env::{
     Environment,
 };
 use jsonrpsee::core::async_trait;
+use pallet_contracts::ContractExecResult;
 use scale::{
     Decode,
     Encode,
 };
 use sp_weights::Weight;
+use std::path::Path;
 use subxt::dynamic::Value;
 
 /// Full E2E testing backend: combines general chain API and contract-specific operations.
@@ -74,6 +76,19 @@ pub trait ChainBackend {
         account: Self::AccountId,
     ) -> Result<Self::Balance, Self::Error>;
 
+    /// Sets the free balance of `account` to `balance`, creating the account if it
+    /// doesn't exist yet.
+    ///
+    /// The sandbox sets the balance directly. A node dispatches
+    /// `Balances::force_set_balance` via `Sudo`, signed by `alice`, which is the sudo
+    /// key of development chains. An error is returned if the node has no `Sudo`
+    /// pallet or the call is rejected.
+    async fn set_balance(
+        &mut self,
+        account: Self::AccountId,
+        balance: Self::Balance,
+    ) -> Result<(), Self::Error>;
+
     /// Executes a runtime call `call_name` for the `pallet_name`.
     /// The `call_data` is a `Vec<Value>`.
     ///
@@ -95,6 +110,32 @@ pub trait ChainBackend {
         call_name: &'a str,
         call_data: Vec<Value>,
     ) -> Result<Self::EventLog, Self::Error>;
+
+    /// Dry runs a runtime call `call_name` for the `pallet_name`, without altering the
+    /// state of the chain.
+    ///
+    /// Takes the same arguments as [`ChainBackend::runtime_call`]. The returned
+    /// [`RuntimeCallDryRunResult`] contains the decoded outcome of the call and its
+    /// weight.
+    async fn runtime_call_dry_run<'a>(
+        &mut self,
+        origin: &Keypair,
+        pallet_name: &'a str,
+        call_name: &'a str,
+        call_data: Vec<Value>,
+    ) -> Result<RuntimeCallDryRunResult, Self::Error>;
+}
+
+/// Result of [`ChainBackend::runtime_call_dry_run`].
+#[derive(Debug)]
+pub struct RuntimeCallDryRunResult {
+    /// The weight of the call.
+    ///
+    /// The sandbox reports the weight actually consumed, while a node only reports the
+    /// weight declared by the call before its execution.
+    pub weight: Weight,
+    /// The outcome of the call, with a failure decoded against the runtime metadata.
+    pub result: Result<(), subxt::error::DispatchError>,
 }
 
 /// Contract-specific operations.
@@ -136,6 +177,99 @@ pub trait ContractsBackend<E: Environment> {
         InstantiateBuilder::new(self, caller, contract_name, constructor)
     }
 
+    /// Start building an instantiate call for a pre-built contract, using a builder
+    /// pattern.
+    ///
+    /// The code is read from the build artifact at `artifact` instead of the contracts
+    /// built from source. The artifact is either a `.wasm` file or a `.contract` bundle.
+    /// It is registered under its file name, replacing any contract of the same name.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut constructor = FlipperRef::new(false);
+    /// let contract = client
+    ///     .instantiate_from_artifact(
+    ///         Path::new("fixtures/flipper.contract"),
+    ///         &ink_e2e::alice(),
+    ///         &mut constructor,
+    ///     )
+    ///     .submit()
+    ///     .await
+    ///     .expect("instantiate failed");
+    /// ```
+    fn instantiate_from_artifact<
+        'a,
+        Contract: Clone,
+        Args: Send + Clone + Encode + Sync,
+        R,
+    >(
+        &'a mut self,
+        artifact: &'a Path,
+        caller: &'a Keypair,
+        constructor: &'a mut CreateBuilderPartial<E, Contract, Args, R>,
+    ) -> InstantiateBuilder<'a, E, Contract, Args, R, Self>
+    where
+        Self: Sized + BuilderClient<E>,
+    {
+        let contract_name = artifact
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .unwrap_or_else(|| {
+                panic!("Invalid contract artifact path '{}'", artifact.display())
+            });
+        self.register_contract(artifact);
+        InstantiateBuilder::new(self, caller, contract_name, constructor)
+    }
+
+    /// Start building an instantiate call for code which was already uploaded, using a
+    /// builder pattern.
+    ///
+    /// The code is referred to by the [`UploadResult`] of a previous
+    /// [`ContractsBackend::upload`], hence the same code can be instantiated any number
+    /// of times without uploading it again. Each instance gets a unique salt unless one
+    /// is specified via [`InstantiateBuilder::salt`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let code = client
+    ///     .upload("flipper", &ink_e2e::alice())
+    ///     .submit()
+    ///     .await
+    ///     .expect("upload failed");
+    ///
+    /// let mut constructor = FlipperRef::new(false);
+    /// let first = client
+    ///     .instantiate_from_code_hash(&code, &ink_e2e::alice(), &mut constructor)
+    ///     .submit()
+    ///     .await
+    ///     .expect("instantiate failed");
+    /// let second = client
+    ///     .instantiate_from_code_hash(&code, &ink_e2e::alice(), &mut constructor)
+    ///     .salt(vec![42])
+    ///     .submit()
+    ///     .await
+    ///     .expect("instantiate failed");
+    /// ```
+    fn instantiate_from_code_hash<
+        'a,
+        Contract: Clone,
+        Args: Send + Clone + Encode + Sync,
+        R,
+    >(
+        &'a mut self,
+        code: &'a UploadResult<E, Self::EventLog>,
+        caller: &'a Keypair,
+        constructor: &'a mut CreateBuilderPartial<E, Contract, Args, R>,
+    ) -> InstantiateBuilder<'a, E, Contract, Args, R, Self>
+    where
+        Self: Sized + BuilderClient<E>,
+    {
+        InstantiateBuilder::new(self, caller, &code.contract_name, constructor)
+            .with_code_hash(code.code_hash)
+    }
+
     /// Start building an upload call.
     /// # Example
     ///
@@ -235,6 +369,23 @@ pub trait BuilderClient<E: Environment>: ContractsBackend<E> {
     where
         CallBuilderFinal<E, Args, RetType>: Clone;
 
+    /// Executes a bare `call` like [`BuilderClient::bare_call`], additionally returning
+    /// the result of the executed call, i.e. its output and the gas it consumed, if the
+    /// backend can observe it.
+    ///
+    /// The sandbox returns the result of the executed call. A node does not return the
+    /// output of a submitted extrinsic, hence `None` is returned for it.
+    async fn bare_call_with_result<Args: Sync + Encode + Clone, RetType: Send + Decode>(
+        &mut self,
+        caller: &Keypair,
+        message: &CallBuilderFinal<E, Args, RetType>,
+        value: E::Balance,
+        gas_limit: Weight,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<(Self::EventLog, Option<ContractExecResult<E::Balance, ()>>), Self::Error>
+    where
+        CallBuilderFinal<E, Args, RetType>: Clone;
+
     /// Executes a dry-run `call`.
     ///
     /// Returns the result of the dry run, together with the decoded return value of the
@@ -280,15 +431,15 @@ pub trait BuilderClient<E: Environment>: ContractsBackend<E> {
     /// This function extracts the metadata of the contract at the file path
     /// `target/ink/$contract_name.contract`.
     ///
-    /// Calling this function multiple times should be idempotent, the contract is
-    /// newly instantiated each time using a unique salt. No existing contract
-    /// instance is reused!
+    /// The address of the contract is derived from the given `salt`. Instantiating
+    /// the same contract with the same constructor arguments and salt twice fails.
     async fn bare_instantiate<Contract: Clone, Args: Send + Sync + Encode + Clone, R>(
         &mut self,
         contract_name: &str,
         caller: &Keypair,
         constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
         value: E::Balance,
+        salt: Vec<u8>,
         gas_limit: Weight,
         storage_deposit_limit: Option<E::Balance>,
     ) -> Result<BareInstantiationResult<E, Self::EventLog>, Self::Error>;
@@ -304,6 +455,67 @@ pub trait BuilderClient<E: Environment>: ContractsBackend<E> {
         caller: &Keypair,
         constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
         value: E::Balance,
+        salt: Vec<u8>,
         storage_deposit_limit: Option<E::Balance>,
     ) -> Result<InstantiateDryRunResult<E>, Self::Error>;
+
+    /// Bare instantiate call for code which was already uploaded with the hash
+    /// `code_hash`. This function does not perform a dry-run, and user is expected to
+    /// provide the gas limit.
+    ///
+    /// The instance is recorded under `contract_name`, which must be the name of the
+    /// contract the code was uploaded from.
+    async fn bare_instantiate_from_code_hash<
+        Contract: Clone,
+        Args: Send + Sync + Encode + Clone,
+        R,
+    >(
+        &mut self,
+        contract_name: &str,
+        code_hash: E::Hash,
+        caller: &Keypair,
+        constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
+        value: E::Balance,
+        salt: Vec<u8>,
+        gas_limit: Weight,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<BareInstantiationResult<E, Self::EventLog>, Self::Error>;
+
+    /// Dry run the instantiation of code which was already uploaded with the hash
+    /// `code_hash`.
+    async fn bare_instantiate_from_code_hash_dry_run<
+        Contract: Clone,
+        Args: Send + Sync + Encode + Clone,
+        R,
+    >(
+        &mut self,
+        code_hash: E::Hash,
+        caller: &Keypair,
+        constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
+        value: E::Balance,
+        salt: Vec<u8>,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<InstantiateDryRunResult<E>, Self::Error>;
+
+    /// Registers the contract build artifact at `artifact` under its file name, so that
+    /// it can be referred to by that name in subsequent calls.
+    fn register_contract(&mut self, artifact: &Path);
+
+    /// Loads the Wasm code of the contract `contract_name`.
+    fn load_code(&self, contract_name: &str) -> Vec<u8>;
+
+    /// Returns the name and account id of every contract instantiated by this client,
+    /// in the order of instantiation.
+    fn instantiated_contracts(&self) -> Vec<(String, E::AccountId)>;
+
+    /// Reads the storage of the contract `contract_name` at `account_id`.
+    ///
+    /// Returns the raw value of every storage cell known from the contract's storage
+    /// layout, labelled by the storage field it belongs to. Cells which are not set
+    /// are omitted.
+    async fn contract_storage(
+        &mut self,
+        contract_name: &str,
+        account_id: &E::AccountId,
+    ) -> Result<Vec<(String, Vec<u8>)>, Self::Error>;
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use ink_env::Environment;
+use pallet_contracts::ContractExecResult;
 use scale::{
     Decode,
     Encode,
@@ -21,7 +22,14 @@ use sp_weights::Weight;
 
 use crate::{
     backend::BuilderClient,
-    builders::CreateBuilderPartial,
+    builders::{
+        constructor_exec_input,
+        CreateBuilderPartial,
+    },
+    client_utils::{
+        contract_address,
+        salt,
+    },
     CallBuilderFinal,
     CallDryRunResult,
     CallResult,
@@ -33,6 +41,11 @@ use crate::{
 
 use super::Keypair;
 
+/// The default margin added to the gas required by the dry-run of a call, in per cent.
+///
+/// See [`CallBuilder::gas_limit_from_dry_run`].
+pub const DEFAULT_GAS_MARGIN: u64 = 20;
+
 /// Allows to build an end-to-end call using a builder pattern.
 pub struct CallBuilder<'a, E, Args, RetType, B>
 where
@@ -117,6 +130,36 @@ where
         self
     }
 
+    /// Sizes the gas limit of the submitted call from the gas required by its dry-run,
+    /// increased by a margin of [`DEFAULT_GAS_MARGIN`] per cent.
+    ///
+    /// Useful for messages whose gas usage varies, e.g. heavy messages in CI where a
+    /// tight estimate leads to flaky out of gas failures.
+    ///
+    /// # Notes
+    ///
+    /// Overwrites any values specified for `gas_limit` and `extra_gas_portion`. Use
+    /// [`CallBuilder::gas_limit_from_dry_run_with_margin`] to override the margin.
+    pub fn gas_limit_from_dry_run(&mut self) -> &mut Self {
+        self.gas_limit_from_dry_run_with_margin(DEFAULT_GAS_MARGIN)
+    }
+
+    /// Sizes the gas limit of the submitted call from the gas required by its dry-run,
+    /// increased by a margin of `per_cent`.
+    ///
+    /// # Example
+    ///
+    /// With dry run gas estimate of `100` units and a margin of `50`% specified, the
+    /// set gas limit becomes `150` units.
+    ///
+    /// # Notes
+    ///
+    /// Overwrites any values specified for `gas_limit` and `extra_gas_portion`.
+    pub fn gas_limit_from_dry_run_with_margin(&mut self, per_cent: u64) -> &mut Self {
+        self.gas_limit = None;
+        self.extra_gas_portion(per_cent)
+    }
+
     /// Specify the max amount of funds that can be charged for storage.
     pub fn storage_deposit_limit(
         &mut self,
@@ -188,6 +231,127 @@ where
         )
         .await
     }
+
+    /// Submit the call for the on-chain execution and assert that the execution agrees
+    /// with its dry-run.
+    ///
+    /// The call is submitted with the gas limit estimated by the dry-run, increased by
+    /// `extra_gas_portion` if specified. A `gas_limit` specified on the builder is
+    /// ignored. Asserts that:
+    ///
+    /// - the dry-run returned a value of the message's return type,
+    /// - the submitted call succeeded if and only if the dry-run did not revert,
+    /// - the submitted call returned the same decoded value as the dry-run,
+    /// - the submitted call did not consume more gas than the dry-run estimate times the
+    ///   margin.
+    ///
+    /// # Acceptable Divergence
+    ///
+    /// A node does not return the output of a submitted call, hence with a node only
+    /// whether the call reverted is compared; the return value and the consumed gas are
+    /// compared with the sandbox, see [`BuilderClient::bare_call_with_result`]. The
+    /// return value of the returned [`CallResult`] is the one of the dry-run. If both
+    /// executions reverted on a node, the error of the submitted call is returned, as
+    /// [`CallBuilder::submit`] does.
+    ///
+    /// The dry-run is executed on top of the latest block, while the submitted call is
+    /// executed in a later block. Messages which depend on the block (e.g. its number
+    /// or timestamp), or on state modified by other transactions in between, may
+    /// diverge legitimately and should not be checked using this function.
+    ///
+    /// # Panics
+    ///
+    /// If any of the above assertions does not hold.
+    pub async fn assert_dry_run_matches_submit(
+        &mut self,
+    ) -> Result<CallResult<E, RetType, B::EventLog>, B::Error>
+    where
+        CallBuilderFinal<E, Args, RetType>: Clone,
+        RetType: PartialEq + core::fmt::Debug,
+        B::Error: core::fmt::Debug,
+    {
+        let dry_run = self.dry_run().await?;
+        if dry_run.is_bare_trap() {
+            panic!(
+                "the dry-run trapped, there is no return value to compare: {:?}",
+                dry_run.exec_result.result
+            )
+        }
+        if let Err(lang_err) = dry_run.message_result() {
+            panic!("the dry-run failed with a `LangError`: {lang_err:?}")
+        }
+        let dry_run_reverted = dry_run.revert_data().is_some();
+
+        let gas_required = dry_run.exec_result.gas_required;
+        let gas_limit = calculate_weight(
+            gas_required.proof_size(),
+            gas_required.ref_time(),
+            self.extra_gas_portion,
+        );
+        let call_result = B::bare_call_with_result(
+            self.client,
+            self.caller,
+            self.message,
+            self.value,
+            gas_limit,
+            self.storage_deposit_limit,
+        )
+        .await;
+
+        match call_result {
+            Ok((events, exec_result)) => {
+                match exec_result {
+                    Some(exec_result) => {
+                        assert_call_matches_dry_run(&dry_run, exec_result, gas_limit)
+                    }
+                    None if dry_run_reverted => {
+                        panic!("the dry-run reverted, but the submitted call succeeded")
+                    }
+                    None => (),
+                }
+                Ok(CallResult { dry_run, events })
+            }
+            Err(err) if dry_run_reverted => Err(err),
+            Err(err) => {
+                panic!(
+                    "the dry-run succeeded, but the submitted call failed with the \
+                    estimated gas limit of {gas_limit:?}: {err:?}"
+                )
+            }
+        }
+    }
+}
+
+/// Asserts that the `exec_result` of a submitted call agrees with its `dry_run`, see
+/// [`CallBuilder::assert_dry_run_matches_submit`].
+fn assert_call_matches_dry_run<E, RetType>(
+    dry_run: &CallDryRunResult<E, RetType>,
+    exec_result: ContractExecResult<E::Balance, ()>,
+    gas_limit: Weight,
+) where
+    E: Environment,
+    RetType: Decode + PartialEq + core::fmt::Debug,
+{
+    let submitted = CallDryRunResult::<E, RetType> {
+        exec_result,
+        _marker: Default::default(),
+    };
+    let gas_consumed = submitted.exec_result.gas_consumed;
+    assert!(
+        gas_consumed.all_lte(gas_limit),
+        "the submitted call consumed {gas_consumed:?}, more than the estimated gas \
+        limit of {gas_limit:?}"
+    );
+    assert_eq!(
+        dry_run.revert_data().is_some(),
+        submitted.revert_data().is_some(),
+        "the dry-run and the submitted call disagree on whether the call reverted"
+    );
+    assert_eq!(
+        dry_run.message_result(),
+        submitted.message_result(),
+        "the submitted call returned a different value than its dry-run"
+    );
 }
 
 /// Allows to build an end-to-end instantiation call using a builder pattern.
@@ -202,8 +366,10 @@ where
     client: &'a mut B,
     caller: &'a Keypair,
     contract_name: &'a str,
+    code_hash: Option<E::Hash>,
     constructor: &'a mut CreateBuilderPartial<E, Contract, Args, R>,
     value: E::Balance,
+    salt: Vec<u8>,
     extra_gas_portion: Option<u64>,
     gas_limit: Option<Weight>,
     storage_deposit_limit: Option<E::Balance>,
@@ -231,20 +397,43 @@ where
             client,
             caller,
             contract_name,
+            code_hash: None,
             constructor,
             value: 0u32.into(),
+            salt: salt(),
             extra_gas_portion: None,
             gas_limit: None,
             storage_deposit_limit: None,
         }
     }
 
+    /// Instantiates the code which was already uploaded with the hash `code_hash`
+    /// instead of uploading the code of the contract.
+    pub(crate) fn with_code_hash(mut self, code_hash: E::Hash) -> Self {
+        self.code_hash = Some(code_hash);
+        self
+    }
+
     /// Provide value with a call
     pub fn value(&mut self, value: E::Balance) -> &mut Self {
         self.value = value;
         self
     }
 
+    /// Specifies the salt used to derive the address of the contract.
+    ///
+    /// **Default value:** a unique salt based on the system time.
+    pub fn salt(&mut self, salt: Vec<u8>) -> &mut Self {
+        self.salt = salt;
+        self
+    }
+
+    /// Replaces the salt with a new unique one, based on the system time.
+    pub fn random_salt(&mut self) -> &mut Self {
+        self.salt = salt();
+        self
+    }
+
     /// Increases the gas limit marginally by a specified percent.
     /// Useful when the message's gas usage depends on the runtime state
     /// and the dry run does not produce an accurate gas estimate.
@@ -290,6 +479,23 @@ where
         self
     }
 
+    /// Returns the address the contract will have once instantiated, without
+    /// submitting anything.
+    ///
+    /// The address is derived from the code hash of the contract, the caller, the
+    /// constructor arguments and the salt, the same way `pallet-contracts` does it.
+    pub fn predict_address(&self) -> E::AccountId {
+        let code_hash = self.code_hash.unwrap_or_else(|| {
+            let code = self.client.load_code(self.contract_name);
+            E::Hash::decode(&mut &sp_core::blake2_256(&code)[..])
+                .expect("the code hash of the environment must be 32 bytes")
+        });
+        let deployer = E::AccountId::decode(&mut &self.caller.public_key().0[..])
+            .expect("the account id of the environment must be 32 bytes");
+        let input_data = constructor_exec_input(self.constructor.clone());
+        contract_address::<E>(&deployer, &code_hash, &input_data, &self.salt)
+    }
+
     /// Submit the instantiate call for the on-chain execution.
     ///
     /// This will automatically run a dry-run call, and use `extra_gas_portion`
@@ -297,15 +503,7 @@ where
     pub async fn submit(
         &mut self,
     ) -> Result<InstantiationResult<E, B::EventLog>, B::Error> {
-        let dry_run = B::bare_instantiate_dry_run(
-            self.client,
-            self.contract_name,
-            self.caller,
-            self.constructor,
-            self.value,
-            self.storage_deposit_limit,
-        )
-        .await?;
+        let dry_run = self.dry_run().await?;
 
         let gas_limit = if let Some(limit) = self.gas_limit {
             limit
@@ -316,16 +514,35 @@ where
             calculate_weight(proof_size, ref_time, self.extra_gas_portion)
         };
 
-        let instantiate_result = B::bare_instantiate(
-            self.client,
-            self.contract_name,
-            self.caller,
-            self.constructor,
-            self.value,
-            gas_limit,
-            self.storage_deposit_limit,
-        )
-        .await?;
+        let instantiate_result = match self.code_hash {
+            Some(code_hash) => {
+                B::bare_instantiate_from_code_hash(
+                    self.client,
+                    self.contract_name,
+                    code_hash,
+                    self.caller,
+                    self.constructor,
+                    self.value,
+                    self.salt.clone(),
+                    gas_limit,
+                    self.storage_deposit_limit,
+                )
+                .await?
+            }
+            None => {
+                B::bare_instantiate(
+                    self.client,
+                    self.contract_name,
+                    self.caller,
+                    self.constructor,
+                    self.value,
+                    self.salt.clone(),
+                    gas_limit,
+                    self.storage_deposit_limit,
+                )
+                .await?
+            }
+        };
 
         Ok(InstantiationResult {
             account_id: instantiate_result.account_id,
@@ -336,15 +553,32 @@ where
 
     /// Dry run the instantiate call.
     pub async fn dry_run(&mut self) -> Result<InstantiateDryRunResult<E>, B::Error> {
-        B::bare_instantiate_dry_run(
-            self.client,
-            self.contract_name,
-            self.caller,
-            self.constructor,
-            self.value,
-            self.storage_deposit_limit,
-        )
-        .await
+        match self.code_hash {
+            Some(code_hash) => {
+                B::bare_instantiate_from_code_hash_dry_run(
+                    self.client,
+                    code_hash,
+                    self.caller,
+                    self.constructor,
+                    self.value,
+                    self.salt.clone(),
+                    self.storage_deposit_limit,
+                )
+                .await
+            }
+            None => {
+                B::bare_instantiate_dry_run(
+                    self.client,
+                    self.contract_name,
+                    self.caller,
+                    self.constructor,
+                    self.value,
+                    self.salt.clone(),
+                    self.storage_deposit_limit,
+                )
+                .await
+            }
+        }
     }
 }
 
@@ -442,3 +676,65 @@ fn calculate_weight(
     }
     Weight::from_parts(ref_time, proof_size)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::DefaultEnvironment;
+    use ink_primitives::MessageResult;
+    use pallet_contracts::{
+        chain_extension::ReturnFlags,
+        ContractResult,
+        ExecReturnValue,
+        StorageDeposit,
+    };
+
+    fn exec_result(
+        value: bool,
+        gas_consumed: Weight,
+    ) -> ContractExecResult<<DefaultEnvironment as Environment>::Balance, ()> {
+        ContractResult {
+            gas_consumed,
+            gas_required: gas_consumed,
+            storage_deposit: StorageDeposit::Charge(0),
+            debug_message: Vec::new(),
+            result: Ok(ExecReturnValue {
+                flags: ReturnFlags::empty(),
+                data: MessageResult::Ok(value).encode(),
+            }),
+            events: None,
+        }
+    }
+
+    fn dry_run(value: bool, gas: Weight) -> CallDryRunResult<DefaultEnvironment, bool> {
+        CallDryRunResult {
+            exec_result: exec_result(value, gas),
+            _marker: Default::default(),
+        }
+    }
+
+    #[test]
+    fn matching_call_passes() {
+        let gas = Weight::from_parts(100, 10);
+        assert_call_matches_dry_run(&dry_run(true, gas), exec_result(true, gas), gas);
+    }
+
+    #[test]
+    #[should_panic(expected = "returned a different value than its dry-run")]
+    fn different_return_value_fails() {
+        let gas = Weight::from_parts(100, 10);
+        assert_call_matches_dry_run(&dry_run(true, gas), exec_result(false, gas), gas);
+    }
+
+    #[test]
+    #[should_panic(expected = "more than the estimated gas limit")]
+    fn gas_consumed_above_limit_fails() {
+        let gas = Weight::from_parts(100, 10);
+        let gas_limit = calculate_weight(10, 100, Some(20));
+        assert_call_matches_dry_run(
+            &dry_run(true, gas),
+            exec_result(true, Weight::from_parts(121, 10)),
+            gas_limit,
+        );
+    }
+}
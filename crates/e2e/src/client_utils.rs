@@ -12,10 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::log_info;
+use crate::{
+    contract_build::load_code_from_artifact,
+    log_info,
+};
+use ink_env::Environment;
+use scale::{
+    Decode,
+    Encode,
+};
+use sp_runtime::traits::TrailingZeroInput;
 use std::{
     collections::BTreeMap,
-    path::PathBuf,
+    path::{
+        Path,
+        PathBuf,
+    },
 };
 
 /// Generate a unique salt based on the system time.
@@ -31,6 +43,23 @@ pub fn salt() -> Vec<u8> {
         .to_vec()
 }
 
+/// Returns the address of the contract that `deployer` instantiates from the code with
+/// `code_hash`, using the encoded constructor `input_data` and `salt`.
+///
+/// This follows the address derivation of the default address generator of
+/// `pallet-contracts`, assuming the chain hashes with BLAKE2-256.
+pub fn contract_address<E: Environment>(
+    deployer: &E::AccountId,
+    code_hash: &E::Hash,
+    input_data: &[u8],
+    salt: &[u8],
+) -> E::AccountId {
+    let entropy = (b"contract_addr_v1", deployer, code_hash, input_data, salt)
+        .using_encoded(sp_core::blake2_256);
+    Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+        .expect("infinite length input; no invalid inputs for type; qed")
+}
+
 /// A registry of contracts that can be loaded.
 pub struct ContractsRegistry {
     contracts: BTreeMap<String, PathBuf>,
@@ -65,10 +94,136 @@ impl ContractsRegistry {
                     self.contracts.keys()
                 )
             );
-        let code = std::fs::read(wasm_path).unwrap_or_else(|err| {
-            panic!("Error loading '{}': {:?}", wasm_path.display(), err)
-        });
+        let code = load_code_from_artifact(wasm_path);
         log_info(&format!("{:?} has {} KiB", contract, code.len() / 1024));
         code
     }
+
+    /// Registers the contract build artifact at `path` under its file name, replacing
+    /// any contract of the same name.
+    pub fn register(&mut self, path: &Path) {
+        let contract_name = path
+            .file_stem()
+            .unwrap_or_else(|| {
+                panic!("Invalid contract artifact path '{}'", path.display())
+            })
+            .to_string_lossy()
+            .replace('-', "_");
+        self.contracts.insert(contract_name, path.to_path_buf());
+    }
+
+    /// Returns the storage cells of the given contract, labelled by the storage field
+    /// they belong to.
+    ///
+    /// The cells are taken from the storage layout in the contract's metadata. If no
+    /// metadata is available, only the cell under the default root key is returned.
+    pub fn storage_cells(&self, contract: &str) -> Vec<(String, Vec<u8>)> {
+        let metadata = self
+            .contracts
+            .get(&contract.replace('-', "_"))
+            .and_then(|path| {
+                // A `.contract` bundle contains the metadata itself.
+                if path.extension().map_or(false, |ext| ext == "contract") {
+                    std::fs::read(path).ok()
+                } else {
+                    std::fs::read(path.with_extension("json")).ok()
+                }
+            })
+            .and_then(|json| serde_json::from_slice::<serde_json::Value>(&json).ok());
+        match metadata {
+            Some(metadata) => storage_cells(&metadata["storage"]),
+            None => vec![(String::from("root"), vec![0u8; 4])],
+        }
+    }
+}
+
+/// Collects the root keys of all storage cells found in the given storage layout.
+fn storage_cells(layout: &serde_json::Value) -> Vec<(String, Vec<u8>)> {
+    fn collect(
+        layout: &serde_json::Value,
+        label: &str,
+        cells: &mut Vec<(String, Vec<u8>)>,
+    ) {
+        match layout {
+            serde_json::Value::Object(map) => {
+                if let Some(root_key) = map
+                    .get("root")
+                    .and_then(|root| root.get("root_key"))
+                    .and_then(|root_key| root_key.as_str())
+                    .and_then(|root_key| impl_serde::serialize::from_hex(root_key).ok())
+                {
+                    if !cells.iter().any(|(_, key)| *key == root_key) {
+                        cells.push((label.to_string(), root_key));
+                    }
+                }
+                if let Some(fields) = map.get("fields").and_then(|f| f.as_array()) {
+                    for field in fields {
+                        let name = field["name"].as_str().unwrap_or_default();
+                        let label = if label == "root" {
+                            name.to_string()
+                        } else {
+                            format!("{label}.{name}")
+                        };
+                        collect(&field["layout"], &label, cells);
+                    }
+                } else {
+                    map.values().for_each(|value| collect(value, label, cells));
+                }
+            }
+            serde_json::Value::Array(values) => {
+                values.iter().for_each(|value| collect(value, label, cells))
+            }
+            _ => {}
+        }
+    }
+
+    let mut cells = Vec::new();
+    collect(layout, "root", &mut cells);
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage_cells_are_labelled_by_field() {
+        let layout = serde_json::json!({
+            "root": {
+                "layout": {
+                    "struct": {
+                        "fields": [
+                            {
+                                "layout": { "leaf": { "key": "0x00000000", "ty": 0 } },
+                                "name": "value"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": { "key": "0x2a000000", "ty": 1 }
+                                        },
+                                        "root_key": "0x2a000000",
+                                        "ty": 2
+                                    }
+                                },
+                                "name": "lazy"
+                            }
+                        ],
+                        "name": "Contract"
+                    }
+                },
+                "root_key": "0x00000000",
+                "ty": 3
+            }
+        });
+
+        assert_eq!(
+            storage_cells(&layout),
+            vec![
+                (String::from("root"), vec![0, 0, 0, 0]),
+                (String::from("lazy"), vec![42, 0, 0, 0]),
+            ]
+        );
+    }
 }
@@ -50,6 +50,32 @@ pub fn build_root_and_contract_dependencies() -> Vec<PathBuf> {
     build_contracts(&contract_manifests)
 }
 
+/// Reads the contract code from the build artifact at `path`.
+///
+/// The artifact is either a `.wasm` file containing the raw code, or a `.contract`
+/// bundle containing the code together with the contract's metadata.
+pub fn load_code_from_artifact(path: &Path) -> Vec<u8> {
+    let artifact = std::fs::read(path)
+        .unwrap_or_else(|err| panic!("Error loading '{}': {:?}", path.display(), err));
+    if path.extension().map_or(false, |ext| ext == "contract") {
+        let bundle: serde_json::Value =
+            serde_json::from_slice(&artifact).unwrap_or_else(|err| {
+                panic!("Error parsing contract bundle '{}': {err}", path.display())
+            });
+        let code = bundle["source"]["wasm"].as_str().unwrap_or_else(|| {
+            panic!("Contract bundle '{}' contains no code", path.display())
+        });
+        impl_serde::serialize::from_hex(code).unwrap_or_else(|err| {
+            panic!(
+                "Invalid code in contract bundle '{}': {err}",
+                path.display()
+            )
+        })
+    } else {
+        artifact
+    }
+}
+
 /// Access manifest paths of contracts which are part of the project in which the E2E
 /// tests are defined.
 struct ContractProject {
@@ -114,6 +114,8 @@ where
 
 /// Result of a contract upload.
 pub struct UploadResult<E: Environment, EventLog> {
+    /// The name of the uploaded contract.
+    pub contract_name: String,
     /// The hash with which the contract can be instantiated.
     pub code_hash: E::Hash,
     /// The result of the dry run, contains debug messages if there were any.
@@ -132,6 +134,7 @@ where
 {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.debug_struct("UploadResult")
+            .field("contract_name", &self.contract_name)
             .field("code_hash", &self.code_hash)
             .field("dry_run", &self.dry_run)
             .field("events", &self.events)
@@ -177,6 +180,20 @@ impl<E: Environment, V: scale::Decode, EventLog> CallResult<E, V, EventLog> {
     pub fn debug_message(&self) -> String {
         self.dry_run.debug_message()
     }
+
+    /// Returns `true` if the dry-run trapped without returning any data.
+    ///
+    /// See [`CallDryRunResult::is_bare_trap`].
+    pub fn is_bare_trap(&self) -> bool {
+        self.dry_run.is_bare_trap()
+    }
+
+    /// Returns the data the contract reverted with in the dry-run, if any.
+    ///
+    /// See [`CallDryRunResult::revert_data`].
+    pub fn revert_data(&self) -> Option<Vec<u8>> {
+        self.dry_run.revert_data()
+    }
 }
 
 // TODO(#xxx) Improve the `Debug` implementation.
@@ -257,6 +274,30 @@ impl<E: Environment, V: scale::Decode> CallDryRunResult<E, V> {
     pub fn debug_message(&self) -> String {
         String::from_utf8_lossy(&self.exec_result.debug_message).into()
     }
+
+    /// Returns `true` if the dry-run trapped without returning any data.
+    ///
+    /// This is the case e.g. when the contract panicked: the execution is aborted
+    /// by the runtime (`ContractTrapped`) and no return data is available. A
+    /// contract that explicitly reverted (e.g. by returning an `Err` from a
+    /// message) is not a bare trap, its revert data can be accessed via
+    /// [`CallDryRunResult::revert_data`].
+    pub fn is_bare_trap(&self) -> bool {
+        self.exec_result.result.is_err()
+    }
+
+    /// Returns the data the contract reverted with, if any.
+    ///
+    /// Returns `None` if the call did not revert, or if it trapped without
+    /// returning any data (see [`CallDryRunResult::is_bare_trap`]).
+    pub fn revert_data(&self) -> Option<Vec<u8>> {
+        match &self.exec_result.result {
+            Ok(exec_return_value) if exec_return_value.did_revert() => {
+                Some(exec_return_value.data.clone())
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Result of the dry run of a contract call.
@@ -53,6 +53,16 @@ pub enum Error<DispatchError: fmt::Debug + fmt::Display> {
     /// Decoding failed.
     #[error("Decoding failed: {0}")]
     Decoding(String),
+    /// Reading the storage of a contract failed.
+    #[error("Contract storage error: {0}")]
+    ContractStorage(String),
+    /// Reading the info of a contract, e.g. its code hash, failed.
+    #[error("Contract info error: {0}")]
+    ContractInfo(String),
+    /// The runtime call dry run could not be applied, e.g. because the transaction
+    /// is invalid.
+    #[error("Runtime call dry-run error: {0}")]
+    RuntimeCallDryRun(String),
 }
 
 /// Error during a dry run RPC invocation.
@@ -72,6 +72,50 @@ where
     const EVENT: &'static str = "CodeStored";
 }
 
+/// The origin of a call to a contract.
+#[derive(
+    Debug,
+    scale::Decode,
+    scale::Encode,
+    scale_decode::DecodeAsType,
+    scale_encode::EncodeAsType,
+)]
+#[decode_as_type(trait_bounds = "", crate_path = "subxt::ext::scale_decode")]
+#[encode_as_type(crate_path = "subxt::ext::scale_encode")]
+pub enum CallerOrigin<E: Environment> {
+    /// The call was dispatched by the root origin.
+    Root,
+    /// The call was made by the given account, either a user or a contract.
+    Signed(E::AccountId),
+}
+
+/// A contract was called, either by a user or by another contract.
+///
+/// The event is only emitted for calls which did not trap, after the call returned.
+#[derive(
+    Debug,
+    scale::Decode,
+    scale::Encode,
+    scale_decode::DecodeAsType,
+    scale_encode::EncodeAsType,
+)]
+#[decode_as_type(trait_bounds = "", crate_path = "subxt::ext::scale_decode")]
+#[encode_as_type(crate_path = "subxt::ext::scale_encode")]
+pub struct ContractCalledEvent<E: Environment> {
+    /// The origin of the call.
+    pub caller: CallerOrigin<E>,
+    /// Account id of the called contract.
+    pub contract: E::AccountId,
+}
+
+impl<E> StaticEvent for ContractCalledEvent<E>
+where
+    E: Environment,
+{
+    const PALLET: &'static str = "Contracts";
+    const EVENT: &'static str = "Called";
+}
+
 #[derive(
     scale::Decode,
     scale::Encode,
@@ -100,3 +144,16 @@ pub struct EventWithTopics<T> {
     pub topics: Vec<sp_core::H256>,
     pub event: T,
 }
+
+impl<E: Environment> EventWithTopics<ContractEmitted<E>> {
+    /// Returns `true` if the contract event is an `Evt` ink! event, i.e. its first
+    /// topic is the signature topic of `Evt`.
+    ///
+    /// Always returns `false` for anonymous events, since they have no signature topic.
+    pub fn is<Evt: ink_env::Event>(&self) -> bool {
+        match (Evt::SIGNATURE_TOPIC, self.topics.first()) {
+            (Some(signature_topic), Some(topic)) => topic.as_bytes() == signature_topic,
+            _ => false,
+        }
+    }
+}
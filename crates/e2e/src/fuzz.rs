@@ -0,0 +1,117 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A seeded pseudo random number generator to generate the inputs of fuzzed messages.
+///
+/// The same seed always yields the same sequence of values, so that a failing fuzzing
+/// run can be reproduced. The generator is not suitable for cryptographic purposes.
+#[derive(Debug, Clone)]
+pub struct FuzzRng {
+    state: u64,
+}
+
+impl FuzzRng {
+    /// Creates a new generator from the given `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64, see https://prng.di.unimi.it/splitmix64.c.
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the next random `u128`.
+    pub fn next_u128(&mut self) -> u128 {
+        (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64())
+    }
+
+    /// Returns a random `bool`.
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// Returns a random value in `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// If `bound` is zero.
+    pub fn below(&mut self, bound: u128) -> u128 {
+        assert!(bound > 0, "the bound of a random value must not be zero");
+        self.next_u128() % bound
+    }
+
+    /// Fills `dest` with random bytes.
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// Returns an array of random bytes, e.g. for a random account id.
+    pub fn bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut bytes = [0; N];
+        self.fill_bytes(&mut bytes);
+        bytes
+    }
+
+    /// Returns a random element of `items`.
+    ///
+    /// # Panics
+    ///
+    /// If `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        let index = self.below(items.len() as u128) as usize;
+        &items[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_yields_same_values() {
+        let mut first = FuzzRng::new(42);
+        let mut second = FuzzRng::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(first.next_u128(), second.next_u128());
+        }
+        assert_eq!(first.bytes::<32>(), second.bytes::<32>());
+    }
+
+    #[test]
+    fn different_seeds_yield_different_values() {
+        let mut first = FuzzRng::new(1);
+        let mut second = FuzzRng::new(2);
+
+        assert_ne!(first.next_u64(), second.next_u64());
+    }
+
+    #[test]
+    fn below_respects_bound() {
+        let mut rng = FuzzRng::new(7);
+
+        for bound in 1..100 {
+            assert!(rng.below(bound) < bound);
+        }
+    }
+}
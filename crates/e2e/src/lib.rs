@@ -27,9 +27,11 @@ mod contract_build;
 mod contract_results;
 mod error;
 pub mod events;
+mod fuzz;
 mod node_proc;
 #[cfg(feature = "sandbox")]
 mod sandbox_client;
+mod storage_dump;
 mod subxt_client;
 mod xts;
 
@@ -38,10 +40,12 @@ pub use backend::{
     ChainBackend,
     ContractsBackend,
     E2EBackend,
+    RuntimeCallDryRunResult,
 };
 pub use backend_calls::{
     CallBuilder,
     InstantiateBuilder,
+    DEFAULT_GAS_MARGIN,
 };
 pub use contract_results::{
     CallDryRunResult,
@@ -50,6 +54,7 @@ pub use contract_results::{
     InstantiationResult,
     UploadResult,
 };
+pub use fuzz::FuzzRng;
 pub use ink_e2e_macro::test;
 pub use node_proc::{
     TestNodeProcess,
@@ -59,9 +64,16 @@ pub use node_proc::{
 pub use sandbox_client::{
     preset,
     Client as SandboxClient,
+    Snapshot as SandboxSnapshot,
 };
 pub use sp_core::H256;
 pub use sp_keyring::AccountKeyring;
+pub use storage_dump::{
+    contracts_storage_dump,
+    log_contracts_storage,
+    CatchUnwind,
+    TestOutcome,
+};
 pub use subxt::{
     self,
     backend::rpc::RpcClient,
@@ -69,6 +81,7 @@ pub use subxt::{
 pub use subxt_client::{
     CallBuilderFinal,
     Client,
+    ContractEventMatcher,
     Error,
 };
 pub use subxt_signer::{
@@ -18,10 +18,7 @@ use crate::{
         constructor_exec_input,
         CreateBuilderPartial,
     },
-    client_utils::{
-        salt,
-        ContractsRegistry,
-    },
+    client_utils::ContractsRegistry,
     contract_results::BareInstantiationResult,
     error::SandboxErr,
     log_error,
@@ -31,15 +28,24 @@ use crate::{
     ContractsBackend,
     E2EBackend,
     InstantiateDryRunResult,
+    RuntimeCallDryRunResult,
     UploadResult,
 };
 
-use frame_support::traits::fungible::Inspect;
+use frame_support::{
+    dispatch::{
+        GetDispatchInfo,
+        PostDispatchInfo,
+    },
+    traits::fungible::Inspect,
+};
 use ink_sandbox::{
     api::prelude::*,
     pallet_balances,
     pallet_contracts,
+    pallet_timestamp,
     AccountIdFor,
+    ContractInstantiateResultFor,
     RuntimeCall,
     Sandbox,
     Weight,
@@ -50,6 +56,7 @@ use ink_env::Environment;
 use jsonrpsee::core::async_trait;
 use pallet_contracts::{
     CodeUploadReturnValue,
+    ContractExecResult,
     ContractInstantiateResult,
     InstantiateReturnValue,
 };
@@ -61,9 +68,16 @@ use sp_core::{
     sr25519::Pair,
     Pair as _,
 };
+use sp_runtime::traits::{
+    Dispatchable,
+    Saturating as _,
+};
 use std::{
     marker::PhantomData,
-    path::PathBuf,
+    path::{
+        Path,
+        PathBuf,
+    },
 };
 use subxt::{
     dynamic::Value,
@@ -74,13 +88,22 @@ use subxt_signer::sr25519::Keypair;
 type BalanceOf<R> = <R as pallet_balances::Config>::Balance;
 type ContractsBalanceOf<R> =
     <<R as pallet_contracts::Config>::Currency as Inspect<AccountIdFor<R>>>::Balance;
+type MomentOf<R> = <R as pallet_timestamp::Config>::Moment;
 
 pub struct Client<AccountId, Hash, S: Sandbox> {
     sandbox: S,
     contracts: ContractsRegistry,
+    instantiated: Vec<(String, AccountId)>,
+    block_time: Option<u64>,
     _phantom: PhantomData<(AccountId, Hash)>,
 }
 
+/// A snapshot of the state of a sandbox [`Client`], taken with [`Client::snapshot`].
+pub struct Snapshot<AccountId> {
+    storage: ink_sandbox::Snapshot,
+    instantiated: Vec<(String, AccountId)>,
+}
+
 // While it is not necessary true that `Client` is `Send`, it will not be used in a way
 // that would violate this bound. In particular, all `Client` instances will be operating
 // synchronously.
@@ -99,6 +122,8 @@ where
         Self {
             sandbox,
             contracts: ContractsRegistry::new(contracts),
+            instantiated: Vec::new(),
+            block_time: None,
             _phantom: Default::default(),
         }
     }
@@ -126,12 +151,98 @@ where
     }
 }
 
+impl<AccountId: Clone, Hash, S: Sandbox> Client<AccountId, Hash, S> {
+    /// Takes a snapshot of the state of the sandbox, e.g. to run experiments and roll
+    /// them back with [`Client::restore`] afterwards.
+    ///
+    /// # Note
+    ///
+    /// Snapshots are only supported by the sandbox backend. The node backend does not
+    /// offer this method, hence using it with the node backend fails to compile.
+    pub fn snapshot(&mut self) -> Snapshot<AccountId> {
+        Snapshot {
+            storage: self.sandbox.take_snapshot(),
+            instantiated: self.instantiated.clone(),
+        }
+    }
+
+    /// Restores the state of the sandbox to the given `snapshot`, discarding all changes
+    /// made since it was taken, including instantiated contracts.
+    pub fn restore(&mut self, snapshot: Snapshot<AccountId>) {
+        self.sandbox.restore_snapshot(snapshot.storage);
+        self.instantiated = snapshot.instantiated;
+    }
+}
+
+impl<AccountId: Clone + From<[u8; 32]>, Hash, S: Sandbox> Client<AccountId, Hash, S>
+where
+    S::Runtime: pallet_contracts::Config,
+    AccountIdFor<S::Runtime>: AsRef<[u8; 32]>,
+{
+    /// Records the contract instantiated by `result` under `contract_name`.
+    fn record_instantiation<E: Environment<AccountId = AccountId>>(
+        &mut self,
+        contract_name: &str,
+        context: &str,
+        result: ContractInstantiateResultFor<S::Runtime>,
+    ) -> Result<BareInstantiationResult<E, ()>, SandboxErr> {
+        let account_id_raw = match &result.result {
+            Err(err) => {
+                log_error(&format!("Instantiation failed: {err:?}"));
+                return Err(SandboxErr::new(format!("{context}: {err:?}")));
+            }
+            Ok(res) => *res.account_id.as_ref(),
+        };
+        let account_id = AccountId::from(account_id_raw);
+        self.instantiated
+            .push((contract_name.to_string(), account_id.clone()));
+
+        Ok(BareInstantiationResult {
+            account_id,
+            events: (), // todo: https://github.com/Cardinal-Cryptography/drink/issues/32
+        })
+    }
+}
+
+impl<AccountId, Hash, S: Sandbox> Client<AccountId, Hash, S>
+where
+    S::Runtime: pallet_timestamp::Config,
+    MomentOf<S::Runtime>: From<u64>,
+{
+    /// Sets by how many milliseconds the block timestamp advances with every block
+    /// built by [`Client::build_blocks`].
+    ///
+    /// This allows to test contracts which enforce deadlines based on the block
+    /// timestamp without depending on the wall-clock time.
+    pub fn set_block_time(&mut self, ms: u64) {
+        self.block_time = Some(ms);
+    }
+
+    /// Builds `n` empty blocks and returns the timestamp of the last one.
+    ///
+    /// If a block time was set via [`Client::set_block_time`], the timestamp of each
+    /// new block is the one of its parent advanced by the block time. Otherwise it is
+    /// the one set by the sandbox when initializing the block.
+    pub fn build_blocks(&mut self, n: u32) -> MomentOf<S::Runtime> {
+        for _ in 0..n {
+            let parent_timestamp = self.sandbox.get_timestamp();
+            self.sandbox.build_block();
+            if let Some(block_time) = self.block_time {
+                self.sandbox
+                    .set_timestamp(parent_timestamp.saturating_add(block_time.into()));
+            }
+        }
+        self.sandbox.get_timestamp()
+    }
+}
+
 #[async_trait]
 impl<AccountId: AsRef<[u8; 32]> + Send, Hash, S: Sandbox> ChainBackend
     for Client<AccountId, Hash, S>
 where
     S::Runtime: pallet_balances::Config,
     AccountIdFor<S::Runtime>: From<[u8; 32]>,
+    RuntimeCall<S::Runtime>: GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
 {
     type AccountId = AccountId;
     type Balance = BalanceOf<S::Runtime>;
@@ -160,6 +271,16 @@ where
         Ok(self.sandbox.free_balance(&account))
     }
 
+    async fn set_balance(
+        &mut self,
+        account: Self::AccountId,
+        balance: Self::Balance,
+    ) -> Result<(), Self::Error> {
+        let account = AccountIdFor::<S::Runtime>::from(*account.as_ref());
+        self.sandbox.set_balance(&account, balance);
+        Ok(())
+    }
+
     async fn runtime_call<'a>(
         &mut self,
         origin: &Keypair,
@@ -167,29 +288,14 @@ where
         call_name: &'a str,
         call_data: Vec<Value>,
     ) -> Result<Self::EventLog, Self::Error> {
-        // Since in general, `ChainBackend::runtime_call` must be dynamic, we have to
-        // perform some translation here in order to invoke strongly-typed
-        // [`ink_sandbox::Sandbox`] API.
-
-        // Get metadata of the Sandbox runtime, so that we can encode the call object.
-        // Panic on error - metadata of the static im-memory runtime should always be
-        // available.
-        let raw_metadata: Vec<u8> = S::get_metadata().into();
-        let metadata = subxt_metadata::Metadata::decode(&mut raw_metadata.as_slice())
-            .expect("Failed to decode metadata");
-
-        // Encode the call object.
-        let call = subxt::dynamic::tx(pallet_name, call_name, call_data);
-        let encoded_call = call.encode_call_data(&metadata.into()).map_err(|err| {
-            SandboxErr::new(format!("runtime_call: Error encoding call: {err:?}"))
-        })?;
-
-        // Decode the call object.
-        // Panic on error - we just encoded a validated call object, so it should be
-        // decodable.
-        let decoded_call =
-            RuntimeCall::<S::Runtime>::decode(&mut encoded_call.as_slice())
-                .expect("Failed to decode runtime call");
+        let metadata = runtime_metadata::<S>();
+        let decoded_call = decode_runtime_call::<S>(
+            &metadata,
+            "runtime_call",
+            pallet_name,
+            call_name,
+            call_data,
+        )?;
 
         // Execute the call.
         self.sandbox
@@ -203,12 +309,96 @@ where
 
         Ok(())
     }
+
+    async fn runtime_call_dry_run<'a>(
+        &mut self,
+        origin: &Keypair,
+        pallet_name: &'a str,
+        call_name: &'a str,
+        call_data: Vec<Value>,
+    ) -> Result<RuntimeCallDryRunResult, Self::Error> {
+        let metadata = runtime_metadata::<S>();
+        let decoded_call = decode_runtime_call::<S>(
+            &metadata,
+            "runtime_call_dry_run",
+            pallet_name,
+            call_name,
+            call_data,
+        )?;
+        let dispatch_info = decoded_call.get_dispatch_info();
+
+        // Execute the call, reverting all of its state changes afterwards.
+        let origin = S::convert_account_to_origin(keypair_to_account(origin));
+        let (post_info, result) = match self
+            .sandbox
+            .dry_run(|sandbox| sandbox.runtime_call(decoded_call, origin))
+        {
+            Ok(post_info) => (post_info, Ok(())),
+            Err(err) => (err.post_info, Err(err.error)),
+        };
+        let weight = post_info.calc_actual_weight(&dispatch_info);
+
+        let result = match result {
+            Ok(()) => Ok(()),
+            Err(dispatch_error) => {
+                let dispatch_error = subxt::error::DispatchError::decode_from(
+                    dispatch_error.encode(),
+                    metadata,
+                )
+                .map_err(|err| {
+                    SandboxErr::new(format!(
+                        "runtime_call_dry_run: Error decoding dispatch error: {err:?}"
+                    ))
+                })?;
+                Err(dispatch_error)
+            }
+        };
+
+        Ok(RuntimeCallDryRunResult { weight, result })
+    }
+}
+
+/// Returns the metadata of the sandbox runtime.
+fn runtime_metadata<S: Sandbox>() -> subxt::Metadata {
+    // Panic on error - metadata of the static im-memory runtime should always be
+    // available.
+    let raw_metadata: Vec<u8> = S::get_metadata().into();
+    subxt_metadata::Metadata::decode(&mut raw_metadata.as_slice())
+        .expect("Failed to decode metadata")
+        .into()
+}
+
+/// Translates the dynamic runtime call `call_name` for the `pallet_name` into the
+/// strongly-typed call of the sandbox runtime.
+///
+/// Since in general, `ChainBackend` runtime calls must be dynamic, we have to perform
+/// this translation in order to invoke strongly-typed [`ink_sandbox::Sandbox`] API.
+fn decode_runtime_call<S: Sandbox>(
+    metadata: &subxt::Metadata,
+    context: &str,
+    pallet_name: &str,
+    call_name: &str,
+    call_data: Vec<Value>,
+) -> Result<RuntimeCall<S::Runtime>, SandboxErr> {
+    // Encode the call object.
+    let call = subxt::dynamic::tx(pallet_name, call_name, call_data);
+    let encoded_call = call.encode_call_data(metadata).map_err(|err| {
+        SandboxErr::new(format!("{context}: Error encoding call: {err:?}"))
+    })?;
+
+    // Decode the call object.
+    // Panic on error - we just encoded a validated call object, so it should be
+    // decodable.
+    Ok(
+        RuntimeCall::<S::Runtime>::decode(&mut encoded_call.as_slice())
+            .expect("Failed to decode runtime call"),
+    )
 }
 
 #[async_trait]
 impl<
         AccountId: Clone + Send + Sync + From<[u8; 32]> + AsRef<[u8; 32]>,
-        Hash: Copy + Send + From<[u8; 32]>,
+        Hash: Copy + Send + From<[u8; 32]> + AsRef<[u8]>,
         S: Sandbox,
         E: Environment<
                 AccountId = AccountId,
@@ -227,6 +417,7 @@ where
         caller: &Keypair,
         constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
         value: E::Balance,
+        salt: Vec<u8>,
         gas_limit: Weight,
         storage_deposit_limit: Option<E::Balance>,
     ) -> Result<BareInstantiationResult<E, Self::EventLog>, Self::Error> {
@@ -237,43 +428,116 @@ where
             code,
             value,
             data,
-            salt(),
+            salt,
             keypair_to_account(caller),
             gas_limit,
             storage_deposit_limit,
         );
 
+        self.record_instantiation(contract_name, "bare_instantiate", result)
+    }
+
+    async fn bare_instantiate_dry_run<Contract: Clone, Args: Send + Encode + Clone, R>(
+        &mut self,
+        contract_name: &str,
+        caller: &Keypair,
+        constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
+        value: E::Balance,
+        salt: Vec<u8>,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<InstantiateDryRunResult<E>, Self::Error> {
+        let code = self.contracts.load_code(contract_name);
+        let data = constructor_exec_input(constructor.clone());
+        let result = self.sandbox.dry_run(|sandbox| {
+            sandbox.deploy_contract(
+                code,
+                value,
+                data,
+                salt,
+                keypair_to_account(caller),
+                S::default_gas_limit(),
+                storage_deposit_limit,
+            )
+        });
+
         let account_id_raw = match &result.result {
             Err(err) => {
-                log_error(&format!("Instantiation failed: {err:?}"));
-                return Err(SandboxErr::new(format!("bare_instantiate: {err:?}")));
+                panic!("Instantiate dry-run failed: {err:?}!")
             }
             Ok(res) => *res.account_id.as_ref(),
         };
         let account_id = AccountId::from(account_id_raw);
 
-        Ok(BareInstantiationResult {
-            account_id: account_id.clone(),
-            events: (), // todo: https://github.com/Cardinal-Cryptography/drink/issues/32
-        })
+        let result = ContractInstantiateResult {
+            gas_consumed: result.gas_consumed,
+            gas_required: result.gas_required,
+            storage_deposit: result.storage_deposit,
+            debug_message: result.debug_message,
+            result: result.result.map(|r| {
+                InstantiateReturnValue {
+                    result: r.result,
+                    account_id,
+                }
+            }),
+            events: None,
+        };
+        Ok(result.into())
     }
 
-    async fn bare_instantiate_dry_run<Contract: Clone, Args: Send + Encode + Clone, R>(
+    async fn bare_instantiate_from_code_hash<
+        Contract: Clone,
+        Args: Send + Sync + Encode + Clone,
+        R,
+    >(
         &mut self,
         contract_name: &str,
+        code_hash: E::Hash,
         caller: &Keypair,
         constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
         value: E::Balance,
+        salt: Vec<u8>,
+        gas_limit: Weight,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<BareInstantiationResult<E, Self::EventLog>, Self::Error> {
+        let data = constructor_exec_input(constructor.clone());
+
+        let result = self.sandbox.instantiate_contract(
+            code_hash.as_ref().to_vec(),
+            value,
+            data,
+            salt,
+            keypair_to_account(caller),
+            gas_limit,
+            storage_deposit_limit,
+        );
+
+        self.record_instantiation(
+            contract_name,
+            "bare_instantiate_from_code_hash",
+            result,
+        )
+    }
+
+    async fn bare_instantiate_from_code_hash_dry_run<
+        Contract: Clone,
+        Args: Send + Sync + Encode + Clone,
+        R,
+    >(
+        &mut self,
+        code_hash: E::Hash,
+        caller: &Keypair,
+        constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
+        value: E::Balance,
+        salt: Vec<u8>,
         storage_deposit_limit: Option<E::Balance>,
     ) -> Result<InstantiateDryRunResult<E>, Self::Error> {
-        let code = self.contracts.load_code(contract_name);
         let data = constructor_exec_input(constructor.clone());
         let result = self.sandbox.dry_run(|sandbox| {
-            sandbox.deploy_contract(
-                code,
+            sandbox.instantiate_contract(
+                code_hash.as_ref().to_vec(),
                 value,
                 data,
-                salt(),
+                salt,
                 keypair_to_account(caller),
                 S::default_gas_limit(),
                 storage_deposit_limit,
@@ -332,6 +596,7 @@ where
             .expect("Invalid code hash");
         let code_hash = Hash::from(code_hash_raw);
         Ok(UploadResult {
+            contract_name: contract_name.to_string(),
             code_hash,
             dry_run: Ok(CodeUploadReturnValue {
                 code_hash,
@@ -357,6 +622,28 @@ where
         gas_limit: Weight,
         storage_deposit_limit: Option<E::Balance>,
     ) -> Result<Self::EventLog, Self::Error>
+    where
+        CallBuilderFinal<E, Args, RetType>: Clone,
+    {
+        self.bare_call_with_result(
+            caller,
+            message,
+            value,
+            gas_limit,
+            storage_deposit_limit,
+        )
+        .await
+        .map(|(events, _)| events)
+    }
+
+    async fn bare_call_with_result<Args: Sync + Encode + Clone, RetType: Send + Decode>(
+        &mut self,
+        caller: &Keypair,
+        message: &CallBuilderFinal<E, Args, RetType>,
+        value: E::Balance,
+        gas_limit: Weight,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<(Self::EventLog, Option<ContractExecResult<E::Balance, ()>>), Self::Error>
     where
         CallBuilderFinal<E, Args, RetType>: Clone,
     {
@@ -364,20 +651,30 @@ where
         let exec_input = Encode::encode(message.clone().params().exec_input());
         let account_id = (*account_id.as_ref()).into();
 
-        self.sandbox
-            .call_contract(
-                account_id,
-                value,
-                exec_input,
-                keypair_to_account(caller),
-                gas_limit,
-                storage_deposit_limit,
-                pallet_contracts::Determinism::Enforced,
-            )
-            .result
-            .map_err(|err| SandboxErr::new(format!("bare_call: {err:?}")))?;
+        let result = self.sandbox.call_contract(
+            account_id,
+            value,
+            exec_input,
+            keypair_to_account(caller),
+            gas_limit,
+            storage_deposit_limit,
+            pallet_contracts::Determinism::Enforced,
+        );
+        if let Err(err) = &result.result {
+            return Err(SandboxErr::new(format!("bare_call: {err:?}")))
+        }
 
-        Ok(())
+        Ok((
+            (),
+            Some(ContractResult {
+                gas_consumed: result.gas_consumed,
+                gas_required: result.gas_required,
+                storage_deposit: result.storage_deposit,
+                debug_message: result.debug_message,
+                result: result.result,
+                events: None,
+            }),
+        ))
     }
 
     async fn bare_call_dry_run<Args: Sync + Encode + Clone, RetType: Send + Decode>(
@@ -417,6 +714,42 @@ where
             _marker: Default::default(),
         })
     }
+
+    fn register_contract(&mut self, artifact: &Path) {
+        self.contracts.register(artifact);
+    }
+
+    fn load_code(&self, contract_name: &str) -> Vec<u8> {
+        self.contracts.load_code(contract_name)
+    }
+
+    fn instantiated_contracts(&self) -> Vec<(String, E::AccountId)> {
+        self.instantiated.clone()
+    }
+
+    async fn contract_storage(
+        &mut self,
+        contract_name: &str,
+        account_id: &E::AccountId,
+    ) -> Result<Vec<(String, Vec<u8>)>, Self::Error> {
+        let account_id: AccountIdFor<S::Runtime> = (*account_id.as_ref()).into();
+        let mut storage = Vec::new();
+        for (label, key) in self.contracts.storage_cells(contract_name) {
+            let value = self
+                .sandbox
+                .execute_with(|| {
+                    pallet_contracts::Pallet::<S::Runtime>::get_storage(
+                        account_id.clone(),
+                        key,
+                    )
+                })
+                .map_err(|err| SandboxErr::new(format!("contract_storage: {err:?}")))?;
+            if let Some(value) = value {
+                storage.push((label, value));
+            }
+        }
+        Ok(storage)
+    }
 }
 
 impl<
@@ -433,6 +766,8 @@ where
     Config::Runtime: pallet_balances::Config + pallet_contracts::Config,
     AccountIdFor<Config::Runtime>: From<[u8; 32]> + AsRef<[u8; 32]>,
     ContractsBalanceOf<Config::Runtime>: Send + Sync,
+    RuntimeCall<Config::Runtime>:
+        GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
 {
 }
 
@@ -469,6 +804,7 @@ pub mod preset {
             Extension,
             RuntimeMetadataPrefixed,
             Sandbox,
+            Snapshot,
         };
         pub use pallet_contracts_mock_network::*;
         use sp_runtime::traits::Dispatchable;
@@ -512,6 +848,22 @@ pub mod preset {
                 })
             }
 
+            fn take_snapshot(&mut self) -> Snapshot {
+                EXT_PARAA.with(|v| {
+                    let mut v = v.borrow_mut();
+                    v.commit_all().expect("Failed to commit changes");
+                    v.as_backend()
+                })
+            }
+
+            fn restore_snapshot(&mut self, snapshot: Snapshot) {
+                EXT_PARAA.with(|v| {
+                    let mut v = v.borrow_mut();
+                    v.commit_all().expect("Failed to commit changes");
+                    v.backend = snapshot;
+                })
+            }
+
             fn register_extension<E: ::core::any::Any + Extension>(&mut self, ext: E) {
                 EXT_PARAA.with(|v| v.borrow_mut().register_extension(ext));
             }
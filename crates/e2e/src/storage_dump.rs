@@ -0,0 +1,176 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Logging of the contracts' storage when an E2E test fails.
+//!
+//! Used by `#[ink_e2e::test(dump_storage_on_failure)]`.
+
+use crate::{
+    backend::BuilderClient,
+    log_error,
+};
+use ink_env::Environment;
+use std::{
+    fmt::Debug,
+    future::Future,
+    panic::{
+        catch_unwind,
+        AssertUnwindSafe,
+    },
+    pin::Pin,
+    task::{
+        Context,
+        Poll,
+    },
+};
+
+/// The outcome of an E2E test function.
+pub trait TestOutcome {
+    /// Returns `true` if the test function failed.
+    fn is_failure(&self) -> bool;
+}
+
+impl TestOutcome for () {
+    fn is_failure(&self) -> bool {
+        false
+    }
+}
+
+impl<T, E> TestOutcome for Result<T, E> {
+    fn is_failure(&self) -> bool {
+        self.is_err()
+    }
+}
+
+/// A future which catches a panic of the wrapped future.
+///
+/// Resolves to `Err` with the panic payload if polling the wrapped future panicked.
+pub struct CatchUnwind<F> {
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> CatchUnwind<F> {
+    /// Wraps the given `future`.
+    pub fn new(future: F) -> Self {
+        Self {
+            future: Box::pin(future),
+        }
+    }
+}
+
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = std::thread::Result<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let future = self.future.as_mut();
+        match catch_unwind(AssertUnwindSafe(|| future.poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    }
+}
+
+/// Logs the storage of every contract instantiated by `client`.
+///
+/// See [`contracts_storage_dump`] for the format.
+pub async fn log_contracts_storage<E, Client>(client: &mut Client)
+where
+    E: Environment,
+    E::AccountId: Debug,
+    Client: BuilderClient<E>,
+    Client::Error: Debug,
+{
+    log_error(&contracts_storage_dump::<E, Client>(client).await);
+}
+
+/// Returns the storage of every contract instantiated by `client`.
+///
+/// The raw value of each storage cell is hex encoded, labelled by the storage field it
+/// belongs to.
+pub async fn contracts_storage_dump<E, Client>(client: &mut Client) -> String
+where
+    E: Environment,
+    E::AccountId: Debug,
+    Client: BuilderClient<E>,
+    Client::Error: Debug,
+{
+    let mut dump = Vec::new();
+    for (contract_name, account_id) in client.instantiated_contracts() {
+        let storage = client.contract_storage(&contract_name, &account_id).await;
+        dump.push(format_contract_storage(
+            &contract_name,
+            &account_id,
+            storage,
+        ));
+    }
+    dump.join("\n")
+}
+
+/// Formats the storage of a single contract, or the error reading it.
+fn format_contract_storage<AccountId, Error>(
+    contract_name: &str,
+    account_id: &AccountId,
+    storage: Result<Vec<(String, Vec<u8>)>, Error>,
+) -> String
+where
+    AccountId: Debug,
+    Error: Debug,
+{
+    match storage {
+        Ok(storage) => {
+            let mut lines = vec![format!(
+                "storage of contract {contract_name:?} at {account_id:?}:"
+            )];
+            lines.extend(storage.into_iter().map(|(label, value)| {
+                format!(
+                    "  {label}: {}",
+                    impl_serde::serialize::to_hex(&value, false)
+                )
+            }));
+            lines.join("\n")
+        }
+        Err(err) => {
+            format!(
+                "failed reading storage of contract {contract_name:?} at {account_id:?}: {err:?}"
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contract_storage_is_formatted_per_cell() {
+        let storage = vec![
+            (String::from("root"), vec![0x01]),
+            (String::from("balances"), vec![0xde, 0xad, 0xbe, 0xef]),
+        ];
+
+        assert_eq!(
+            format_contract_storage::<_, ()>("flipper", &[7u8; 2], Ok(storage)),
+            "storage of contract \"flipper\" at [7, 7]:\n  root: 0x01\n  balances: 0xdeadbeef"
+        );
+    }
+
+    #[test]
+    fn storage_read_error_is_formatted() {
+        assert_eq!(
+            format_contract_storage("flipper", &[7u8; 2], Err("not found")),
+            "failed reading storage of contract \"flipper\" at [7, 7]: \"not found\""
+        );
+    }
+}
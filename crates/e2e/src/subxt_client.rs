@@ -49,27 +49,37 @@ use ink_env::{
     },
     Environment,
 };
+use ink_primitives::MessageResult;
 use jsonrpsee::core::async_trait;
-use pallet_contracts::ContractResult;
+use pallet_contracts::{
+    ContractExecResult,
+    ContractResult,
+};
 use scale::{
     Decode,
     Encode,
 };
+use sp_runtime::transaction_validity::{
+    InvalidTransaction,
+    TransactionValidityError,
+};
 use sp_weights::Weight;
 #[cfg(feature = "std")]
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{
+    Path,
+    PathBuf,
+};
 
 use crate::{
     backend::ChainBackend,
-    client_utils::{
-        salt,
-        ContractsRegistry,
-    },
+    client_utils::ContractsRegistry,
     error::DryRunError,
     events,
+    fuzz::FuzzRng,
     ContractsBackend,
     E2EBackend,
+    RuntimeCallDryRunResult,
 };
 use subxt::{
     blocks::ExtrinsicEvents,
@@ -108,6 +118,7 @@ where
 {
     api: ContractsApi<C, E>,
     contracts: ContractsRegistry,
+    instantiated: Vec<(String, E::AccountId)>,
 }
 
 impl<C, E> Client<C, E>
@@ -133,6 +144,7 @@ where
         Ok(Self {
             api: ContractsApi::new(client).await?,
             contracts: ContractsRegistry::new(contracts),
+            instantiated: Vec::new(),
         })
     }
 
@@ -143,11 +155,10 @@ where
         code: Vec<u8>,
         data: Vec<u8>,
         value: E::Balance,
+        salt: Vec<u8>,
         gas_limit: Weight,
         storage_deposit_limit: Option<E::Balance>,
     ) -> Result<BareInstantiationResult<E, ExtrinsicEvents<C>>, Error> {
-        let salt = salt();
-
         let tx_events = self
             .api
             .instantiate_with_code(
@@ -161,6 +172,15 @@ where
             )
             .await;
 
+        self.instantiation_result(tx_events)
+    }
+
+    /// Extracts the account id of the instantiated contract from the events of an
+    /// instantiation extrinsic.
+    fn instantiation_result(
+        &self,
+        tx_events: ExtrinsicEvents<C>,
+    ) -> Result<BareInstantiationResult<E, ExtrinsicEvents<C>>, Error> {
         let mut account_id = None;
         for evt in tx_events.iter() {
             let evt = evt.unwrap_or_else(|err| {
@@ -206,6 +226,7 @@ where
     /// Executes an `upload` call and captures the resulting events.
     async fn exec_upload(
         &mut self,
+        contract_name: &str,
         signer: &Keypair,
         code: Vec<u8>,
         storage_deposit_limit: Option<E::Balance>,
@@ -266,12 +287,372 @@ where
         };
 
         Ok(UploadResult {
+            contract_name: contract_name.to_string(),
             dry_run,
             code_hash,
             events: tx_events,
         })
     }
 
+    /// Dry runs the `message` on top of the state of the block with the hash
+    /// `block_hash`, instead of the state of the best block.
+    ///
+    /// This allows to query historical state, e.g. the value a message returned before
+    /// a later call changed it. The block hash of a call or an instantiation can be
+    /// taken from its events via `events.block_hash()`.
+    ///
+    /// The node must still have the state of the block available, i.e. it must not
+    /// have been pruned.
+    pub async fn call_dry_run_at<Args: Sync + Encode + Clone, RetType: Send + Decode>(
+        &self,
+        block_hash: C::Hash,
+        caller: &Keypair,
+        message: &CallBuilderFinal<E, Args, RetType>,
+        value: E::Balance,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<CallDryRunResult<E, RetType>, Error>
+    where
+        CallBuilderFinal<E, Args, RetType>: Clone,
+    {
+        self.exec_call_dry_run(
+            Some(block_hash),
+            caller,
+            message,
+            value,
+            storage_deposit_limit,
+        )
+        .await
+    }
+
+    /// Returns the code hash of the contract at `contract`, as currently stored on
+    /// chain.
+    ///
+    /// Returns `Err` if there is no contract at `contract`.
+    pub async fn code_hash(&self, contract: &E::AccountId) -> Result<E::Hash, Error>
+    where
+        E::Hash: Decode,
+    {
+        let contract_info_addr = subxt::dynamic::storage(
+            "Contracts",
+            "ContractInfoOf",
+            vec![Value::from_bytes(Encode::encode(contract))],
+        );
+        let key = self
+            .api
+            .client
+            .storage()
+            .address_bytes(&contract_info_addr)
+            .map_err(|err| Error::ContractInfo(format!("{err:?}")))?;
+
+        let best_block = self.api.best_block().await;
+        let contract_info = self
+            .api
+            .client
+            .storage()
+            .at(best_block)
+            .fetch_raw(key)
+            .await
+            .map_err(|err| Error::ContractInfo(format!("{err:?}")))?
+            .ok_or_else(|| {
+                Error::ContractInfo(format!("no contract found at {contract:?}"))
+            })?;
+
+        // The contract info starts with the trie id of the contract, followed by its
+        // code hash.
+        let (_trie_id, code_hash) = <(Vec<u8>, E::Hash)>::decode(&mut &contract_info[..])
+            .map_err(|err| Error::Decoding(format!("{err:?}")))?;
+        Ok(code_hash)
+    }
+
+    /// Asserts that the contract at `contract` currently runs the code with the hash
+    /// `expected`, e.g. after it was upgraded using `set_code_hash`.
+    ///
+    /// # Panics
+    ///
+    /// If the code hash of the contract differs from `expected`, or if it could not be
+    /// read.
+    pub async fn assert_code_hash(&self, contract: &E::AccountId, expected: E::Hash)
+    where
+        E::Hash: Decode + PartialEq,
+    {
+        let code_hash = self.code_hash(contract).await.unwrap_or_else(|err| {
+            panic!("unable to read code hash of contract {contract:?}: {err:?}")
+        });
+        assert_eq!(
+            code_hash, expected,
+            "unexpected code hash of contract {contract:?}"
+        );
+    }
+
+    /// Submits the `message` to the contract `contract_name`, expecting it to revert,
+    /// and asserts that the storage of the contract is the same before and after the
+    /// call.
+    ///
+    /// The call is submitted even though it reverts, using the gas required by its
+    /// dry-run. Both a call that returns with the revert flag set and a call that
+    /// traps are accepted. The storage cells compared are the ones known from the
+    /// storage layout of the contract, see [`BuilderClient::contract_storage`].
+    ///
+    /// # Panics
+    ///
+    /// If the call does not revert, or if the storage of the contract changed.
+    pub async fn assert_no_state_change_on_revert<
+        Args: Sync + Encode + Clone,
+        RetType: Send + Decode,
+    >(
+        &mut self,
+        contract_name: &str,
+        caller: &Keypair,
+        message: &CallBuilderFinal<E, Args, RetType>,
+    ) -> Result<(), Error>
+    where
+        Self: BuilderClient<E> + ContractsBackend<E, Error = Error>,
+        CallBuilderFinal<E, Args, RetType>: Clone,
+        E::Balance: From<u32>,
+    {
+        let contract = message.clone().params().callee().clone();
+        let storage_before = self.contract_storage(contract_name, &contract).await?;
+
+        let dry_run = self
+            .api
+            .call_dry_run(
+                None,
+                Signer::<C>::account_id(caller),
+                contract.clone(),
+                Encode::encode(message.clone().params().exec_input()),
+                0u32.into(),
+                None,
+            )
+            .await;
+        let reverted = match &dry_run.result {
+            Ok(exec_return_value) => exec_return_value.did_revert(),
+            Err(_) => true,
+        };
+        assert!(
+            reverted,
+            "expected the call to contract {contract:?} to revert, but it succeeded"
+        );
+
+        // A trapped call fails the extrinsic, which is expected here.
+        let _ = self
+            .bare_call(caller, message, 0u32.into(), dry_run.gas_required, None)
+            .await;
+
+        let storage_after = self.contract_storage(contract_name, &contract).await?;
+        assert_eq!(
+            storage_before, storage_after,
+            "the reverted call changed the storage of contract {contract:?}"
+        );
+        Ok(())
+    }
+
+    /// Submits the `message` from a new account which cannot pay the fee of the call,
+    /// and asserts that the call is rejected for insufficient funds.
+    ///
+    /// The new account is funded by `origin` with the existential deposit plus the
+    /// estimated fee of the call, minus one. Since paying the fee must not reap the
+    /// account, this is just below what the account needs to pay the fee. Returns the
+    /// drained account, e.g. to assert that its balance did not change.
+    ///
+    /// # Panics
+    ///
+    /// If the call is not rejected because the account cannot pay its fee.
+    pub async fn with_drained_account<
+        Args: Sync + Encode + Clone,
+        RetType: Send + Decode,
+    >(
+        &mut self,
+        origin: &Keypair,
+        message: &CallBuilderFinal<E, Args, RetType>,
+    ) -> Result<Keypair, Error>
+    where
+        Self: ChainBackend<Balance = E::Balance>,
+        CallBuilderFinal<E, Args, RetType>: Clone,
+        E::Balance: From<u32>,
+    {
+        let dest = message.clone().params().callee().clone();
+        let exec_input = Encode::encode(message.clone().params().exec_input());
+        let gas_limit = self
+            .exec_call_dry_run(None, origin, message, 0u32.into(), None)
+            .await?
+            .exec_result
+            .gas_required;
+
+        let (_, fee) = self
+            .api
+            .call_extrinsic_dry_run(
+                subxt::utils::MultiAddress::Id(dest.clone()),
+                0u32.into(),
+                gas_limit.into(),
+                None,
+                exec_input.clone(),
+                origin,
+            )
+            .await;
+        let existential_deposit = self.api.existential_deposit().await;
+        let amount = existential_deposit + fee - 1u32.into();
+        let drained = self.create_and_fund_account(origin, amount).await;
+
+        let (result, _) = self
+            .api
+            .call_extrinsic_dry_run(
+                subxt::utils::MultiAddress::Id(dest.clone()),
+                0u32.into(),
+                gas_limit.into(),
+                None,
+                exec_input.clone(),
+                &drained,
+            )
+            .await;
+        assert_eq!(
+            result,
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Payment
+            )),
+            "expected the call from an account with a balance of {amount:?} to be \
+            rejected for insufficient funds"
+        );
+
+        let submitted = self
+            .api
+            .try_call(
+                subxt::utils::MultiAddress::Id(dest),
+                0u32.into(),
+                gas_limit.into(),
+                None,
+                exec_input,
+                &drained,
+            )
+            .await;
+        assert!(
+            submitted.is_err(),
+            "expected the transaction pool to reject the call from the drained account"
+        );
+
+        Ok(drained)
+    }
+
+    /// Fuzzes a contract by submitting `iterations` messages built by `generator` from
+    /// random inputs.
+    ///
+    /// Every message is dry-run first. A message which returns `Ok` is then submitted,
+    /// so that later messages see its effects. A message which returns an `Err` of its
+    /// declared error type is not submitted, its error is collected and returned in the
+    /// order of the iterations. The random inputs are drawn from a [`FuzzRng`] created
+    /// from `seed`, so a failing run can be reproduced with the same `seed`.
+    ///
+    /// # Panics
+    ///
+    /// If a message traps, e.g. because the contract panicked, fails with a
+    /// [`LangError`][`ink_primitives::LangError`], or returns data which can't be decoded
+    /// as its return type. The panic message contains the `seed` and the iteration of
+    /// the offending message.
+    pub async fn fuzz<Args, T, Err, G>(
+        &mut self,
+        caller: &Keypair,
+        seed: u64,
+        iterations: u32,
+        mut generator: G,
+    ) -> Result<Vec<Err>, Error>
+    where
+        Args: Sync + Encode + Clone,
+        T: Send + Decode,
+        Err: Send + Decode,
+        G: FnMut(&mut FuzzRng) -> CallBuilderFinal<E, Args, Result<T, Err>>,
+        Self: BuilderClient<E> + ContractsBackend<E, Error = Error>,
+        CallBuilderFinal<E, Args, Result<T, Err>>: Clone,
+        E::Balance: From<u32>,
+    {
+        let mut rng = FuzzRng::new(seed);
+        let mut errors = Vec::new();
+        for iteration in 0..iterations {
+            let message = generator(&mut rng);
+            let dry_run = match self
+                .exec_call_dry_run(None, caller, &message, 0u32.into(), None)
+                .await
+            {
+                Ok(dry_run) if !dry_run.is_err() => dry_run,
+                Ok(dry_run) => {
+                    panic!(
+                        "fuzzing with seed {seed} trapped in iteration {iteration}: {:?}",
+                        dry_run.exec_result.result
+                    )
+                }
+                Err(Error::CallDryRun(err)) => {
+                    panic!(
+                        "fuzzing with seed {seed} trapped in iteration {iteration}: {err}"
+                    )
+                }
+                Err(err) => return Err(err),
+            };
+            let message_result = <MessageResult<Result<T, Err>> as Decode>::decode(
+                &mut dry_run.return_data(),
+            )
+            .unwrap_or_else(|err| {
+                panic!(
+                    "fuzzing with seed {seed} returned undecodable data in iteration {iteration}: {err}"
+                )
+            });
+            match message_result {
+                Ok(Ok(_)) => {
+                    let gas_required = dry_run.exec_result.gas_required;
+                    self.bare_call(caller, &message, 0u32.into(), gas_required, None)
+                        .await?;
+                }
+                Ok(Err(err)) => errors.push(err),
+                Err(lang_err) => {
+                    panic!(
+                        "fuzzing with seed {seed} failed in iteration {iteration}: {lang_err:?}"
+                    )
+                }
+            }
+        }
+        Ok(errors)
+    }
+
+    /// Dry runs the `message` on top of the state of the block with the hash `at`, or
+    /// of the best block if `at` is `None`.
+    async fn exec_call_dry_run<Args: Sync + Encode + Clone, RetType: Send + Decode>(
+        &self,
+        at: Option<C::Hash>,
+        caller: &Keypair,
+        message: &CallBuilderFinal<E, Args, RetType>,
+        value: E::Balance,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<CallDryRunResult<E, RetType>, Error>
+    where
+        CallBuilderFinal<E, Args, RetType>: Clone,
+    {
+        let dest = message.clone().params().callee().clone();
+        let exec_input = Encode::encode(message.clone().params().exec_input());
+
+        let exec_result = self
+            .api
+            .call_dry_run(
+                at,
+                Signer::<C>::account_id(caller),
+                dest,
+                exec_input,
+                value,
+                storage_deposit_limit,
+            )
+            .await;
+        log_info(&format!("call dry run: {:?}", &exec_result.result));
+        log_info(&format!(
+            "call dry run debug message: {}",
+            String::from_utf8_lossy(&exec_result.debug_message)
+        ));
+
+        let exec_result = self
+            .contract_result_to_result(exec_result)
+            .map_err(Error::CallDryRun)?;
+
+        Ok(CallDryRunResult {
+            exec_result,
+            _marker: Default::default(),
+        })
+    }
+
     /// Transforms a [`ContractResult`] from a dry run into a [`Result`] type, containing
     /// details of the [`DispatchError`] if the dry run failed.
     #[allow(clippy::type_complexity)]
@@ -420,6 +801,67 @@ where
         Ok(balance)
     }
 
+    async fn set_balance(
+        &mut self,
+        account: Self::AccountId,
+        balance: Self::Balance,
+    ) -> Result<(), Self::Error> {
+        if self.api.client.metadata().pallet_by_name("Sudo").is_none() {
+            return Err(Error::Balance(
+                "setting a balance requires the `Sudo` pallet".into(),
+            ))
+        }
+        let new_free =
+            subxt::ext::scale_value::serde::to_value(&balance).map_err(|err| {
+                Error::Balance(format!("{balance:?} failed to convert: {err}"))
+            })?;
+        let call = Value::unnamed_variant(
+            "Balances",
+            [Value::named_variant(
+                "force_set_balance",
+                [
+                    (
+                        "who",
+                        Value::unnamed_variant("Id", [Value::from_bytes(&account)]),
+                    ),
+                    ("new_free", new_free),
+                ],
+            )],
+        );
+
+        let tx_events = self
+            .runtime_call(&crate::alice(), "Sudo", "sudo", vec![call])
+            .await?;
+
+        // `Sudo` reports the outcome of the dispatched call in its `Sudid` event.
+        for evt in tx_events.iter() {
+            let evt = evt.unwrap_or_else(|err| {
+                panic!("unable to unwrap event: {err:?}");
+            });
+            if evt.pallet_name() != "Sudo" || evt.variant_name() != "Sudid" {
+                continue
+            }
+            let fields = evt
+                .field_values()
+                .map_err(|e| Error::Decoding(e.to_string()))?;
+            let sudo_result = fields
+                .into_values()
+                .next()
+                .ok_or_else(|| Error::Decoding("`Sudid` event has no fields".into()))?;
+            if let ValueDef::Variant(variant) = &sudo_result.value {
+                if variant.name == "Err" {
+                    return Err(Error::Balance(format!(
+                        "setting the balance of {account:?} failed: {:?}",
+                        variant.values
+                    )))
+                }
+            }
+        }
+
+        log_info(&format!("balance of {account:?} set to {balance:?}"));
+        Ok(())
+    }
+
     async fn runtime_call<'a>(
         &mut self,
         origin: &Keypair,
@@ -450,6 +892,37 @@ where
 
         Ok(tx_events)
     }
+
+    async fn runtime_call_dry_run<'a>(
+        &mut self,
+        origin: &Keypair,
+        pallet_name: &'a str,
+        call_name: &'a str,
+        call_data: Vec<Value>,
+    ) -> Result<RuntimeCallDryRunResult, Self::Error> {
+        let (result, weight) = self
+            .api
+            .runtime_call_dry_run(origin, pallet_name, call_name, call_data)
+            .await;
+
+        let outcome = result.map_err(|err| {
+            Error::RuntimeCallDryRun(format!("transaction is invalid: {err:?}"))
+        })?;
+        let result = match outcome {
+            Ok(()) => Ok(()),
+            Err(dispatch_error) => {
+                let metadata = self.api.client.metadata();
+                let dispatch_error = subxt::error::DispatchError::decode_from(
+                    Encode::encode(&dispatch_error),
+                    metadata,
+                )
+                .map_err(|e| Error::Decoding(e.to_string()))?;
+                Err(dispatch_error)
+            }
+        };
+
+        Ok(RuntimeCallDryRunResult { weight, result })
+    }
 }
 
 #[async_trait]
@@ -482,18 +955,98 @@ where
         caller: &Keypair,
         constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
         value: E::Balance,
+        salt: Vec<u8>,
         gas_limit: Weight,
         storage_deposit_limit: Option<E::Balance>,
     ) -> Result<BareInstantiationResult<E, Self::EventLog>, Self::Error> {
         let code = self.contracts.load_code(contract_name);
         let data = constructor_exec_input(constructor.clone());
         let ret = self
-            .exec_instantiate(caller, code, data, value, gas_limit, storage_deposit_limit)
+            .exec_instantiate(
+                caller,
+                code,
+                data,
+                value,
+                salt,
+                gas_limit,
+                storage_deposit_limit,
+            )
             .await?;
         log_info(&format!("instantiated contract at {:?}", ret.account_id));
+        self.instantiated
+            .push((contract_name.to_string(), ret.account_id.clone()));
+        Ok(ret)
+    }
+
+    async fn bare_instantiate_from_code_hash<
+        Contract: Clone,
+        Args: Send + Sync + Encode + Clone,
+        R,
+    >(
+        &mut self,
+        contract_name: &str,
+        code_hash: E::Hash,
+        caller: &Keypair,
+        constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
+        value: E::Balance,
+        salt: Vec<u8>,
+        gas_limit: Weight,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<BareInstantiationResult<E, Self::EventLog>, Self::Error> {
+        let data = constructor_exec_input(constructor.clone());
+        let tx_events = self
+            .api
+            .instantiate(
+                value,
+                gas_limit.into(),
+                storage_deposit_limit,
+                code_hash,
+                data,
+                salt,
+                caller,
+            )
+            .await;
+        let ret = self.instantiation_result(tx_events)?;
+        log_info(&format!("instantiated contract at {:?}", ret.account_id));
+        self.instantiated
+            .push((contract_name.to_string(), ret.account_id.clone()));
         Ok(ret)
     }
 
+    async fn bare_instantiate_from_code_hash_dry_run<
+        Contract: Clone,
+        Args: Send + Sync + Encode + Clone,
+        R,
+    >(
+        &mut self,
+        code_hash: E::Hash,
+        caller: &Keypair,
+        constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
+        value: E::Balance,
+        salt: Vec<u8>,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<InstantiateDryRunResult<E>, Self::Error> {
+        let data = constructor_exec_input(constructor.clone());
+
+        let result = self
+            .api
+            .instantiate_dry_run(
+                value,
+                storage_deposit_limit,
+                code_hash,
+                data,
+                salt,
+                caller,
+            )
+            .await;
+
+        let result = self
+            .contract_result_to_result(result)
+            .map_err(Error::InstantiateDryRun)?;
+
+        Ok(result.into())
+    }
+
     async fn bare_instantiate_dry_run<
         Contract: Clone,
         Args: Send + Sync + Encode + Clone,
@@ -504,6 +1057,7 @@ where
         caller: &Keypair,
         constructor: &mut CreateBuilderPartial<E, Contract, Args, R>,
         value: E::Balance,
+        salt: Vec<u8>,
         storage_deposit_limit: Option<E::Balance>,
     ) -> Result<InstantiateDryRunResult<E>, Self::Error> {
         let code = self.contracts.load_code(contract_name);
@@ -516,7 +1070,7 @@ where
                 storage_deposit_limit,
                 code,
                 data,
-                salt(),
+                salt,
                 caller,
             )
             .await;
@@ -536,7 +1090,7 @@ where
     ) -> Result<UploadResult<E, Self::EventLog>, Self::Error> {
         let code = self.contracts.load_code(contract_name);
         let ret = self
-            .exec_upload(caller, code, storage_deposit_limit)
+            .exec_upload(contract_name, caller, code, storage_deposit_limit)
             .await?;
         log_info(&format!("contract stored with hash {:?}", ret.code_hash));
         Ok(ret)
@@ -611,6 +1165,23 @@ where
         Ok(tx_events)
     }
 
+    async fn bare_call_with_result<Args: Sync + Encode + Clone, RetType: Send + Decode>(
+        &mut self,
+        caller: &Keypair,
+        message: &CallBuilderFinal<E, Args, RetType>,
+        value: E::Balance,
+        gas_limit: Weight,
+        storage_deposit_limit: Option<E::Balance>,
+    ) -> Result<(Self::EventLog, Option<ContractExecResult<E::Balance, ()>>), Self::Error>
+    where
+        CallBuilderFinal<E, Args, RetType>: Clone,
+    {
+        let events = self
+            .bare_call(caller, message, value, gas_limit, storage_deposit_limit)
+            .await?;
+        Ok((events, None))
+    }
+
     async fn bare_call_dry_run<Args: Sync + Encode + Clone, RetType: Send + Decode>(
         &mut self,
         caller: &Keypair,
@@ -621,33 +1192,39 @@ where
     where
         CallBuilderFinal<E, Args, RetType>: Clone,
     {
-        let dest = message.clone().params().callee().clone();
-        let exec_input = Encode::encode(message.clone().params().exec_input());
+        self.exec_call_dry_run(None, caller, message, value, storage_deposit_limit)
+            .await
+    }
 
-        let exec_result = self
-            .api
-            .call_dry_run(
-                Signer::<C>::account_id(caller),
-                dest,
-                exec_input,
-                value,
-                storage_deposit_limit,
-            )
-            .await;
-        log_info(&format!("call dry run: {:?}", &exec_result.result));
-        log_info(&format!(
-            "call dry run debug message: {}",
-            String::from_utf8_lossy(&exec_result.debug_message)
-        ));
+    fn register_contract(&mut self, artifact: &Path) {
+        self.contracts.register(artifact);
+    }
 
-        let exec_result = self
-            .contract_result_to_result(exec_result)
-            .map_err(Error::CallDryRun)?;
+    fn load_code(&self, contract_name: &str) -> Vec<u8> {
+        self.contracts.load_code(contract_name)
+    }
 
-        Ok(CallDryRunResult {
-            exec_result,
-            _marker: Default::default(),
-        })
+    fn instantiated_contracts(&self) -> Vec<(String, E::AccountId)> {
+        self.instantiated.clone()
+    }
+
+    async fn contract_storage(
+        &mut self,
+        contract_name: &str,
+        account_id: &E::AccountId,
+    ) -> Result<Vec<(String, Vec<u8>)>, Self::Error> {
+        let mut storage = Vec::new();
+        for (label, key) in self.contracts.storage_cells(contract_name) {
+            let value = self
+                .api
+                .get_storage(account_id.clone(), key)
+                .await
+                .map_err(|err| Error::ContractStorage(format!("{err:?}")))?;
+            if let Some(value) = value {
+                storage.push((label, value));
+            }
+        }
+        Ok(storage)
     }
 }
 
@@ -759,4 +1336,169 @@ impl<E: Environment, V, C: subxt::Config> CallResult<E, V, ExtrinsicEvents<C>> {
         }
         Ok(events_with_topics)
     }
+
+    /// Asserts that the contract emitted exactly one event per matcher, in the order
+    /// of the given matchers.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// call_res.assert_events_in_order(&[
+    ///     &|event| event.is::<Approval>(),
+    ///     &|event| event.is::<Transfer>(),
+    /// ]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If the `ContractEmitted` events cannot be decoded.
+    /// - If the number of emitted contract events differs from the number of matchers.
+    /// - If an emitted contract event does not satisfy the matcher at its position.
+    pub fn assert_events_in_order(&self, matchers: &[ContractEventMatcher<'_, E>])
+    where
+        C::Hash: Into<sp_core::H256>,
+    {
+        let events = self
+            .contract_emitted_events()
+            .unwrap_or_else(|err| panic!("failed to decode contract events: {err:?}"));
+        assert_eq!(
+            events.len(),
+            matchers.len(),
+            "expected {} contract events, but {} were emitted",
+            matchers.len(),
+            events.len(),
+        );
+        for (index, (event, matcher)) in events.iter().zip(matchers).enumerate() {
+            assert!(
+                matcher(event),
+                "contract event at position {index} does not match the expected event"
+            );
+        }
+    }
+
+    /// Asserts that the contract emitted an event of type `Evt` and returns the first
+    /// one found.
+    ///
+    /// Only events whose first topic is the signature topic of `Evt` are considered,
+    /// their data is then SCALE decoded into `Evt`. For anonymous events all emitted
+    /// contract events are considered, since they have no signature topic.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let approval = call_res.assert_event_emitted::<Approval>();
+    /// assert_eq!(approval.value, 100);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - If the `ContractEmitted` events cannot be decoded.
+    /// - If no emitted contract event can be decoded into `Evt`. The panic message lists
+    ///   the signature topic of every emitted contract event.
+    pub fn assert_event_emitted<Evt>(&self) -> Evt
+    where
+        Evt: ink_env::Event + Decode,
+        C::Hash: Into<sp_core::H256>,
+    {
+        let events = self
+            .contract_emitted_events()
+            .unwrap_or_else(|err| panic!("failed to decode contract events: {err:?}"));
+        let mut decode_errors = Vec::new();
+        for (index, event) in events.iter().enumerate() {
+            if Evt::SIGNATURE_TOPIC.is_some() && !event.is::<Evt>() {
+                continue
+            }
+            match <Evt as scale::DecodeAll>::decode_all(&mut &event.event.data[..]) {
+                Ok(decoded) => return decoded,
+                Err(err) => decode_errors.push((index, err)),
+            }
+        }
+
+        let expected = Evt::SIGNATURE_TOPIC
+            .map(|topic| format!("{:?}", sp_core::H256::from(topic)))
+            .unwrap_or_else(|| String::from("<anonymous>"));
+        let emitted = events
+            .iter()
+            .enumerate()
+            .map(|(index, event)| {
+                let signature_topic = event
+                    .topics
+                    .first()
+                    .map(|topic| format!("{topic:?}"))
+                    .unwrap_or_else(|| String::from("<no topics>"));
+                let decode_error = decode_errors
+                    .iter()
+                    .find(|(i, _)| *i == index)
+                    .map(|(_, err)| format!(" (failed to decode: {err})"))
+                    .unwrap_or_default();
+                format!("\n  [{index}] {signature_topic}{decode_error}")
+            })
+            .collect::<String>();
+        panic!(
+            "expected contract event `{}` with signature topic {expected} to be emitted, \
+            but the contract emitted {} events:{emitted}",
+            core::any::type_name::<Evt>(),
+            events.len(),
+        )
+    }
+
+    /// Returns the `Called` events of all contracts called during the call, in the
+    /// order in which the calls returned.
+    ///
+    /// Calls which trapped are not included, since their events are reverted.
+    pub fn contract_calls(
+        &self,
+    ) -> Result<Vec<events::ContractCalledEvent<E>>, subxt::Error> {
+        let mut calls = Vec::new();
+        for event in self.events.iter() {
+            if let Some(called) = event?.as_event::<events::ContractCalledEvent<E>>()? {
+                calls.push(called);
+            }
+        }
+        Ok(calls)
+    }
+
+    /// Returns the call depth reached by `contract` recursively calling itself, i.e. the
+    /// number of calls to `contract` which returned without trapping.
+    ///
+    /// # Note
+    ///
+    /// `pallet-contracts` does not expose a trace of the call stack. The depth is
+    /// derived from the `Called` events of the call instead, hence it only equals the
+    /// depth of the call stack if every call to `contract` makes at most one nested
+    /// call to `contract`.
+    pub fn recursion_depth(
+        &self,
+        contract: &E::AccountId,
+    ) -> Result<usize, subxt::Error> {
+        let calls = self.contract_calls()?;
+        Ok(calls
+            .iter()
+            .filter(|called| &called.contract == contract)
+            .count())
+    }
+
+    /// Asserts that `contract` recursively called itself up to the `expected` call
+    /// depth.
+    ///
+    /// See [`CallResult::recursion_depth`] for how the depth is determined.
+    ///
+    /// # Panics
+    ///
+    /// - If the `Called` events cannot be decoded.
+    /// - If the reached call depth differs from `expected`.
+    pub fn assert_recursion_depth(&self, contract: &E::AccountId, expected: usize) {
+        let depth = self
+            .recursion_depth(contract)
+            .unwrap_or_else(|err| panic!("failed to decode contract calls: {err:?}"));
+        assert_eq!(
+            depth, expected,
+            "expected the contract to reach a call depth of {expected}, but it reached {depth}"
+        );
+    }
 }
+
+/// Matches a decoded `ContractEmitted` event, see
+/// [`CallResult::assert_events_in_order`].
+pub type ContractEventMatcher<'a, E> =
+    &'a dyn Fn(&EventWithTopics<events::ContractEmitted<E>>) -> bool;
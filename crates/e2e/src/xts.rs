@@ -22,7 +22,10 @@ use super::{
 use ink_env::Environment;
 
 use core::marker::PhantomData;
-use pallet_contracts::CodeUploadResult;
+use pallet_contracts::{
+    CodeUploadResult,
+    GetStorageResult,
+};
 use sp_core::H256;
 use subxt::{
     backend::{
@@ -97,6 +100,19 @@ pub struct InstantiateWithCode<E: Environment> {
     salt: Vec<u8>,
 }
 
+/// A raw call to `pallet-contracts`'s `instantiate`.
+#[derive(Debug, scale::Encode, scale::Decode, scale_encode::EncodeAsType)]
+#[encode_as_type(trait_bounds = "", crate_path = "subxt::ext::scale_encode")]
+pub struct Instantiate<E: Environment> {
+    #[codec(compact)]
+    value: E::Balance,
+    gas_limit: Weight,
+    storage_deposit_limit: Option<E::Balance>,
+    code_hash: E::Hash,
+    data: Vec<u8>,
+    salt: Vec<u8>,
+}
+
 /// A raw call to `pallet-contracts`'s `call`.
 #[derive(Debug, scale::Decode, scale::Encode, scale_encode::EncodeAsType)]
 #[encode_as_type(trait_bounds = "", crate_path = "subxt::ext::scale_encode")]
@@ -210,7 +226,6 @@ struct RpcCallRequest<C: subxt::Config, E: Environment> {
 enum Code {
     /// A Wasm module as raw bytes.
     Upload(Vec<u8>),
-    #[allow(unused)]
     /// The code hash of an on-chain Wasm blob.
     Existing(H256),
 }
@@ -280,7 +295,48 @@ where
         salt: Vec<u8>,
         signer: &Keypair,
     ) -> ContractInstantiateResult<E::AccountId, E::Balance, ()> {
-        let code = Code::Upload(code);
+        self.instantiate_dry_run_with(
+            value,
+            storage_deposit_limit,
+            Code::Upload(code),
+            data,
+            salt,
+            signer,
+        )
+        .await
+    }
+
+    /// Dry runs the instantiation of the code which was already uploaded with the hash
+    /// `code_hash`.
+    pub async fn instantiate_dry_run(
+        &self,
+        value: E::Balance,
+        storage_deposit_limit: Option<E::Balance>,
+        code_hash: E::Hash,
+        data: Vec<u8>,
+        salt: Vec<u8>,
+        signer: &Keypair,
+    ) -> ContractInstantiateResult<E::AccountId, E::Balance, ()> {
+        self.instantiate_dry_run_with(
+            value,
+            storage_deposit_limit,
+            Code::Existing(H256::from_slice(code_hash.as_ref())),
+            data,
+            salt,
+            signer,
+        )
+        .await
+    }
+
+    async fn instantiate_dry_run_with(
+        &self,
+        value: E::Balance,
+        storage_deposit_limit: Option<E::Balance>,
+        code: Code,
+        data: Vec<u8>,
+        salt: Vec<u8>,
+        signer: &Keypair,
+    ) -> ContractInstantiateResult<E::AccountId, E::Balance, ()> {
         let call_request = RpcInstantiateRequest::<C, E> {
             origin: Signer::<C>::account_id(signer),
             value,
@@ -436,6 +492,39 @@ where
         self.submit_extrinsic(&call, signer).await
     }
 
+    /// Submits an extrinsic to instantiate the code which was already uploaded with the
+    /// hash `code_hash`.
+    ///
+    /// Returns when the transaction is included in a block. The return value
+    /// contains all events that are associated with this transaction.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn instantiate(
+        &self,
+        value: E::Balance,
+        gas_limit: Weight,
+        storage_deposit_limit: Option<E::Balance>,
+        code_hash: E::Hash,
+        data: Vec<u8>,
+        salt: Vec<u8>,
+        signer: &Keypair,
+    ) -> ExtrinsicEvents<C> {
+        let call = subxt::tx::Payload::new(
+            "Contracts",
+            "instantiate",
+            Instantiate::<E> {
+                value,
+                gas_limit,
+                storage_deposit_limit,
+                code_hash,
+                data,
+                salt,
+            },
+        )
+        .unvalidated();
+
+        self.submit_extrinsic(&call, signer).await
+    }
+
     /// Dry runs the upload of the given `code`.
     pub async fn upload_dry_run(
         &self,
@@ -506,8 +595,12 @@ where
     }
 
     /// Dry runs a call of the contract at `contract` with the given parameters.
+    ///
+    /// The call is executed on top of the state of the block with the hash `at`, or
+    /// of the best block if `at` is `None`.
     pub async fn call_dry_run(
         &self,
+        at: Option<C::Hash>,
         origin: C::AccountId,
         dest: E::AccountId,
         input_data: Vec<u8>,
@@ -526,7 +619,7 @@ where
         let params = scale::Encode::encode(&call_request);
         let bytes = self
             .rpc
-            .state_call(func, Some(&params), None)
+            .state_call(func, Some(&params), at)
             .await
             .unwrap_or_else(|err| {
                 panic!("error on ws request `contracts_call`: {err:?}");
@@ -535,6 +628,25 @@ where
             .unwrap_or_else(|err| panic!("decoding ContractExecResult failed: {err}"))
     }
 
+    /// Reads the storage of the contract at `contract` under the given `key`.
+    pub async fn get_storage(
+        &self,
+        contract: E::AccountId,
+        key: Vec<u8>,
+    ) -> GetStorageResult {
+        let func = "ContractsApi_get_storage";
+        let params = scale::Encode::encode(&(contract, key));
+        let bytes = self
+            .rpc
+            .state_call(func, Some(&params), None)
+            .await
+            .unwrap_or_else(|err| {
+                panic!("error on ws request `contracts_getStorage`: {err:?}");
+            });
+        scale::Decode::decode(&mut bytes.as_ref())
+            .unwrap_or_else(|err| panic!("decoding GetStorageResult failed: {err}"))
+    }
+
     /// Submits an extrinsic to call a contract with the given parameters.
     ///
     /// Returns when the transaction is included in a block. The return value
@@ -548,7 +660,92 @@ where
         data: Vec<u8>,
         signer: &Keypair,
     ) -> ExtrinsicEvents<C> {
-        let call = subxt::tx::Payload::new(
+        let call =
+            Self::call_payload(contract, value, gas_limit, storage_deposit_limit, data);
+
+        self.submit_extrinsic(&call, signer).await
+    }
+
+    /// Signs and submits an extrinsic to call a contract with the given parameters.
+    ///
+    /// Returns as soon as the transaction pool accepted the extrinsic, with the hash of
+    /// the extrinsic. Returns a [`subxt::Error`] if the transaction pool rejected the
+    /// extrinsic, e.g. because the `signer` cannot pay its fee.
+    pub async fn try_call(
+        &self,
+        contract: MultiAddress<E::AccountId, ()>,
+        value: E::Balance,
+        gas_limit: Weight,
+        storage_deposit_limit: Option<E::Balance>,
+        data: Vec<u8>,
+        signer: &Keypair,
+    ) -> Result<C::Hash, subxt::Error> {
+        let call =
+            Self::call_payload(contract, value, gas_limit, storage_deposit_limit, data);
+        let account_id = <Keypair as Signer<C>>::account_id(signer);
+        let account_nonce = self.get_account_nonce(&account_id).await?;
+
+        let params = DefaultExtrinsicParamsBuilder::new()
+            .nonce(account_nonce)
+            .build();
+        self.client
+            .tx()
+            .create_signed_offline(&call, signer, params.into())?
+            .submit()
+            .await
+    }
+
+    /// Dry runs the extrinsic to call a contract with the given parameters on top of
+    /// the best block, without submitting it.
+    ///
+    /// Returns the outcome of applying the extrinsic together with its fee, excluding
+    /// any tip.
+    pub async fn call_extrinsic_dry_run(
+        &self,
+        contract: MultiAddress<E::AccountId, ()>,
+        value: E::Balance,
+        gas_limit: Weight,
+        storage_deposit_limit: Option<E::Balance>,
+        data: Vec<u8>,
+        signer: &Keypair,
+    ) -> (sp_runtime::ApplyExtrinsicResult, E::Balance) {
+        let call =
+            Self::call_payload(contract, value, gas_limit, storage_deposit_limit, data);
+        let extrinsic = self.create_signed_encoded(&call, signer).await;
+        let result = self.apply_extrinsic_dry_run(&extrinsic).await;
+
+        // The `RuntimeDispatchInfo` consists of the weight, the class and the fee of the
+        // call, in this order.
+        let bytes = self.query_dispatch_info(extrinsic).await;
+        let (_, _, partial_fee): (sp_weights::Weight, u8, E::Balance) =
+            scale::Decode::decode(&mut bytes.as_ref())
+                .unwrap_or_else(|err| panic!("decoding dispatch info failed: {err}"));
+
+        (result, partial_fee)
+    }
+
+    /// Returns the existential deposit of the chain, i.e. the minimum balance of an
+    /// account.
+    pub async fn existential_deposit(&self) -> E::Balance {
+        let address = subxt::dynamic::constant("Balances", "ExistentialDeposit");
+        let value = self.client.constants().at(&address).unwrap_or_else(|err| {
+            panic!("error reading `Balances::ExistentialDeposit`: {err:?}");
+        });
+        scale::Decode::decode(&mut value.encoded()).unwrap_or_else(|err| {
+            panic!("decoding `Balances::ExistentialDeposit` failed: {err}")
+        })
+    }
+
+    /// Returns the payload of the extrinsic to call a contract with the given
+    /// parameters.
+    fn call_payload(
+        contract: MultiAddress<E::AccountId, ()>,
+        value: E::Balance,
+        gas_limit: Weight,
+        storage_deposit_limit: Option<E::Balance>,
+        data: Vec<u8>,
+    ) -> subxt::tx::Payload<Call<E>> {
+        subxt::tx::Payload::new(
             "Contracts",
             "call",
             Call::<E> {
@@ -559,9 +756,7 @@ where
                 data,
             },
         )
-        .unvalidated();
-
-        self.submit_extrinsic(&call, signer).await
+        .unvalidated()
     }
 
     /// Submit an extrinsic `call_name` for the `pallet_name`.
@@ -581,4 +776,88 @@ where
 
         self.submit_extrinsic(&call, signer).await
     }
+
+    /// Dry runs an extrinsic `call_name` for the `pallet_name` on top of the best
+    /// block, without submitting it.
+    ///
+    /// Returns the outcome of applying the extrinsic together with the weight
+    /// declared by the call.
+    pub async fn runtime_call_dry_run<'a>(
+        &self,
+        signer: &Keypair,
+        pallet_name: &'a str,
+        call_name: &'a str,
+        call_data: Vec<subxt::dynamic::Value>,
+    ) -> (sp_runtime::ApplyExtrinsicResult, sp_weights::Weight) {
+        let call = subxt::dynamic::tx(pallet_name, call_name, call_data);
+        let extrinsic = self.create_signed_encoded(&call, signer).await;
+        let result = self.apply_extrinsic_dry_run(&extrinsic).await;
+
+        // The `RuntimeDispatchInfo` returned here starts with the weight of the call.
+        let bytes = self.query_dispatch_info(extrinsic).await;
+        let weight = scale::Decode::decode(&mut bytes.as_ref())
+            .unwrap_or_else(|err| panic!("decoding dispatch weight failed: {err}"));
+
+        (result, weight)
+    }
+
+    /// Signs the extrinsic with the given call payload with the current nonce of the
+    /// `signer`, without submitting it.
+    ///
+    /// Returns the encoded extrinsic.
+    async fn create_signed_encoded<Call>(&self, call: &Call, signer: &Keypair) -> Vec<u8>
+    where
+        Call: subxt::tx::TxPayload,
+    {
+        let account_id = <Keypair as Signer<C>>::account_id(signer);
+        let account_nonce =
+            self.get_account_nonce(&account_id)
+                .await
+                .unwrap_or_else(|err| {
+                    panic!("error calling `get_account_nonce`: {err:?}");
+                });
+
+        let params = DefaultExtrinsicParamsBuilder::new()
+            .nonce(account_nonce)
+            .build();
+        self.client
+            .tx()
+            .create_signed_offline(call, signer, params.into())
+            .unwrap_or_else(|err| {
+                panic!("error on call `create_signed_offline`: {err:?}");
+            })
+            .into_encoded()
+    }
+
+    /// Applies the encoded `extrinsic` on top of the best block, without altering the
+    /// state of the chain.
+    async fn apply_extrinsic_dry_run(
+        &self,
+        extrinsic: &[u8],
+    ) -> sp_runtime::ApplyExtrinsicResult {
+        // This is what the `system_dryRun` RPC does under the hood.
+        let bytes = self
+            .rpc
+            .state_call("BlockBuilder_apply_extrinsic", Some(extrinsic), None)
+            .await
+            .unwrap_or_else(|err| {
+                panic!("error on ws request `BlockBuilder_apply_extrinsic`: {err:?}");
+            });
+        scale::Decode::decode(&mut bytes.as_ref())
+            .unwrap_or_else(|err| panic!("decoding ApplyExtrinsicResult failed: {err}"))
+    }
+
+    /// Returns the encoded `RuntimeDispatchInfo` of the encoded `extrinsic`.
+    async fn query_dispatch_info(&self, extrinsic: Vec<u8>) -> Vec<u8> {
+        // The extrinsic is already length-prefixed, so it must not be encoded again.
+        let len = extrinsic.len() as u32;
+        let mut params = extrinsic;
+        scale::Encode::encode_to(&len, &mut params);
+        self.rpc
+            .state_call("TransactionPaymentApi_query_info", Some(&params), None)
+            .await
+            .unwrap_or_else(|err| {
+                panic!("error on ws request `TransactionPaymentApi_query_info`: {err:?}");
+            })
+    }
 }
@@ -18,6 +18,7 @@ use std::collections::HashMap;
 
 const BALANCE_OF: &[u8] = b"balance:";
 const STORAGE_OF: &[u8] = b"contract-storage:";
+const CODE_HASH_OF: &[u8] = b"code-hash:";
 
 /// Returns the database key under which to find the balance for account `who`.
 pub fn balance_of_key(who: &[u8]) -> [u8; 32] {
@@ -35,11 +36,19 @@ pub fn storage_of_contract_key(who: &[u8], key: &[u8]) -> [u8; 32] {
     hashed_key
 }
 
+/// Returns the database key under which to find the code hash for account `who`.
+pub fn code_hash_of_key(who: &[u8]) -> [u8; 32] {
+    let keyed = who.to_vec().to_keyed_vec(CODE_HASH_OF);
+    let mut hashed_key: [u8; 32] = [0; 32];
+    super::hashing::blake2b_256(&keyed[..], &mut hashed_key);
+    hashed_key
+}
+
 /// The chain database.
 ///
 /// Everything is stored in here: accounts, balances, contract storage, etc..
 /// Just like in Substrate a prefix hash is computed for every contract.
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Database {
     hmap: HashMap<Vec<u8>, Vec<u8>>,
 }
@@ -128,6 +137,18 @@ impl Database {
             .and_modify(|v| *v = encoded_balance.clone())
             .or_insert(encoded_balance);
     }
+
+    /// Returns the encoded code hash of the contract at `account_id`, if available.
+    pub fn get_code_hash(&self, account_id: &[u8]) -> Option<&Vec<u8>> {
+        let hashed_key = code_hash_of_key(account_id);
+        self.get(&hashed_key)
+    }
+
+    /// Sets the encoded code hash of the contract at `account_id` to `code_hash`.
+    pub fn set_code_hash(&mut self, account_id: &[u8], code_hash: Vec<u8>) {
+        let hashed_key = code_hash_of_key(account_id);
+        self.hmap.insert(hashed_key.to_vec(), code_hash);
+    }
 }
 
 #[cfg(test)]
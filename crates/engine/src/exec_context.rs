@@ -21,7 +21,7 @@ use super::types::{
 
 /// The context of a contract execution.
 #[cfg_attr(test, derive(Debug, PartialEq, Eq))]
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct ExecContext {
     /// The caller of the contract execution. Might be user or another contract.
     ///
@@ -39,12 +39,30 @@ pub struct ExecContext {
     pub callee: Option<AccountId>,
     /// The value transferred to the contract as part of the call.
     pub value_transferred: Balance,
+    /// The tip paid by the origin of the transaction.
+    pub transaction_tip: Balance,
+    /// The author of the current block, if known.
+    pub block_author: Option<AccountId>,
+    /// The balance of the callee before value was transferred to it as part of the
+    /// call, if any value was transferred.
+    pub balance_at_call_start: Option<Balance>,
     /// The current block number.
     pub block_number: BlockNumber,
     /// The current block timestamp.
     pub block_timestamp: BlockTimestamp,
     /// Known contract accounts
     pub contracts: Vec<Vec<u8>>,
+    /// The gas limit of the contract execution, if any was set.
+    pub gas_limit: Option<u64>,
+    /// The gas consumed by the contract execution so far.
+    pub gas_consumed: u64,
+    /// Whether the contract execution is a dry-run.
+    pub is_dry_run: bool,
+    /// The encoded version of the runtime, if known.
+    pub runtime_version: Option<Vec<u8>>,
+    /// Whether the caller is the origin of the call stack, i.e. a plain account and
+    /// not another contract.
+    pub caller_is_origin: bool,
 }
 
 impl ExecContext {
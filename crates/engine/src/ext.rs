@@ -35,6 +35,9 @@ pub use pallet_contracts_uapi::ReturnErrorCode as Error;
 use scale::Encode;
 use std::panic::panic_any;
 
+/// The gas charged by the off-chain engine for every access to contract storage.
+pub const STORAGE_ACCESS_GAS: u64 = 1_000_000;
+
 /// The off-chain engine.
 pub struct Engine {
     /// The environment database.
@@ -49,9 +52,12 @@ pub struct Engine {
     pub chain_spec: ChainSpec,
     /// Handler for registered chain extensions.
     pub chain_extension_handler: ChainExtensionHandler,
+    /// The error to return from the next call to `set_code_hash`, if any.
+    pub(crate) set_code_hash_error: Option<Error>,
 }
 
 /// The chain specification.
+#[derive(Clone)]
 pub struct ChainSpec {
     /// The current gas price.
     pub gas_price: Balance,
@@ -88,6 +94,7 @@ impl Engine {
             debug_info: DebugInfo::new(),
             chain_spec: ChainSpec::default(),
             chain_extension_handler: ChainExtensionHandler::new(),
+            set_code_hash_error: None,
         }
     }
 }
@@ -151,6 +158,7 @@ impl Engine {
     /// Writes the encoded value into the storage at the given key.
     /// Returns the size of the previously stored value at the key if any.
     pub fn set_storage(&mut self, key: &[u8], encoded_value: &[u8]) -> Option<u32> {
+        self.charge_gas(STORAGE_ACCESS_GAS);
         let callee = self.get_callee();
         let account_id = AccountId::from_bytes(&callee[..]);
 
@@ -165,6 +173,7 @@ impl Engine {
 
     /// Returns the contract storage bytes at the key if any.
     pub fn get_storage(&mut self, key: &[u8]) -> Result<&[u8], Error> {
+        self.charge_gas(STORAGE_ACCESS_GAS);
         let callee = self.get_callee();
         let account_id = AccountId::from_bytes(&callee[..]);
 
@@ -178,18 +187,22 @@ impl Engine {
     /// Removes the storage entries at the given key,
     /// returning previously stored value at the key if any.
     pub fn take_storage(&mut self, key: &[u8]) -> Result<Vec<u8>, Error> {
+        self.charge_gas(STORAGE_ACCESS_GAS);
         let callee = self.get_callee();
         let account_id = AccountId::from_bytes(&callee[..]);
 
+        // An absent key is not cleared, hence it is not counted as a write.
+        let value = self
+            .database
+            .remove_contract_storage(&callee, key)
+            .ok_or(Error::KeyNotFound)?;
         self.debug_info.inc_writes(account_id);
-        match self.database.remove_contract_storage(&callee, key) {
-            Some(val) => Ok(val),
-            None => Err(Error::KeyNotFound),
-        }
+        Ok(value)
     }
 
     /// Returns the size of the value stored in the contract storage at the key if any.
     pub fn contains_storage(&mut self, key: &[u8]) -> Option<u32> {
+        self.charge_gas(STORAGE_ACCESS_GAS);
         let callee = self.get_callee();
         let account_id = AccountId::from_bytes(&callee[..]);
 
@@ -202,6 +215,7 @@ impl Engine {
     /// Removes the storage entries at the given key.
     /// Returns the size of the previously stored value at the key if any.
     pub fn clear_storage(&mut self, key: &[u8]) -> Option<u32> {
+        self.charge_gas(STORAGE_ACCESS_GAS);
         let callee = self.get_callee();
         let account_id = AccountId::from_bytes(&callee[..]);
         self.debug_info.inc_writes(account_id.clone());
@@ -227,6 +241,8 @@ impl Engine {
         let value = &scale::Encode::encode(&all)[..];
         self.transfer(beneficiary, value)
             .unwrap_or_else(|err| panic!("transfer did not work: {err:?}"));
+        self.debug_info
+            .record_termination(beneficiary.to_vec(), all);
 
         // Encode the result of the termination and panic with it.
         // This enables testing for the proper result and makes sure this
@@ -262,6 +278,25 @@ impl Engine {
         set_output(output, &balance[..])
     }
 
+    /// Returns by how much the balance of the called contract changed since the call
+    /// started, saturating at zero.
+    pub fn balance_delta_since_call_start(&self, output: &mut &mut [u8]) {
+        let contract = self
+            .exec_context
+            .callee
+            .as_ref()
+            .expect("no callee has been set");
+
+        let balance = self
+            .database
+            .get_balance(contract.as_bytes())
+            .expect("currently executing contract must exist");
+        let balance_at_call_start =
+            self.exec_context.balance_at_call_start.unwrap_or(balance);
+        let delta = scale::Encode::encode(&balance.saturating_sub(balance_at_call_start));
+        set_output(output, &delta[..])
+    }
+
     /// Returns the transferred value for the called contract.
     pub fn value_transferred(&self, output: &mut &mut [u8]) {
         let value_transferred: Vec<u8> =
@@ -320,8 +355,50 @@ impl Engine {
         set_output(output, &block_timestamp[..])
     }
 
-    pub fn gas_left(&self, _output: &mut &mut [u8]) {
-        unimplemented!("off-chain environment does not yet support `gas_left`");
+    /// Returns whether the current call is a dry-run.
+    pub fn is_dry_run(&self) -> bool {
+        self.exec_context.is_dry_run
+    }
+
+    /// Returns the encoded version of the runtime, if any was set.
+    pub fn runtime_version(&self) -> Option<&[u8]> {
+        self.exec_context.runtime_version.as_deref()
+    }
+
+    /// Returns whether the caller is the origin of the call stack.
+    pub fn caller_is_origin(&self) -> bool {
+        self.exec_context.caller_is_origin
+    }
+
+    /// Returns the gas left for the contract execution.
+    ///
+    /// The off-chain engine charges [`STORAGE_ACCESS_GAS`] for every access to
+    /// contract storage. If no gas limit was set, the execution has a budget of
+    /// `u64::MAX`.
+    pub fn gas_left(&self, output: &mut &mut [u8]) {
+        let gas_left = self
+            .exec_context
+            .gas_limit
+            .unwrap_or(u64::MAX)
+            .saturating_sub(self.exec_context.gas_consumed);
+        let gas_left = scale::Encode::encode(&gas_left);
+        set_output(output, &gas_left[..])
+    }
+
+    /// Charges `gas` from the gas left for the contract execution.
+    ///
+    /// # Panics
+    ///
+    /// If the execution runs out of gas.
+    pub fn charge_gas(&mut self, gas: u64) {
+        let exec_context = &mut self.exec_context;
+        exec_context.gas_consumed = exec_context.gas_consumed.saturating_add(gas);
+        if let Some(gas_limit) = exec_context.gas_limit {
+            assert!(
+                exec_context.gas_consumed <= gas_limit,
+                "contract execution ran out of gas"
+            );
+        }
     }
 
     /// Returns the minimum balance that is required for creating an account
@@ -332,6 +409,51 @@ impl Engine {
         set_output(output, &minimum_balance[..])
     }
 
+    /// Returns the encoded code hash of the contract at `account_id`.
+    ///
+    /// Returns `Error::KeyNotFound` if no code hash is known for `account_id`.
+    pub fn code_hash(&self, account_id: &[u8]) -> Result<&[u8], Error> {
+        match self.database.get_code_hash(account_id) {
+            Some(code_hash) => Ok(code_hash),
+            None => Err(Error::KeyNotFound),
+        }
+    }
+
+    /// Returns the encoded code hash of the currently executing contract.
+    ///
+    /// Returns `Error::KeyNotFound` if no code hash is known for the contract.
+    pub fn own_code_hash(&self) -> Result<&[u8], Error> {
+        let callee = self.get_callee();
+        self.code_hash(&callee)
+    }
+
+    /// Replaces the code hash of the currently executing contract.
+    ///
+    /// Returns the error set via `set_code_hash_result` instead, if any.
+    pub fn set_code_hash(&mut self, code_hash: &[u8]) -> Result<(), Error> {
+        if let Some(error) = self.set_code_hash_error.take() {
+            return Err(error)
+        }
+        let callee = self.get_callee();
+        self.database.set_code_hash(&callee, code_hash.to_vec());
+        Ok(())
+    }
+
+    /// Returns the tip paid by the origin of the transaction.
+    pub fn transaction_tip(&self, output: &mut &mut [u8]) {
+        let transaction_tip: Vec<u8> =
+            scale::Encode::encode(&self.exec_context.transaction_tip);
+        set_output(output, &transaction_tip[..])
+    }
+
+    /// Returns the encoded author of the current block, if any was set.
+    pub fn block_author(&self) -> Option<&[u8]> {
+        self.exec_context
+            .block_author
+            .as_ref()
+            .map(|author| author.as_bytes())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn instantiate(
         &mut self,
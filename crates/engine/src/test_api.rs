@@ -13,7 +13,13 @@
 // limitations under the License.
 
 use crate::{
-    ext::Engine,
+    database::Database,
+    exec_context::ExecContext,
+    ext::{
+        self,
+        ChainSpec,
+        Engine,
+    },
     types::{
         AccountId,
         Balance,
@@ -34,6 +40,15 @@ pub struct EmittedEvent {
     pub data: Vec<u8>,
 }
 
+/// Record for a cross-contract call issued by a contract.
+#[derive(Debug, Clone)]
+pub struct RecordedCall {
+    /// The encoded account id of the called contract.
+    pub callee: Vec<u8>,
+    /// The storage keys which were pre-declared to be accessed by the call.
+    pub access_keys: Vec<u32>,
+}
+
 #[derive(Clone)]
 pub struct RecordedDebugMessages {
     debug_messages: Vec<String>,
@@ -74,9 +89,17 @@ impl IntoIterator for RecordedDebugMessages {
 }
 
 /// Recorder for relevant interactions with this crate.
+#[derive(Clone)]
 pub struct DebugInfo {
     /// Emitted events recorder.
     emitted_events: Vec<EmittedEvent>,
+    /// Issued cross-contract calls recorder.
+    recorded_calls: Vec<RecordedCall>,
+    /// Encoded runtime calls recorder.
+    recorded_runtime_calls: Vec<Vec<u8>>,
+    /// The encoded beneficiary and the transferred remaining balance of the contract
+    /// termination, if the contract terminated.
+    termination: Option<(Vec<u8>, Balance)>,
     /// Emitted print messages recorder.
     emitted_debug_messages: RecordedDebugMessages,
     /// The total number of reads to the storage.
@@ -98,6 +121,9 @@ impl DebugInfo {
     pub fn new() -> Self {
         Self {
             emitted_events: Vec::new(),
+            recorded_calls: Vec::new(),
+            recorded_runtime_calls: Vec::new(),
+            termination: None,
             emitted_debug_messages: RecordedDebugMessages::new(),
             count_reads: HashMap::new(),
             count_writes: HashMap::new(),
@@ -110,6 +136,9 @@ impl DebugInfo {
         self.count_reads.clear();
         self.count_writes.clear();
         self.emitted_events.clear();
+        self.recorded_calls.clear();
+        self.recorded_runtime_calls.clear();
+        self.termination = None;
         self.emitted_debug_messages.clear();
         self.cells_per_account.clear();
     }
@@ -170,6 +199,29 @@ impl DebugInfo {
     pub fn record_event(&mut self, event: EmittedEvent) {
         self.emitted_events.push(event);
     }
+
+    /// Records a cross-contract call.
+    pub fn record_call(&mut self, call: RecordedCall) {
+        self.recorded_calls.push(call);
+    }
+
+    /// Records an encoded runtime call.
+    pub fn record_runtime_call(&mut self, call: Vec<u8>) {
+        self.recorded_runtime_calls.push(call);
+    }
+
+    /// Records the termination of the contract.
+    pub fn record_termination(&mut self, beneficiary: Vec<u8>, value: Balance) {
+        self.termination = Some((beneficiary, value));
+    }
+}
+
+/// A snapshot of the engine state, taken with [`Engine::snapshot`].
+pub struct EngineSnapshot {
+    database: Database,
+    exec_context: ExecContext,
+    debug_info: DebugInfo,
+    chain_spec: ChainSpec,
 }
 
 impl Engine {
@@ -178,6 +230,28 @@ impl Engine {
         self.exec_context.reset();
         self.database.clear();
         self.debug_info.reset();
+        self.set_code_hash_error = None;
+    }
+
+    /// Takes a snapshot of the database, the execution context, the recorded debug info
+    /// (e.g. emitted events) and the chain specification.
+    ///
+    /// Registered chain extensions are not part of the snapshot.
+    pub fn snapshot(&self) -> EngineSnapshot {
+        EngineSnapshot {
+            database: self.database.clone(),
+            exec_context: self.exec_context.clone(),
+            debug_info: self.debug_info.clone(),
+            chain_spec: self.chain_spec.clone(),
+        }
+    }
+
+    /// Restores the engine state captured in `snapshot`.
+    pub fn restore(&mut self, snapshot: EngineSnapshot) {
+        self.database = snapshot.database;
+        self.exec_context = snapshot.exec_context;
+        self.debug_info = snapshot.debug_info;
+        self.chain_spec = snapshot.chain_spec;
     }
 
     /// Returns the total number of reads and writes of the contract's storage.
@@ -203,14 +277,56 @@ impl Engine {
         self.exec_context.caller = Some(caller.into());
     }
 
+    /// Sets whether the next call is a dry-run.
+    pub fn set_dry_run(&mut self, is_dry_run: bool) {
+        self.exec_context.is_dry_run = is_dry_run;
+    }
+
+    /// Sets the encoded version of the runtime.
+    pub fn set_runtime_version(&mut self, version: Vec<u8>) {
+        self.exec_context.runtime_version = Some(version);
+    }
+
+    /// Sets whether the caller of the next call is the origin of the call stack.
+    pub fn set_caller_is_origin(&mut self, caller_is_origin: bool) {
+        self.exec_context.caller_is_origin = caller_is_origin;
+    }
+
+    /// Sets the minimum balance (i.e. the chain's existential deposit).
+    pub fn set_minimum_balance(&mut self, minimum_balance: Balance) {
+        self.chain_spec.minimum_balance = minimum_balance;
+    }
+
+    /// Sets the gas price, i.e. the fee charged per unit of gas.
+    pub fn set_gas_price(&mut self, gas_price: Balance) {
+        self.chain_spec.gas_price = gas_price;
+    }
+
     /// Sets a known contract by adding it to a vector of known contracts accounts
     pub fn set_contract(&mut self, caller: Vec<u8>) {
         self.exec_context.contracts.push(caller);
     }
 
+    /// Sets the encoded code hash of the contract at `account_id`.
+    pub fn set_code_hash_of(&mut self, account_id: Vec<u8>, code_hash: Vec<u8>) {
+        self.database.set_code_hash(&account_id, code_hash);
+    }
+
+    /// Sets the result of the next call to `set_code_hash`.
+    pub fn set_code_hash_result(&mut self, result: Result<(), ext::Error>) {
+        self.set_code_hash_error = result.err();
+    }
+
     /// Sets the callee for the next call.
     pub fn set_callee(&mut self, callee: Vec<u8>) {
         self.exec_context.callee = Some(callee.into());
+        self.exec_context.balance_at_call_start = None;
+    }
+
+    /// Sets the balance of the callee before value was transferred to it as part of
+    /// the call.
+    pub fn set_balance_at_call_start(&mut self, balance: Balance) {
+        self.exec_context.balance_at_call_start = Some(balance);
     }
 
     /// Returns the amount of storage cells used by the account `account_id`.
@@ -227,6 +343,16 @@ impl Engine {
         Ok(cells.len())
     }
 
+    /// Returns the storage keys of all cells used by the currently executing contract.
+    pub fn contract_storage_keys(&self) -> Vec<Vec<u8>> {
+        let account_id = AccountId::from_bytes(&self.get_callee()[..]);
+        self.debug_info
+            .cells_per_account
+            .get(&account_id)
+            .map(|cells| cells.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
     /// Advances the chain by a single block.
     pub fn advance_block(&mut self) {
         self.exec_context.block_number += 1;
@@ -253,6 +379,32 @@ impl Engine {
         self.debug_info.emitted_events.clone().into_iter()
     }
 
+    /// Records a cross-contract call issued by the currently executing contract.
+    pub fn record_call(&mut self, call: RecordedCall) {
+        self.debug_info.record_call(call);
+    }
+
+    /// Returns the recorded cross-contract calls in order.
+    pub fn get_recorded_calls(&self) -> impl Iterator<Item = RecordedCall> {
+        self.debug_info.recorded_calls.clone().into_iter()
+    }
+
+    /// Records an encoded runtime call issued by the currently executing contract.
+    pub fn record_runtime_call(&mut self, call: Vec<u8>) {
+        self.debug_info.record_runtime_call(call);
+    }
+
+    /// Returns the encoded runtime calls in order.
+    pub fn get_recorded_runtime_calls(&self) -> impl Iterator<Item = Vec<u8>> {
+        self.debug_info.recorded_runtime_calls.clone().into_iter()
+    }
+
+    /// Returns the encoded beneficiary and the transferred remaining balance of the
+    /// contract termination, if the contract terminated.
+    pub fn get_termination(&self) -> Option<(Vec<u8>, Balance)> {
+        self.debug_info.termination.clone()
+    }
+
     /// Returns the current balance of `account_id`.
     pub fn get_balance(&self, account_id: Vec<u8>) -> Result<Balance, Error> {
         self.database
@@ -270,6 +422,23 @@ impl Engine {
         self.exec_context.value_transferred = value;
     }
 
+    /// Sets the tip paid by the origin of the transaction.
+    pub fn set_transaction_tip(&mut self, tip: Balance) {
+        self.exec_context.transaction_tip = tip;
+    }
+
+    /// Sets the author of the current block.
+    pub fn set_block_author(&mut self, author: Vec<u8>) {
+        self.exec_context.block_author = Some(author.into());
+    }
+
+    /// Sets the gas limit of the contract execution and resets the gas consumed so
+    /// far.
+    pub fn set_gas_limit(&mut self, gas_limit: u64) {
+        self.exec_context.gas_limit = Some(gas_limit);
+        self.exec_context.gas_consumed = 0;
+    }
+
     /// Set the block timestamp for the execution context.
     pub fn set_block_timestamp(&mut self, new_block_timestamp: BlockTimestamp) {
         self.exec_context.block_timestamp = new_block_timestamp;
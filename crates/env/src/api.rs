@@ -42,6 +42,7 @@ use crate::{
     types::Gas,
     Environment,
     Result,
+    RuntimeVersion,
 };
 use ink_storage_traits::Storable;
 use pallet_contracts_uapi::ReturnFlags;
@@ -90,6 +91,12 @@ where
 
 /// Returns the amount of gas left for the contract execution.
 ///
+/// # Note
+///
+/// The off-chain environment charges a fixed amount of gas for every access to
+/// contract storage. Its gas limit can be set with
+/// [`test::set_gas_limit`][`crate::test::set_gas_limit`].
+///
 /// # Errors
 ///
 /// If the returned value cannot be properly decoded.
@@ -177,6 +184,87 @@ where
     })
 }
 
+/// Returns the balance of the executed contract that can be spent without the account
+/// falling below the minimum balance (i.e. the chain's existential deposit).
+///
+/// This is the [`balance`] minus the [`minimum_balance`], saturating at zero.
+///
+/// # Errors
+///
+/// If the returned values cannot be properly decoded.
+pub fn spendable_balance<E>() -> E::Balance
+where
+    E: Environment,
+{
+    use crate::arithmetic::Saturating as _;
+    balance::<E>().saturating_sub(minimum_balance::<E>())
+}
+
+/// Returns by how much the balance of the executed contract increased since the call
+/// started, saturating at zero.
+///
+/// This allows payable messages to check that the balance of the contract increased by
+/// exactly the [`transferred_value`].
+///
+/// # Note
+///
+/// This is only available in the off-chain environment, where the balance of the
+/// contract at the start of the call is recorded by
+/// [`test::transfer_in`][`crate::test::transfer_in`].
+#[cfg(feature = "std")]
+pub fn balance_delta_since_call_start<E>() -> E::Balance
+where
+    E: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.balance_delta_since_call_start::<E>()
+    })
+}
+
+/// Returns the tip paid by the origin of the transaction, e.g. to implement fee rebates.
+///
+/// # Note
+///
+/// `pallet-contracts` does not expose the tip of the transaction to contracts, hence
+/// this always returns zero on-chain. In the off-chain environment it can be set via
+/// [`test::set_transaction_tip`][`crate::test::set_transaction_tip`].
+///
+/// # Errors
+///
+/// If the returned value cannot be properly decoded.
+pub fn transaction_tip<E>() -> E::Balance
+where
+    E: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::transaction_tip::<E>(instance)
+    })
+}
+
+/// Returns the author of the current block, e.g. to reward the block producer.
+///
+/// Returns `Some` account of the author if it is known, and `None` otherwise.
+///
+/// # Note
+///
+/// `pallet-contracts` does not expose the author of the block to contracts, hence
+/// this always returns `None` on-chain. In the off-chain environment it can be set via
+/// [`test::set_block_author`][`crate::test::set_block_author`], and is `None` until it
+/// was set.
+///
+/// # Panics
+///
+/// In the off-chain environment, if the author was set for an environment whose
+/// `AccountId` can't be decoded as `E::AccountId`.
+pub fn block_author<E>() -> Option<E::AccountId>
+where
+    E: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::block_author::<E>(instance)
+    })
+}
+
 /// Emits an event with the given event data.
 pub fn emit_event<E, Evt>(event: Evt)
 where
@@ -188,6 +276,37 @@ where
     })
 }
 
+/// The maximum number of topics of a log emitted with [`emit_evm_log`].
+///
+/// This mirrors the `LOG0` to `LOG4` instructions of the EVM.
+pub const EVM_LOG_MAX_TOPICS: usize = 4;
+
+/// Emits a log with the given raw `topics` and `data`.
+///
+/// In contrast to [`emit_event`] neither the topics nor the data are derived from an
+/// event type. The log has the shape of an EVM log, i.e. it has up to
+/// [`EVM_LOG_MAX_TOPICS`] topics of 32 bytes each and arbitrary data, so that
+/// indexers can process it like the output of the `LOG0` to `LOG4` instructions.
+///
+/// # Note
+///
+/// The log is deposited with the `deposit_event` host function of `pallet-contracts`,
+/// which requires the `Hash` type of the runtime to be 32 bytes wide.
+///
+/// # Panics
+///
+/// If more than [`EVM_LOG_MAX_TOPICS`] topics are given.
+pub fn emit_evm_log(topics: &[[u8; 32]], data: &[u8]) {
+    assert!(
+        topics.len() <= EVM_LOG_MAX_TOPICS,
+        "an EVM log has at most {EVM_LOG_MAX_TOPICS} topics, got {}",
+        topics.len()
+    );
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::emit_evm_log(instance, topics, data)
+    })
+}
+
 /// Writes the value to the contract storage under the given storage key and returns the
 /// size of pre-existing value if any.
 ///
@@ -519,6 +638,38 @@ pub fn debug_message(message: &str) {
     })
 }
 
+/// Returns `true` if the current call is a dry-run.
+///
+/// Contracts can use this to skip effects which are only of interest when the call is
+/// executed for real, e.g. during gas estimation.
+///
+/// # Note
+///
+/// `pallet-contracts` does not expose whether a call is a dry-run to contracts, hence
+/// this always returns `false` on-chain. In the off-chain environment it can be set via
+/// [`test::set_dry_run`][`crate::test::set_dry_run`].
+pub fn is_dry_run() -> bool {
+    <EnvInstance as OnInstance>::on_instance(EnvBackend::is_dry_run)
+}
+
+/// Returns the name and version of the runtime specification, e.g. to gate features
+/// on a runtime upgrade.
+///
+/// Returns `None` if the version of the runtime is not known.
+///
+/// # Note
+///
+/// `pallet-contracts` does not expose the version of the runtime to contracts, hence
+/// this always returns `None` on-chain. Contracts should treat `None` as "unknown" and
+/// fall back to behavior which works on every supported runtime. In the off-chain
+/// environment the version can be set via
+/// [`test::set_runtime_version`][`crate::test::set_runtime_version`].
+pub fn runtime_version() -> Option<RuntimeVersion> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::runtime_version(instance)
+    })
+}
+
 /// Conducts the crypto hash of the given input and stores the result in `output`.
 ///
 /// # Example
@@ -684,6 +835,11 @@ where
 ///
 /// - If no code hash was found for the specified account id.
 /// - If the returned value cannot be properly decoded.
+///
+/// # Note
+///
+/// In the off-chain environment code hashes can be set via
+/// [`test::set_code_hash_of`][`crate::test::set_code_hash_of`].
 pub fn code_hash<E>(account: &E::AccountId) -> Result<E::Hash>
 where
     E: Environment,
@@ -698,6 +854,11 @@ where
 /// # Errors
 ///
 /// If the returned value cannot be properly decoded.
+///
+/// # Note
+///
+/// In the off-chain environment code hashes can be set via
+/// [`test::set_code_hash_of`][`crate::test::set_code_hash_of`].
 pub fn own_code_hash<E>() -> Result<E::Hash>
 where
     E: Environment,
@@ -717,6 +878,9 @@ where
 /// A return value of `true` indicates that this contract is being called by a plain
 /// account. and `false` indicates that the caller is another contract.
 ///
+/// In the off-chain environment the returned value can be set with
+/// [`test::set_caller_is_origin`][`crate::test::set_caller_is_origin`].
+///
 /// # Errors
 ///
 /// If the returned value cannot be properly decoded.
@@ -758,6 +922,11 @@ where
 /// would revert the changes made by `set_code_hash` and the next caller would use the old
 /// code.
 ///
+/// The host error is returned instead of trapping, so contracts can handle a failed
+/// upgrade. In the off-chain environment `set_code_hash` replaces the code hash of the
+/// contract under test, a failure can be injected via
+/// [`test::set_code_hash_result`][`crate::test::set_code_hash_result`].
+///
 /// # Errors
 ///
 /// `ReturnCode::CodeNotFound` in case the supplied `code_hash` cannot be found on-chain.
@@ -852,9 +1021,10 @@ where
 /// - If the runtime doesn't allow for the contract unstable feature.
 /// - If the runtime doesn't allow for dispatching this call from a contract.
 ///
-/// # Panics
+/// # Note
 ///
-/// Panics in the off-chain environment.
+/// The off-chain environment does not dispatch the call. It records its encoding, which
+/// can be inspected with `ink_env::test::recorded_runtime_calls`.
 pub fn call_runtime<E, Call>(call: &Call) -> Result<()>
 where
     E: Environment,
@@ -31,6 +31,7 @@ use crate::{
     },
     Environment,
     Result,
+    RuntimeVersion,
 };
 use ink_storage_traits::Storable;
 pub use pallet_contracts_uapi::ReturnFlags;
@@ -129,6 +130,27 @@ pub trait EnvBackend {
     /// the case when the code is executing on-chain, then this will have no effect.
     fn debug_message(&mut self, content: &str);
 
+    /// Emits a log with the given raw `topics` and `data`.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`emit_evm_log`][`crate::emit_evm_log`]
+    fn emit_evm_log(&mut self, topics: &[[u8; 32]], data: &[u8]);
+
+    /// Returns `true` if the current call is a dry-run.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`is_dry_run`][`crate::is_dry_run`]
+    fn is_dry_run(&mut self) -> bool;
+
+    /// Returns the version of the runtime, if known.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`runtime_version`][`crate::runtime_version`]
+    fn runtime_version(&mut self) -> Option<RuntimeVersion>;
+
     /// Conducts the crypto hash of the given input and stores the result in `output`.
     fn hash_bytes<H>(&mut self, input: &[u8], output: &mut <H as HashOutput>::Type)
     where
@@ -283,6 +305,20 @@ pub trait TypedEnvBackend: EnvBackend {
     /// For more details visit: [`minimum_balance`][`crate::minimum_balance`]
     fn minimum_balance<E: Environment>(&mut self) -> E::Balance;
 
+    /// Returns the tip paid by the origin of the transaction.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`transaction_tip`][`crate::transaction_tip`]
+    fn transaction_tip<E: Environment>(&mut self) -> E::Balance;
+
+    /// Returns the author of the current block, if known.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`block_author`][`crate::block_author`]
+    fn block_author<E: Environment>(&mut self) -> Option<E::AccountId>;
+
     /// Emits an event with the given event data.
     ///
     /// # Note
@@ -431,6 +467,11 @@ pub trait TypedEnvBackend: EnvBackend {
     where
         E: Environment;
 
+    /// Tries to trigger a runtime dispatchable, i.e. an extrinsic from a pallet.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`call_runtime`][`crate::call_runtime`]
     fn call_runtime<E, Call>(&mut self, call: &Call) -> Result<()>
     where
         E: Environment,
@@ -29,6 +29,8 @@ use crate::{
     Error,
     Gas,
 };
+use ink_prelude::vec::Vec;
+use ink_primitives::Key;
 use num_traits::Zero;
 use pallet_contracts_uapi::CallFlags;
 
@@ -43,6 +45,7 @@ pub struct Call<E: Environment> {
     storage_deposit_limit: Option<E::Balance>,
     transferred_value: E::Balance,
     call_flags: CallFlags,
+    access_keys: Vec<Key>,
 }
 
 impl<E: Environment> Call<E> {
@@ -55,6 +58,7 @@ impl<E: Environment> Call<E> {
             storage_deposit_limit: None,
             transferred_value: E::Balance::zero(),
             call_flags: CallFlags::empty(),
+            access_keys: Vec::new(),
         }
     }
 }
@@ -166,6 +170,27 @@ where
             ..self
         }
     }
+
+    /// Pre-declares the storage keys which the called contract is going to access.
+    ///
+    /// Runtimes supporting access hints can use them to reduce the proof size of the
+    /// call.
+    ///
+    /// # Note
+    ///
+    /// The `contracts` pallet does not support access hints, hence the keys are not
+    /// forwarded on-chain. The off-chain environment records them, they can be
+    /// inspected with `ink_env::test::recorded_calls`.
+    pub fn access_keys(self, access_keys: &[Key]) -> Self {
+        let call_type = self.call_type.value();
+        CallBuilder {
+            call_type: Set(Call {
+                access_keys: access_keys.to_vec(),
+                ..call_type
+            }),
+            ..self
+        }
+    }
 }
 
 impl<E, Args, RetType>
@@ -302,6 +327,12 @@ where
     pub fn call_flags(&self) -> &CallFlags {
         &self.call_type.call_flags
     }
+
+    /// Returns the storage keys pre-declared to be accessed by the call.
+    #[inline]
+    pub fn access_keys(&self) -> &[Key] {
+        &self.call_type.access_keys
+    }
 }
 
 impl<E, Args, R> CallParams<E, Call<E>, Args, R>
@@ -18,6 +18,7 @@ mod call_builder;
 mod common;
 mod create_builder;
 mod execution;
+mod multicall;
 mod selector;
 
 /// Utility types for the cross-contract calling API.
@@ -63,5 +64,9 @@ pub use self::{
         ExecutionInput,
         Executor,
     },
+    multicall::{
+        build_multicall,
+        MultiCall,
+    },
     selector::Selector,
 };
@@ -0,0 +1,149 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    call::{
+        Call,
+        CallParams,
+    },
+    Environment,
+    Error,
+};
+use core::marker::PhantomData;
+use ink_prelude::{
+    boxed::Box,
+    vec::Vec,
+};
+use ink_primitives::MessageResult;
+
+/// A type-erased cross-contract call of a [`MultiCall`].
+type SubCall<'a, R> = Box<dyn Fn() -> Result<MessageResult<R>, Error> + 'a>;
+
+/// Returns a new [`MultiCall`] to batch several cross-contract calls returning `R`.
+///
+/// # Note
+///
+/// `pallet-contracts` offers no host function to execute several calls in a single
+/// host invocation. Hence, the calls are executed sequentially, each with its own host
+/// invocation, in the order in which they were pushed.
+///
+/// # Example
+///
+/// **Note:** The shown example panics because there is currently no cross-calling
+///           support in the off-chain testing environment. However, this code
+///           should work fine in on-chain environments.
+///
+/// ```should_panic
+/// # use ::ink_env::{
+/// #     Environment,
+/// #     DefaultEnvironment,
+/// #     call::{build_call, build_multicall, Selector, ExecutionInput}
+/// # };
+/// # type AccountId = <DefaultEnvironment as Environment>::AccountId;
+/// let get_value = |callee: AccountId| {
+///     build_call::<DefaultEnvironment>()
+///         .call(callee)
+///         .exec_input(ExecutionInput::new(Selector::new([0xDE, 0xAD, 0xBE, 0xEF])))
+///         .returns::<i32>()
+///         .params()
+/// };
+/// let values: Vec<i32> = build_multicall::<DefaultEnvironment, i32>()
+///     .push(get_value(AccountId::from([0x42; 32])))
+///     .push(get_value(AccountId::from([0x43; 32])))
+///     .invoke();
+/// ```
+pub fn build_multicall<'a, E, R>() -> MultiCall<'a, E, R>
+where
+    E: Environment,
+{
+    MultiCall {
+        calls: Vec::new(),
+        _phantom: PhantomData,
+    }
+}
+
+/// Batches several cross-contract calls which return the same type `R`.
+///
+/// The calls may target different contracts and messages with different arguments.
+pub struct MultiCall<'a, E, R>
+where
+    E: Environment,
+{
+    /// The batched calls, in the order of execution.
+    calls: Vec<SubCall<'a, R>>,
+    /// `Environment` is used by the batched calls for correct types
+    _phantom: PhantomData<fn() -> E>,
+}
+
+impl<'a, E, R> MultiCall<'a, E, R>
+where
+    E: Environment + 'a,
+    R: scale::Decode,
+{
+    /// Appends a call to the batch.
+    pub fn push<Args>(mut self, params: CallParams<E, Call<E>, Args, R>) -> Self
+    where
+        Args: scale::Encode + 'a,
+        R: 'a,
+    {
+        self.calls.push(Box::new(move || params.try_invoke()));
+        self
+    }
+
+    /// Returns the number of batched calls.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Returns `true` if no calls were batched.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Invokes all batched calls and returns their results, in the order in which the
+    /// calls were pushed.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any call encounters an [`ink::env::Error`][`crate::Error`]
+    /// or an [`ink::primitives::LangError`][`ink_primitives::LangError`]. If you want to
+    /// handle those use the [`try_invoke`][`MultiCall::try_invoke`] method instead.
+    pub fn invoke(self) -> Vec<R> {
+        self.calls
+            .iter()
+            .map(|call| {
+                call()
+                    .unwrap_or_else(|env_error| {
+                        panic!("Cross-contract call failed with {env_error:?}")
+                    })
+                    .unwrap_or_else(|lang_error| {
+                        panic!("Cross-contract call failed with {lang_error:?}")
+                    })
+            })
+            .collect()
+    }
+
+    /// Invokes all batched calls and returns their results, in the order in which the
+    /// calls were pushed.
+    ///
+    /// # Note
+    ///
+    /// A failing call does not prevent the subsequent calls from being invoked. Its
+    /// outer [`ink::env::Error`][`crate::Error`] or inner
+    /// [`ink::primitives::LangError`][`ink_primitives::LangError`] is returned at its
+    /// position instead.
+    pub fn try_invoke(self) -> Vec<Result<MessageResult<R>, Error>> {
+        self.calls.iter().map(|call| call()).collect()
+    }
+}
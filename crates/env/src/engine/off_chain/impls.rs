@@ -41,9 +41,13 @@ use crate::{
     EnvBackend,
     Environment,
     Result,
+    RuntimeVersion,
     TypedEnvBackend,
 };
-use ink_engine::ext::Engine;
+use ink_engine::{
+    ext::Engine,
+    test_api::RecordedCall,
+};
 use ink_storage_traits::{
     decode_all,
     Storable,
@@ -144,6 +148,21 @@ where
         self.topics.push(off_hash);
     }
 
+    fn push_hashed_topic<H>(&mut self, topic_bytes: &[u8])
+    where
+        H: CryptoHash,
+        <H as HashOutput>::Type: AsRef<[u8]>,
+    {
+        let mut result = <E as Environment>::Hash::CLEAR_HASH;
+        let len_result = result.as_ref().len();
+        let mut hash_output = <H as HashOutput>::Type::default();
+        <H as CryptoHash>::hash(topic_bytes, &mut hash_output);
+        let hash_output = hash_output.as_ref();
+        let copy_len = core::cmp::min(hash_output.len(), len_result);
+        result.as_mut()[0..copy_len].copy_from_slice(&hash_output[0..copy_len]);
+        self.topics.push(result.as_ref().to_vec());
+    }
+
     fn output(self) -> Self::Output {
         let mut all: Vec<u8> = Vec::new();
 
@@ -157,6 +176,22 @@ where
 }
 
 impl EnvInstance {
+    /// Returns by how much the balance of the executed contract changed since the call
+    /// started.
+    ///
+    /// For more details visit:
+    /// [`balance_delta_since_call_start`][`crate::balance_delta_since_call_start`]
+    pub(crate) fn balance_delta_since_call_start<E: Environment>(
+        &mut self,
+    ) -> E::Balance {
+        self.get_property::<E::Balance>(Engine::balance_delta_since_call_start)
+            .unwrap_or_else(|error| {
+                panic!(
+                    "could not read `balance_delta_since_call_start` property: {error:?}"
+                )
+            })
+    }
+
     /// Returns the contract property value.
     fn get_property<T>(
         &mut self,
@@ -245,6 +280,23 @@ impl EnvBackend for EnvInstance {
         self.engine.debug_message(message)
     }
 
+    fn emit_evm_log(&mut self, topics: &[[u8; 32]], data: &[u8]) {
+        self.engine
+            .deposit_event(&scale::Encode::encode(topics)[..], data);
+    }
+
+    fn is_dry_run(&mut self) -> bool {
+        self.engine.is_dry_run()
+    }
+
+    fn runtime_version(&mut self) -> Option<RuntimeVersion> {
+        self.engine.runtime_version().map(|version| {
+            scale::Decode::decode(&mut &version[..]).unwrap_or_else(|error| {
+                panic!("could not read `runtime_version` property: {error:?}")
+            })
+        })
+    }
+
     fn hash_bytes<H>(&mut self, input: &[u8], output: &mut <H as HashOutput>::Type)
     where
         H: CryptoHash,
@@ -368,8 +420,8 @@ impl EnvBackend for EnvInstance {
         Ok(decoded)
     }
 
-    fn set_code_hash(&mut self, _code_hash: &[u8]) -> Result<()> {
-        unimplemented!("off-chain environment does not support `set_code_hash`")
+    fn set_code_hash(&mut self, code_hash: &[u8]) -> Result<()> {
+        self.engine.set_code_hash(code_hash).map_err(Into::into)
     }
 }
 
@@ -428,6 +480,21 @@ impl TypedEnvBackend for EnvInstance {
             })
     }
 
+    fn transaction_tip<E: Environment>(&mut self) -> E::Balance {
+        self.get_property::<E::Balance>(Engine::transaction_tip)
+            .unwrap_or_else(|error| {
+                panic!("could not read `transaction_tip` property: {error:?}")
+            })
+    }
+
+    fn block_author<E: Environment>(&mut self) -> Option<E::AccountId> {
+        self.engine.block_author().map(|author| {
+            scale::Decode::decode(&mut &author[..]).unwrap_or_else(|error| {
+                panic!("could not read `block_author` property: {error:?}")
+            })
+        })
+    }
+
     fn emit_event<E, Evt>(&mut self, event: Evt)
     where
         E: Environment,
@@ -453,13 +520,17 @@ impl TypedEnvBackend for EnvInstance {
 
     fn invoke_contract<E, Args, R>(
         &mut self,
-        _params: &CallParams<E, Call<E>, Args, R>,
+        params: &CallParams<E, Call<E>, Args, R>,
     ) -> Result<ink_primitives::MessageResult<R>>
     where
         E: Environment,
         Args: scale::Encode,
         R: scale::Decode,
     {
+        self.engine.record_call(RecordedCall {
+            callee: scale::Encode::encode(params.callee()),
+            access_keys: params.access_keys().to_vec(),
+        });
         unimplemented!("off-chain environment does not support contract invocation")
     }
 
@@ -564,28 +635,34 @@ impl TypedEnvBackend for EnvInstance {
     where
         E: Environment,
     {
-        unimplemented!("off-chain environment does not support cross-contract calls")
+        self.engine.caller_is_origin()
     }
 
-    fn code_hash<E>(&mut self, _account: &E::AccountId) -> Result<E::Hash>
+    fn code_hash<E>(&mut self, account: &E::AccountId) -> Result<E::Hash>
     where
         E: Environment,
     {
-        unimplemented!("off-chain environment does not support `code_hash`")
+        let output = self.engine.code_hash(&scale::Encode::encode(account))?;
+        let hash = scale::Decode::decode(&mut &output[..])?;
+        Ok(hash)
     }
 
     fn own_code_hash<E>(&mut self) -> Result<E::Hash>
     where
         E: Environment,
     {
-        unimplemented!("off-chain environment does not support `own_code_hash`")
+        let output = self.engine.own_code_hash()?;
+        let hash = scale::Decode::decode(&mut &output[..])?;
+        Ok(hash)
     }
 
-    fn call_runtime<E, Call>(&mut self, _call: &Call) -> Result<()>
+    fn call_runtime<E, Call>(&mut self, call: &Call) -> Result<()>
     where
         E: Environment,
+        Call: scale::Encode,
     {
-        unimplemented!("off-chain environment does not support `call_runtime`")
+        self.engine.record_runtime_call(scale::Encode::encode(call));
+        Ok(())
     }
 
     fn lock_delegate_dependency<E>(&mut self, _code_hash: &E::Hash)
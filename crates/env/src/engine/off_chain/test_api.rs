@@ -20,7 +20,10 @@ use super::{
 };
 use crate::{
     Environment,
+    Gas,
     Result,
+    ReturnErrorCode,
+    RuntimeVersion,
 };
 use core::fmt::Debug;
 use ink_engine::test_api::RecordedDebugMessages;
@@ -38,6 +41,15 @@ pub struct EmittedEvent {
     pub data: Vec<u8>,
 }
 
+/// Record for a cross-contract call issued by a contract.
+#[derive(Clone)]
+pub struct RecordedCall {
+    /// The encoded account id of the called contract.
+    pub callee: Vec<u8>,
+    /// The storage keys which were pre-declared to be accessed by the call.
+    pub access_keys: Vec<ink_primitives::Key>,
+}
+
 /// Sets the balance of the account to the given balance.
 ///
 /// # Note
@@ -150,6 +162,34 @@ where
     })
 }
 
+/// Sets the code hash of the contract at `account_id`.
+///
+/// See [`code_hash`][`crate::code_hash`] and [`own_code_hash`][`crate::own_code_hash`].
+pub fn set_code_hash_of<T>(account_id: T::AccountId, code_hash: T::Hash)
+where
+    T: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.set_code_hash_of(
+            scale::Encode::encode(&account_id),
+            scale::Encode::encode(&code_hash),
+        );
+    })
+}
+
+/// Sets the result of the next call to [`set_code_hash`][`crate::set_code_hash`] of
+/// the contract under test.
+///
+/// By default, `set_code_hash` succeeds and replaces the code hash returned by
+/// [`own_code_hash`][`crate::own_code_hash`]. An `Err` is returned once as is, without
+/// changing the code hash, e.g. to test how an upgrade handles
+/// `ReturnErrorCode::CodeNotFound`.
+pub fn set_code_hash_result(result: core::result::Result<(), ReturnErrorCode>) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.set_code_hash_result(result);
+    })
+}
+
 /// Sets an account as a contract
 pub fn set_contract<T>(contract: T::AccountId)
 where
@@ -217,6 +257,10 @@ where
 
 /// Transfers value from the caller account to the contract.
 ///
+/// The balance of the contract before the transfer is recorded as its balance at the
+/// start of the call, see
+/// [`balance_delta_since_call_start`][`crate::balance_delta_since_call_start`].
+///
 /// Please note that the acting accounts should be set with [`set_caller()`] and
 /// [`set_callee()`] beforehand.
 pub fn transfer_in<T>(value: T::Balance)
@@ -247,6 +291,9 @@ where
         instance
             .engine
             .set_balance(caller, caller_old_balance - value);
+        instance
+            .engine
+            .set_balance_at_call_start(contract_old_balance);
         instance
             .engine
             .set_balance(callee, contract_old_balance + value);
@@ -269,6 +316,38 @@ where
     })
 }
 
+/// Returns the storage keys of all cells used by the currently executing contract.
+///
+/// The keys are returned in no particular order.
+pub fn contract_storage_keys() -> Vec<Vec<u8>> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.contract_storage_keys()
+    })
+}
+
+/// Sets the gas limit of the contract execution and resets the gas consumed so far.
+///
+/// The off-chain environment charges a fixed amount of gas for every access to contract
+/// storage and panics if the execution runs out of gas. The gas left can be read with
+/// [`gas_left`][`crate::gas_left`].
+pub fn set_gas_limit(gas_limit: Gas) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.set_gas_limit(gas_limit);
+    })
+}
+
+/// Consumes `gas` from the gas left for the contract execution, e.g. to emulate an
+/// expensive computation.
+///
+/// # Panics
+///
+/// If the execution runs out of gas.
+pub fn consume_gas(gas: Gas) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.charge_gas(gas);
+    })
+}
+
 /// Sets the block timestamp for the next [`advance_block`] invocation.
 pub fn set_block_timestamp<T>(value: T::Timestamp)
 where
@@ -289,6 +368,113 @@ where
     })
 }
 
+/// Sets whether the next call is a dry-run.
+///
+/// See [`is_dry_run`][`crate::is_dry_run`].
+pub fn set_dry_run(is_dry_run: bool) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.set_dry_run(is_dry_run);
+    })
+}
+
+/// Sets the version of the runtime.
+///
+/// See [`runtime_version`][`crate::runtime_version`].
+pub fn set_runtime_version(version: RuntimeVersion) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .engine
+            .set_runtime_version(scale::Encode::encode(&version));
+    })
+}
+
+/// Sets whether the caller of the next call is the origin of the call stack.
+///
+/// Defaults to `true` in [`run_test`].
+///
+/// See [`caller_is_origin`][`crate::caller_is_origin`].
+pub fn set_caller_is_origin(caller_is_origin: bool) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.set_caller_is_origin(caller_is_origin);
+    })
+}
+
+/// Sets the minimum balance (i.e. the chain's existential deposit).
+///
+/// See [`minimum_balance`][`crate::minimum_balance`].
+pub fn set_minimum_balance<T>(minimum_balance: T::Balance)
+where
+    T: Environment<Balance = u128>, // Just temporary for the MVP!
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.set_minimum_balance(minimum_balance);
+    })
+}
+
+/// Sets the tip paid by the origin of the transaction.
+///
+/// See [`transaction_tip`][`crate::transaction_tip`].
+pub fn set_transaction_tip<T>(tip: T::Balance)
+where
+    T: Environment<Balance = u128>, // Just temporary for the MVP!
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.set_transaction_tip(tip);
+    })
+}
+
+/// Sets the author of the current block.
+///
+/// See [`block_author`][`crate::block_author`].
+pub fn set_block_author<T>(author: T::AccountId)
+where
+    T: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .engine
+            .set_block_author(scale::Encode::encode(&author));
+    })
+}
+
+/// Sets the fee charged per unit of gas, making [`weight_to_fee`] return
+/// `gas * fee_per_gas`.
+///
+/// The default fee per gas is `100`.
+///
+/// [`weight_to_fee`]: crate::weight_to_fee
+pub fn set_weight_to_fee<T>(fee_per_gas: T::Balance)
+where
+    T: Environment<Balance = u128>, // Just temporary for the MVP!
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.set_gas_price(fee_per_gas);
+    })
+}
+
+/// A snapshot of the off-chain environment, taken with [`snapshot`].
+pub struct Snapshot(ink_engine::test_api::EngineSnapshot);
+
+/// Takes a snapshot of the off-chain environment.
+///
+/// The snapshot comprises the contract storage and balances, the recorded events and
+/// debug messages, and the execution context (e.g. caller, callee, block number). It
+/// can be passed to [`restore`] to roll the environment back to this state.
+pub fn snapshot() -> Snapshot {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        Snapshot(instance.engine.snapshot())
+    })
+}
+
+/// Restores the off-chain environment to the state captured in `snapshot`.
+///
+/// See [`snapshot`].
+pub fn restore(snapshot: Snapshot) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.restore(snapshot.0);
+    })
+}
+
 /// Runs the given closure test function with the default configuration
 /// for the off-chain environment.
 pub fn run_test<T, F>(f: F) -> Result<()>
@@ -304,6 +490,7 @@ where
         let encoded_alice = scale::Encode::encode(&default_accounts.alice);
         instance.engine.set_caller(encoded_alice.clone());
         instance.engine.set_callee(encoded_alice.clone());
+        instance.engine.set_caller_is_origin(true);
 
         // set up the funds for the default accounts
         let substantial = 1_000_000;
@@ -374,6 +561,56 @@ pub fn recorded_events() -> impl Iterator<Item = EmittedEvent> {
     })
 }
 
+/// Returns the recorded cross-contract calls in order.
+///
+/// # Note
+///
+/// The off-chain environment does not execute cross-contract calls, it only records
+/// them before panicking.
+pub fn recorded_calls() -> impl Iterator<Item = RecordedCall> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .engine
+            .get_recorded_calls()
+            .map(|call: ink_engine::test_api::RecordedCall| call.into())
+    })
+}
+
+/// Returns the encoded runtime calls in order.
+///
+/// # Note
+///
+/// The off-chain environment does not dispatch runtime calls, it only records them.
+pub fn recorded_runtime_calls() -> impl Iterator<Item = Vec<u8>> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine.get_recorded_runtime_calls()
+    })
+}
+
+/// Returns the beneficiary and the transferred remaining balance of the contract
+/// termination, or `None` if the contract did not terminate.
+///
+/// # Note
+///
+/// Terminating the contract panics in the off-chain environment, the termination is
+/// recorded before.
+pub fn termination_info<T>() -> Option<(T::AccountId, T::Balance)>
+where
+    T: Environment<Balance = u128>, // Just temporary for the MVP!
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .engine
+            .get_termination()
+            .map(|(beneficiary, value)| {
+                let beneficiary =
+                    <T::AccountId as scale::Decode>::decode(&mut &beneficiary[..])
+                        .unwrap_or_else(|err| panic!("input can not be decoded: {err}"));
+                (beneficiary, value)
+            })
+    })
+}
+
 /// Tests if a contract terminates successfully after `self.env().terminate()`
 /// has been called.
 ///
@@ -41,3 +41,377 @@ fn topics_builder() -> Result<()> {
         Ok(())
     })
 }
+
+#[test]
+fn topics_builder_hashes_topic_bytes() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        // given
+        let mut builder = TopicsBuilder::default();
+
+        // when
+        TopicsBuilderBackend::<crate::DefaultEnvironment>::push_hashed_topic::<
+            crate::hash::Keccak256,
+        >(&mut builder, b"ink");
+
+        // then
+        let mut expected = [0u8; 32];
+        crate::hash_bytes::<crate::hash::Keccak256>(b"ink", &mut expected);
+        assert_eq!(builder.topics, vec![expected.to_vec()]);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn spendable_balance_subtracts_minimum_balance() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        // given
+        let contract = crate::test::callee::<crate::DefaultEnvironment>();
+        crate::test::set_account_balance::<crate::DefaultEnvironment>(contract, 1_500);
+        crate::test::set_minimum_balance::<crate::DefaultEnvironment>(1_000);
+
+        // then
+        assert_eq!(crate::spendable_balance::<crate::DefaultEnvironment>(), 500);
+
+        // when
+        crate::test::set_minimum_balance::<crate::DefaultEnvironment>(2_000);
+
+        // then
+        assert_eq!(crate::spendable_balance::<crate::DefaultEnvironment>(), 0);
+        Ok(())
+    })
+}
+
+#[test]
+fn weight_to_fee_uses_configured_fee_per_gas() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        // given
+        assert_eq!(crate::weight_to_fee::<crate::DefaultEnvironment>(10), 1_000);
+
+        // when
+        crate::test::set_weight_to_fee::<crate::DefaultEnvironment>(7);
+
+        // then
+        assert_eq!(crate::weight_to_fee::<crate::DefaultEnvironment>(0), 0);
+        assert_eq!(crate::weight_to_fee::<crate::DefaultEnvironment>(10), 70);
+        assert_eq!(
+            crate::weight_to_fee::<crate::DefaultEnvironment>(u64::MAX),
+            7 * u128::from(u64::MAX)
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn none_topic_is_blake2x256_of_its_preimage() {
+    let mut expected = [0u8; 32];
+    crate::hash_bytes::<crate::hash::Blake2x256>(
+        b"ink::event::topic::None",
+        &mut expected,
+    );
+    assert_eq!(crate::event::NONE_TOPIC, expected);
+}
+
+#[test]
+fn restore_undoes_mutations_since_snapshot() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        // given
+        crate::set_contract_storage(&42u32, &1u32);
+        let snapshot = crate::test::snapshot();
+
+        // when
+        crate::set_contract_storage(&42u32, &2u32);
+        crate::set_contract_storage(&43u32, &3u32);
+        crate::test::set_caller::<crate::DefaultEnvironment>(accounts.bob);
+        crate::test::restore(snapshot);
+
+        // then
+        assert_eq!(crate::get_contract_storage::<u32, u32>(&42u32)?, Some(1));
+        assert_eq!(crate::get_contract_storage::<u32, u32>(&43u32)?, None);
+        assert_eq!(crate::caller::<crate::DefaultEnvironment>(), accounts.alice);
+        Ok(())
+    })
+}
+
+#[test]
+fn code_hash_works() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        // given
+        let code_hash = ink_primitives::Hash::from([0x42; 32]);
+        crate::test::set_code_hash_of::<crate::DefaultEnvironment>(
+            accounts.bob,
+            code_hash,
+        );
+
+        // then
+        assert_eq!(
+            crate::code_hash::<crate::DefaultEnvironment>(&accounts.bob),
+            Ok(code_hash)
+        );
+        assert_eq!(
+            crate::code_hash::<crate::DefaultEnvironment>(&accounts.charlie),
+            Err(crate::Error::ReturnError(
+                crate::ReturnErrorCode::KeyNotFound
+            ))
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn own_code_hash_works() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        // given
+        let code_hash = ink_primitives::Hash::from([0x42; 32]);
+        assert!(crate::own_code_hash::<crate::DefaultEnvironment>().is_err());
+
+        // when
+        crate::test::set_code_hash_of::<crate::DefaultEnvironment>(
+            accounts.alice,
+            code_hash,
+        );
+
+        // then
+        assert_eq!(
+            crate::own_code_hash::<crate::DefaultEnvironment>(),
+            Ok(code_hash)
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn call_access_keys_are_recorded() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        // given
+        let params = crate::call::build_call::<crate::DefaultEnvironment>()
+            .call(accounts.bob)
+            .access_keys(&[1, 2])
+            .exec_input(crate::call::ExecutionInput::new(
+                crate::call::Selector::new([0xDE, 0xAD, 0xBE, 0xEF]),
+            ))
+            .returns::<()>()
+            .params();
+
+        // when
+        // The off-chain environment does not execute the call, it panics after
+        // recording it.
+        let result = std::panic::catch_unwind(|| params.try_invoke());
+        assert!(result.is_err());
+
+        // then
+        let calls = crate::test::recorded_calls().collect::<Vec<_>>();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].callee, scale::Encode::encode(&accounts.bob));
+        assert_eq!(calls[0].access_keys, [1, 2]);
+        Ok(())
+    })
+}
+
+#[test]
+fn runtime_calls_are_recorded() -> Result<()> {
+    #[derive(scale::Encode)]
+    enum RuntimeCall {
+        #[codec(index = 4)]
+        Balances(BalancesCall),
+    }
+
+    #[derive(scale::Encode)]
+    enum BalancesCall {
+        #[codec(index = 0)]
+        TransferAllowDeath {
+            dest: ink_primitives::AccountId,
+            #[codec(compact)]
+            value: u128,
+        },
+    }
+
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        // given
+        let call = RuntimeCall::Balances(BalancesCall::TransferAllowDeath {
+            dest: accounts.bob,
+            value: 100,
+        });
+
+        // when
+        crate::call_runtime::<crate::DefaultEnvironment, _>(&call)?;
+
+        // then
+        let mut expected = vec![4, 0];
+        expected.extend_from_slice(&scale::Encode::encode(&accounts.bob));
+        expected.extend_from_slice(&scale::Encode::encode(&scale::Compact(100u128)));
+        let calls = crate::test::recorded_runtime_calls().collect::<Vec<_>>();
+        assert_eq!(calls, vec![expected]);
+        Ok(())
+    })
+}
+
+#[test]
+fn evm_logs_are_recorded() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        // given
+        let topics = [[0x01; 32], [0x02; 32], [0x03; 32]];
+        let data = [0xDE, 0xAD, 0xBE, 0xEF];
+
+        // when
+        crate::emit_evm_log(&topics, &data);
+        crate::emit_evm_log(&[], &[]);
+
+        // then
+        let logs = crate::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].topics, topics.map(|topic| topic.to_vec()));
+        assert_eq!(logs[0].data, data);
+        assert!(logs[1].topics.is_empty());
+        assert!(logs[1].data.is_empty());
+        Ok(())
+    })
+}
+
+#[test]
+#[should_panic(expected = "an EVM log has at most 4 topics, got 5")]
+fn evm_log_with_too_many_topics_panics() {
+    crate::emit_evm_log(&[[0x01; 32]; 5], &[]);
+}
+
+#[test]
+fn gas_left_decreases_with_storage_accesses() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        // given
+        crate::test::set_gas_limit(100_000_000);
+        let gas_at_start = crate::gas_left::<crate::DefaultEnvironment>();
+        assert_eq!(gas_at_start, 100_000_000);
+
+        // when
+        crate::set_contract_storage(&1u32, &42u64);
+        let gas_after_write = crate::gas_left::<crate::DefaultEnvironment>();
+        let _: Option<u64> = crate::get_contract_storage(&1u32)?;
+        crate::test::consume_gas(1_000);
+        let gas_after_read = crate::gas_left::<crate::DefaultEnvironment>();
+
+        // then
+        assert!(gas_after_write < gas_at_start);
+        assert!(gas_after_read < gas_after_write);
+        Ok(())
+    })
+}
+
+#[test]
+#[should_panic(expected = "contract execution ran out of gas")]
+fn running_out_of_gas_panics() {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        crate::test::set_gas_limit(1_000);
+        crate::test::consume_gas(1_001);
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[test]
+fn block_author_works() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        // given
+        assert_eq!(crate::block_author::<crate::DefaultEnvironment>(), None);
+
+        // when
+        crate::test::set_block_author::<crate::DefaultEnvironment>(accounts.django);
+
+        // then
+        assert_eq!(
+            crate::block_author::<crate::DefaultEnvironment>(),
+            Some(accounts.django)
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn termination_info_records_beneficiary_and_remaining_balance() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        // given
+        let contract = crate::test::callee::<crate::DefaultEnvironment>();
+        crate::test::set_account_balance::<crate::DefaultEnvironment>(contract, 1_500);
+        let bob_balance_before =
+            crate::test::get_account_balance::<crate::DefaultEnvironment>(accounts.bob)?;
+        assert_eq!(
+            crate::test::termination_info::<crate::DefaultEnvironment>(),
+            None
+        );
+
+        // when
+        let result = std::panic::catch_unwind(|| {
+            crate::terminate_contract::<crate::DefaultEnvironment>(accounts.bob)
+        });
+        assert!(result.is_err());
+
+        // then
+        assert_eq!(
+            crate::test::termination_info::<crate::DefaultEnvironment>(),
+            Some((accounts.bob, 1_500))
+        );
+        assert_eq!(
+            crate::test::get_account_balance::<crate::DefaultEnvironment>(accounts.bob)?,
+            bob_balance_before + 1_500
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn runtime_version_works() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        // given
+        assert_eq!(crate::runtime_version(), None);
+        let version = crate::RuntimeVersion {
+            spec_name: "node".into(),
+            spec_version: 100,
+        };
+
+        // when
+        crate::test::set_runtime_version(version.clone());
+
+        // then
+        assert_eq!(crate::runtime_version(), Some(version));
+        Ok(())
+    })
+}
+
+#[test]
+fn set_code_hash_works() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        // given
+        let code_hash = ink_primitives::Hash::from([0x42; 32]);
+
+        // when
+        crate::set_code_hash::<crate::DefaultEnvironment>(&code_hash)?;
+
+        // then
+        assert_eq!(
+            crate::own_code_hash::<crate::DefaultEnvironment>(),
+            Ok(code_hash)
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn set_code_hash_returns_injected_error() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        // given
+        let code_hash = ink_primitives::Hash::from([0x42; 32]);
+        crate::test::set_code_hash_result(Err(crate::ReturnErrorCode::CodeNotFound));
+
+        // when
+        let result = crate::set_code_hash::<crate::DefaultEnvironment>(&code_hash);
+
+        // then
+        assert_eq!(
+            result,
+            Err(crate::Error::ReturnError(
+                crate::ReturnErrorCode::CodeNotFound
+            ))
+        );
+        assert!(crate::own_code_hash::<crate::DefaultEnvironment>().is_err());
+        Ok(())
+    })
+}
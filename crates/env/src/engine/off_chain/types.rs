@@ -16,7 +16,10 @@
 //! of this crate.
 
 use super::{
-    test_api::EmittedEvent,
+    test_api::{
+        EmittedEvent,
+        RecordedCall,
+    },
     AccountError,
     Error,
     OffChainError,
@@ -31,6 +34,15 @@ impl From<ink_engine::test_api::EmittedEvent> for EmittedEvent {
     }
 }
 
+impl From<ink_engine::test_api::RecordedCall> for RecordedCall {
+    fn from(call: ink_engine::test_api::RecordedCall) -> Self {
+        RecordedCall {
+            callee: call.callee,
+            access_keys: call.access_keys,
+        }
+    }
+}
+
 impl From<ink_engine::Error> for Error {
     fn from(err: ink_engine::Error) -> Self {
         let e = match err {
@@ -45,6 +45,7 @@ use crate::{
     Environment,
     FromLittleEndian,
     Result,
+    RuntimeVersion,
     TypedEnvBackend,
 };
 use ink_storage_traits::{
@@ -161,6 +162,21 @@ where
         self.scoped_buffer.append_encoded(&result);
     }
 
+    fn push_hashed_topic<H>(&mut self, topic_bytes: &[u8])
+    where
+        H: CryptoHash,
+        <H as HashOutput>::Type: AsRef<[u8]>,
+    {
+        let mut result = <E as Environment>::Hash::CLEAR_HASH;
+        let len_result = result.as_ref().len();
+        let mut hash_output = <H as HashOutput>::Type::default();
+        <H as CryptoHash>::hash(topic_bytes, &mut hash_output);
+        let hash_output = hash_output.as_ref();
+        let copy_len = core::cmp::min(hash_output.len(), len_result);
+        result.as_mut()[0..copy_len].copy_from_slice(&hash_output[0..copy_len]);
+        self.scoped_buffer.append_encoded(&result);
+    }
+
     fn output(mut self) -> Self::Output {
         let encoded_topics = self.scoped_buffer.take_appended();
         (self.scoped_buffer, encoded_topics)
@@ -311,6 +327,22 @@ impl EnvBackend for EnvInstance {
         }
     }
 
+    fn emit_evm_log(&mut self, topics: &[[u8; 32]], data: &[u8]) {
+        let mut scope = self.scoped_buffer();
+        let enc_topics = scope.take_encoded(&topics);
+        ext::deposit_event(enc_topics, data);
+    }
+
+    fn is_dry_run(&mut self) -> bool {
+        // `pallet-contracts` does not expose whether the call is a dry-run.
+        false
+    }
+
+    fn runtime_version(&mut self) -> Option<RuntimeVersion> {
+        // `pallet-contracts` does not expose the version of the runtime.
+        None
+    }
+
     fn hash_bytes<H>(&mut self, input: &[u8], output: &mut <H as HashOutput>::Type)
     where
         H: CryptoHash,
@@ -416,6 +448,16 @@ impl TypedEnvBackend for EnvInstance {
         self.get_property_little_endian::<E::Balance>(ext::minimum_balance)
     }
 
+    fn transaction_tip<E: Environment>(&mut self) -> E::Balance {
+        // `pallet-contracts` does not expose the tip of the transaction.
+        E::Balance::from(0u8)
+    }
+
+    fn block_author<E: Environment>(&mut self) -> Option<E::AccountId> {
+        // `pallet-contracts` does not expose the author of the block.
+        None
+    }
+
     fn emit_event<E, Evt>(&mut self, event: Evt)
     where
         E: Environment,
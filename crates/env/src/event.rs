@@ -14,7 +14,24 @@
 
 //! This module contains the implementation for the event topic logic.
 
-use crate::Environment;
+use crate::{
+    hash::{
+        CryptoHash,
+        HashOutput,
+    },
+    Environment,
+};
+
+/// The topic published for a topic field whose value is `Option::None`.
+///
+/// This is the [`Blake2x256`] hash of `b"ink::event::topic::None"`. A topic field
+/// whose value is `Some(value)` publishes the topic of `value` itself, so the sentinel
+/// can only coincide with a `Some` topic whose encoding is exactly this hash.
+pub const NONE_TOPIC: [u8; 32] = [
+    0xc3, 0xe6, 0x54, 0x83, 0x79, 0x10, 0x53, 0x6f, 0x05, 0xa7, 0x1e, 0x7d, 0xcb, 0x18,
+    0xb0, 0x54, 0x4f, 0x0c, 0x2b, 0x54, 0x1c, 0xb6, 0x1d, 0xb8, 0xbe, 0xc1, 0x98, 0x6a,
+    0x7c, 0x5b, 0xa4, 0x73,
+];
 
 /// The concrete implementation that is guided by the topics builder.
 ///
@@ -35,6 +52,13 @@ where
     where
         T: scale::Encode;
 
+    /// Pushes the hash of `topic_bytes` as another topic for serialization to the
+    /// backend.
+    fn push_hashed_topic<H>(&mut self, topic_bytes: &[u8])
+    where
+        H: CryptoHash,
+        <H as HashOutput>::Type: AsRef<[u8]>;
+
     /// Extracts the serialized topics.
     fn output(self) -> Self::Output;
 }
@@ -102,6 +126,8 @@ where
 {
     /// Pushes another event topic to be serialized through the topics builder.
     ///
+    /// A `None` value is published as the [`NONE_TOPIC`] sentinel.
+    ///
     /// Returns a topics builder that expects one less event topic for serialization
     /// than before the call.
     pub fn push_topic<T>(
@@ -111,11 +137,44 @@ where
     where
         T: scale::Encode,
     {
-        // Only publish the topic if it is not an `Option::None`.
+        // An `Option::None` topic is published as the fixed `NONE_TOPIC` sentinel.
         if let Some(topic) = value {
             self.backend.push_topic::<T>(topic);
         } else {
-            self.backend.push_topic::<u8>(&0u8);
+            self.backend.push_topic::<[u8; 32]>(&NONE_TOPIC);
+        }
+        TopicsBuilder {
+            backend: self.backend,
+            state: Default::default(),
+        }
+    }
+
+    /// Pushes the `H` hash of the bytes of another event topic to be serialized
+    /// through the topics builder.
+    ///
+    /// In contrast to [`TopicsBuilder::push_topic`] the topic is always the hash of the
+    /// raw bytes of the value, not of its SCALE encoding, e.g. `keccak256(value)` for a
+    /// `String` like for indexed dynamic values of EVM logs. This is used for the
+    /// `String` and `Vec<u8>` topics of events with `topic_hash = "keccak"`.
+    ///
+    /// A `None` value is published as the [`NONE_TOPIC`] sentinel.
+    ///
+    /// Returns a topics builder that expects one less event topic for serialization
+    /// than before the call.
+    pub fn push_hashed_topic<H, T>(
+        mut self,
+        value: Option<&T>,
+    ) -> TopicsBuilder<<S as SomeRemainingTopics>::Next, E, B>
+    where
+        H: CryptoHash,
+        <H as HashOutput>::Type: AsRef<[u8]>,
+        T: AsRef<[u8]> + ?Sized,
+    {
+        // An `Option::None` topic is published as the fixed `NONE_TOPIC` sentinel.
+        if let Some(topic) = value {
+            self.backend.push_hashed_topic::<H>(topic.as_ref());
+        } else {
+            self.backend.push_topic::<[u8; 32]>(&NONE_TOPIC);
         }
         TopicsBuilder {
             backend: self.backend,
@@ -131,6 +131,8 @@ pub use self::{
         FromLittleEndian,
         Gas,
         NoChainExtension,
+        RuntimeVersion,
+        StorageKeySalt,
     },
 };
 use ink_primitives::Clear;
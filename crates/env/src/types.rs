@@ -32,11 +32,15 @@
 //! the trait bounds on the `Environment` trait types.
 
 use super::arithmetic::AtLeast32BitUnsigned;
+use core::marker::PhantomData;
+use ink_prelude::string::String;
 use ink_primitives::{
     AccountId,
     Clear,
     Hash,
+    Key,
 };
+use ink_storage_traits::StorageKey;
 #[cfg(feature = "std")]
 use scale_info::TypeInfo;
 
@@ -124,6 +128,16 @@ pub trait Environment: Clone {
     /// runtime.
     const MAX_EVENT_TOPICS: usize;
 
+    /// The salt that is combined with the storage keys of ink! storage items.
+    ///
+    /// Storage items declared with `#[ink::storage_item(env = ...)]` combine the storage
+    /// keys of their fields with this salt, see [`StorageKeySalt`]. This allows a
+    /// contract to share its storage layout with e.g. a runtime pallet that uses a
+    /// different key derivation.
+    ///
+    /// The default of `0` leaves the storage keys unchanged.
+    const STORAGE_KEY_SALT: Key = 0;
+
     /// The account id type.
     type AccountId: 'static
         + scale::Codec
@@ -193,6 +207,40 @@ pub trait Environment: Clone {
     type ChainExtension;
 }
 
+/// Storage key salt of the environment `E`, see [`Environment::STORAGE_KEY_SALT`].
+///
+/// This type can be used wherever a [`StorageKey`] salt is expected, e.g. as the
+/// `ParentKey` of a `ManualKey`.
+#[cfg_attr(feature = "std", derive(TypeInfo))]
+#[cfg_attr(feature = "std", scale_info(skip_type_params(E)))]
+pub struct StorageKeySalt<E>(PhantomData<fn() -> E>);
+
+impl<E: Environment> StorageKey for StorageKeySalt<E> {
+    const KEY: Key = E::STORAGE_KEY_SALT;
+}
+
+impl<E> Default for StorageKeySalt<E> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<E> Clone for StorageKeySalt<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for StorageKeySalt<E> {}
+
+impl<E: Environment> core::fmt::Debug for StorageKeySalt<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("StorageKeySalt")
+            .field("key", &<Self as StorageKey>::KEY)
+            .finish()
+    }
+}
+
 /// Placeholder for chains that have no defined chain extension.
 #[cfg_attr(feature = "std", derive(TypeInfo))]
 pub enum NoChainExtension {}
@@ -224,3 +272,15 @@ pub type Gas = u64;
 
 /// The default block number type.
 pub type BlockNumber = u32;
+
+/// The version of the runtime a contract is executed in.
+///
+/// See [`runtime_version`][`crate::runtime_version`].
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(TypeInfo))]
+pub struct RuntimeVersion {
+    /// The name of the runtime specification, e.g. `"node"`.
+    pub spec_name: String,
+    /// The version of the runtime specification.
+    pub spec_version: u32,
+}
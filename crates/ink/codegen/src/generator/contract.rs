@@ -19,7 +19,11 @@ use crate::{
 };
 use derive_more::From;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned;
 
 /// Generates code for the entirety of the ink! contract.
 #[derive(From)]
@@ -42,6 +46,7 @@ impl GenerateCode for Contract<'_> {
             let event_generator = generator::Event::from(event);
             event_generator.generate_code()
         });
+        let event_signatures = self.generate_event_signatures();
         let dispatch2 = self.generate_code_using::<generator::Dispatch>();
         let item_impls = self.generate_code_using::<generator::ItemImpls>();
         let metadata = self.generate_code_using::<generator::Metadata>();
@@ -59,6 +64,7 @@ impl GenerateCode for Contract<'_> {
                 #env
                 #storage
                 #( #events )*
+                #event_signatures
                 #dispatch2
                 #item_impls
                 #contract_reference
@@ -68,3 +74,52 @@ impl GenerateCode for Contract<'_> {
         }
     }
 }
+
+impl Contract<'_> {
+    /// Generates the signature topics of all non-anonymous events defined in the
+    /// contract module or declared to be emitted by a message.
+    ///
+    /// The topics are taken from the `Event` implementations, hence events defined
+    /// outside of the contract with `#[ink::event]` are included if a message declares
+    /// them with `#[ink(emits = Event)]`. Unlike the event metadata, these are
+    /// available in `no_std` and can therefore be used by on-chain logic, e.g. to verify
+    /// the events emitted by another contract.
+    fn generate_event_signatures(&self) -> TokenStream2 {
+        let module = self.contract.module();
+        let defined = module.events().map(|event| {
+            let span = event.span();
+            let ident = &event.item().ident;
+            let cfg_attrs = event.get_cfg_attrs(span);
+            quote_spanned!(span=>
+                #( #cfg_attrs )*
+                <#ident as ::ink::env::Event>::SIGNATURE_TOPIC
+            )
+        });
+        let emitted = module
+            .impls()
+            .flat_map(|item_impl| item_impl.iter_messages())
+            .flat_map(|message| message.callable().emits())
+            .map(|event| {
+                quote_spanned!(event.span()=>
+                    <#event as ::ink::env::Event>::SIGNATURE_TOPIC
+                )
+            });
+        quote! {
+            /// The distinct signature topics of all non-anonymous events defined in this
+            /// contract module or declared to be emitted by its messages.
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __INK_EVENT_SIGNATURES: &[[::core::primitive::u8; 32]] = {
+                const TOPICS: &[::core::option::Option<[::core::primitive::u8; 32]>] = &[
+                    #( #defined, )*
+                    #( #emitted, )*
+                ];
+                const COUNT: ::core::primitive::usize =
+                    ::ink::codegen::utils::count_signature_topics(TOPICS);
+                const SIGNATURES: [[::core::primitive::u8; 32]; COUNT] =
+                    ::ink::codegen::utils::signature_topics(TOPICS);
+                &SIGNATURES
+            };
+        }
+    }
+}
@@ -79,6 +79,7 @@ impl GenerateCode for Dispatch<'_> {
             self.generate_dispatchable_constructor_infos();
         let contract_dispatchable_messages_infos =
             self.generate_dispatchable_message_infos();
+        let contract_messages = self.generate_contract_messages(&messages);
         let constructor_decoder_type =
             self.generate_constructor_decoder_type(&constructors);
         let message_decoder_type = self.generate_message_decoder_type(&messages);
@@ -86,6 +87,7 @@ impl GenerateCode for Dispatch<'_> {
         quote! {
             #contract_dispatchable_constructor_infos
             #contract_dispatchable_messages_infos
+            #contract_messages
             #constructor_decoder_type
             #message_decoder_type
 
@@ -224,6 +226,42 @@ impl Dispatch<'_> {
         )
     }
 
+    /// Generate code for the [`ink::reflect::ContractMessages`] trait implementation.
+    ///
+    /// The table of messages is assembled from the [`ink::DispatchableMessageInfo`]
+    /// trait implementations, so that it is also available in `no_std` environments.
+    fn generate_contract_messages(
+        &self,
+        messages: &[MessageDispatchable],
+    ) -> TokenStream2 {
+        let span = self.contract.module().storage().span();
+        let storage_ident = self.contract.module().storage().ident();
+        let message_infos = messages.iter().map(|item| {
+            let message_span = item.message.span();
+            let cfg_attrs = item.message.get_cfg_attrs(message_span);
+            let id = &item.id;
+            let message_info = quote_spanned!(message_span=>
+                <#storage_ident as ::ink::reflect::DispatchableMessageInfo<#id>>
+            );
+            quote_spanned!(message_span=>
+                #( #cfg_attrs )*
+                ::ink::reflect::MessageInfo {
+                    selector: #message_info::SELECTOR,
+                    mutates: #message_info::MUTATES,
+                    payable: #message_info::PAYABLE,
+                    label: #message_info::LABEL,
+                }
+            )
+        });
+        quote_spanned!(span=>
+            impl ::ink::reflect::ContractMessages for #storage_ident {
+                const MESSAGES: &'static [::ink::reflect::MessageInfo] = &[
+                    #( #message_infos ),*
+                ];
+            }
+        )
+    }
+
     /// Generate code for the [`ink::DispatchableMessageInfo`] trait implementations.
     ///
     /// These trait implementations store relevant dispatch information for every
@@ -567,6 +605,12 @@ impl Dispatch<'_> {
 
             let constructor_accept_payment_assignment =
                 self.any_constructor_accepts_payment(constructors);
+            let ensure_min_value = item.constructor.min_value().map(|min_value| {
+                quote_spanned!(constructor_span=>
+                    ::ink::codegen::ensure_min_value::<
+                        <#storage_ident as ::ink::env::ContractEnv>::Env>(#min_value)?;
+                )
+            });
 
             quote_spanned!(constructor_span=>
                 #( #cfg_attrs )*
@@ -576,6 +620,7 @@ impl Dispatch<'_> {
                         ::ink::codegen::deny_payment::<
                             <#storage_ident as ::ink::env::ContractEnv>::Env>()?;
                     }
+                    #ensure_min_value
 
                     let result: #constructor_output = #constructor_callable(input);
                     let output_value = ::ink::reflect::ConstructorOutputValue::new(result);
@@ -777,6 +822,37 @@ impl Dispatch<'_> {
 
                 let any_message_accepts_payment =
                     self.any_message_accepts_payment(messages);
+                let (enter_guard, exit_guard) = if item.message.callable().is_non_reentrant() {
+                    (
+                        quote_spanned!(message_span=>
+                            if !::ink::codegen::enter_non_reentrant() {
+                                ::ink::env::return_value::<::ink::MessageResult::<#message_output>>(
+                                    ::ink::env::ReturnFlags::REVERT,
+                                    &::ink::MessageResult::Err(::ink::LangError::ReentrantCall),
+                                )
+                            }
+                        ),
+                        quote_spanned!(message_span=>
+                            ::ink::codegen::exit_non_reentrant();
+                        ),
+                    )
+                } else {
+                    (quote! {}, quote! {})
+                };
+                let deny_contract_caller = if item.message.callable().is_eoa_only() {
+                    quote_spanned!(message_span=>
+                        if !::ink::env::caller_is_origin::<
+                            <#storage_ident as ::ink::env::ContractEnv>::Env>()
+                        {
+                            ::ink::env::return_value::<::ink::MessageResult::<#message_output>>(
+                                ::ink::env::ReturnFlags::REVERT,
+                                &::ink::MessageResult::Err(::ink::LangError::CallerNotOrigin),
+                            )
+                        }
+                    )
+                } else {
+                    quote! {}
+                };
 
                 quote_spanned!(message_span=>
                     #( #cfg_attrs )*
@@ -785,10 +861,14 @@ impl Dispatch<'_> {
                             ::ink::codegen::deny_payment::<
                                 <#storage_ident as ::ink::env::ContractEnv>::Env>()?;
                         }
+                        #deny_contract_caller
+                        #enter_guard
 
                         let result: #message_output = #message_callable(&mut contract, input);
-                        let is_reverted = ::ink::is_result_type!(#message_output)
-                            && ::ink::is_result_err!(result);
+                        #exit_guard
+                        let is_reverted = ::ink::is_revert_type!(#message_output)
+                            || (::ink::is_result_type!(#message_output)
+                                && ::ink::is_result_err!(result));
 
                         // NOTE: we can't use an if/else expression here
                         // It fails inside quote_spanned! macro.
@@ -803,8 +883,8 @@ impl Dispatch<'_> {
 
                         ::ink::env::return_value::<::ink::MessageResult::<#message_output>>(
                             flag,
-                            // Currently no `LangError`s are raised at this level of the
-                            // dispatch logic so `Ok` is always returned to the caller.
+                            // `LangError`s raised at this level of the dispatch logic
+                            // return early, so `Ok` is always returned here.
                             &::ink::MessageResult::Ok(result),
                         )
                     }
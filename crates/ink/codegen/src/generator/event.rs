@@ -36,6 +36,17 @@ impl GenerateCode for Event<'_> {
             .item
             .signature_topic_hex()
             .map(|hex_s| quote::quote! { #[ink(signature_topic = #hex_s)] });
+        let topic_hash = self
+            .item
+            .topic_hash()
+            .map(|hash| quote::quote! { #[ink(topic_hash = #hash)] });
+        let schema_version = self
+            .item
+            .schema_version()
+            .map(|version| quote::quote! { #[ink(schema_version = #version)] });
+        let derives = self.item.derives();
+        let derives =
+            (!derives.is_empty()).then(|| quote::quote! { #[derive( #( #derives ),* )] });
         let cfg_attrs = self.item.get_cfg_attrs(item.span());
 
         quote::quote! (
@@ -43,8 +54,11 @@ impl GenerateCode for Event<'_> {
             #[cfg_attr(feature = "std", derive(::ink::EventMetadata))]
             #[derive(::ink::Event)]
             #[::ink::scale_derive(Encode, Decode)]
+            #derives
             #anonymous
             #signature_topic
+            #topic_hash
+            #schema_version
             #item
         )
     }
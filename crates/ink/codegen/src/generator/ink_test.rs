@@ -15,7 +15,10 @@
 use crate::GenerateCode;
 use derive_more::From;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{
+    format_ident,
+    quote,
+};
 
 /// Generates code for the `[ink::test]` macro.
 #[derive(From)]
@@ -27,14 +30,58 @@ pub struct InkTest<'a> {
 impl GenerateCode for InkTest<'_> {
     /// Generates the code for `#[ink:test]`.
     fn generate_code(&self) -> TokenStream2 {
+        let cases = self.test.cases();
+        if cases.is_empty() {
+            let fn_name = &self.test.item_fn.sig.ident;
+            let fn_args = &self.test.item_fn.sig.inputs;
+            return self.generate_test(fn_name, quote! { #fn_args }, quote! {})
+        }
+        let tests = cases
+            .iter()
+            .enumerate()
+            .map(|(index, case)| self.generate_case(index, case));
+        quote! {
+            #( #tests )*
+        }
+    }
+}
+
+impl InkTest<'_> {
+    /// Generates a test for a single case of a parameterized `#[ink::test]`.
+    ///
+    /// The values of the case are bound to the arguments of the test function
+    /// before its body is executed.
+    fn generate_case(&self, index: usize, case: &[syn::Expr]) -> TokenStream2 {
+        let sig = &self.test.item_fn.sig;
+        let fn_name = format_ident!("{}_case_{}", sig.ident, index);
+        let bindings = sig.inputs.iter().zip(case).map(|(input, value)| {
+            match input {
+                syn::FnArg::Typed(pat_type) => {
+                    let pat = &pat_type.pat;
+                    let ty = &pat_type.ty;
+                    quote! { let #pat: #ty = #value; }
+                }
+                syn::FnArg::Receiver(_) => {
+                    unreachable!("parameterized ink! tests have no `self` receiver")
+                }
+            }
+        });
+        self.generate_test(&fn_name, quote! {}, quote! { #( #bindings )* })
+    }
+
+    /// Generates a `#[test]` function which runs the body of the annotated function
+    /// in the off-chain testing environment.
+    fn generate_test(
+        &self,
+        fn_name: &syn::Ident,
+        fn_args: TokenStream2,
+        bindings: TokenStream2,
+    ) -> TokenStream2 {
         let item_fn = &self.test.item_fn;
         let attrs = &item_fn.attrs;
-        let sig = &item_fn.sig;
-        let fn_name = &sig.ident;
-        let fn_return_type = &sig.output;
+        let fn_return_type = &item_fn.sig.output;
         let fn_block = &item_fn.block;
         let vis = &item_fn.vis;
-        let fn_args = &sig.inputs;
         let expect_msg = format!(
             "{}: the off-chain testing environment returned an error",
             stringify!(#fn_name)
@@ -46,6 +93,7 @@ impl GenerateCode for InkTest<'_> {
                     #[test]
                     #vis fn #fn_name( #fn_args ) {
                         ::ink::env::test::run_test::<::ink::env::DefaultEnvironment, _>(|_| {
+                            #bindings
                             {
                                 {
                                     #fn_block
@@ -63,6 +111,7 @@ impl GenerateCode for InkTest<'_> {
                     #[test]
                     #vis fn #fn_name( #fn_args ) #rarrow #ret_type {
                         ::ink::env::test::run_test::<::ink::env::DefaultEnvironment, _>(|_| {
+                            #bindings
                             #fn_block
                         })
                     }
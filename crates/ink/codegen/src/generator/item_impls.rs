@@ -160,9 +160,18 @@ impl ItemImpls<'_> {
                         >();
                     )
                 });
+                let message_emits = message.emits().iter().map(|event| {
+                    let span = event.span();
+                    quote_spanned!(span=>
+                        ::ink::codegen::utils::consume_type::<
+                            ::ink::codegen::DispatchEvent<#event>
+                        >();
+                    )
+                });
                 quote_spanned!(message_span=>
                     #( #message_inputs )*
                     #message_output
+                    #( #message_emits )*
                 )
             });
         quote_spanned!(storage_span=>
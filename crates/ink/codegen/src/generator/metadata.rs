@@ -23,6 +23,7 @@ use ir::{
 use proc_macro2::{
     Ident,
     TokenStream as TokenStream2,
+    TokenTree,
 };
 use quote::{
     quote,
@@ -45,6 +46,7 @@ impl GenerateCode for Metadata<'_> {
     fn generate_code(&self) -> TokenStream2 {
         let contract = self.generate_contract();
         let layout = self.generate_layout();
+        let imports = self.generate_imports();
 
         quote! {
             #[cfg(feature = "std")]
@@ -57,6 +59,9 @@ impl GenerateCode for Metadata<'_> {
                         ::core::panic!("metadata ink! generation failed: {}", error)
                     });
                     ::ink::metadata::InkProject::new(layout, #contract)
+                        .with_imports([
+                            #( #imports ),*
+                        ])
                 }
             };
         }
@@ -215,8 +220,10 @@ impl Metadata<'_> {
                 let ident = message.ident();
                 let args = message.inputs().map(Self::generate_dispatch_argument);
                 let cfg_attrs = message.get_cfg_attrs(span);
-                let ret_ty =
-                    Self::generate_message_return_type(&message.wrapped_output());
+                let ret_ty = Self::generate_message_return_type(message);
+                let emits = Self::generate_message_emits(message);
+                let is_eoa_only = message.is_eoa_only();
+                let is_reentrancy_guarded = message.is_non_reentrant();
                 quote_spanned!(span =>
                     #( #cfg_attrs )*
                     ::ink::metadata::MessageSpec::from_label(::core::stringify!(#ident))
@@ -230,6 +237,11 @@ impl Metadata<'_> {
                         .mutates(#mutates)
                         .payable(#is_payable)
                         .default(#is_default)
+                        .eoa_only(#is_eoa_only)
+                        .reentrancy_guarded(#is_reentrancy_guarded)
+                        .emits([
+                            #( #emits ),*
+                        ])
                         .docs([
                             #( #docs ),*
                         ])
@@ -279,7 +291,10 @@ impl Metadata<'_> {
                         as #trait_path>::__ink_TraitInfo
                         as ::ink::reflect::TraitMessageInfo<#local_id>>::SELECTOR
                 }};
-                let ret_ty = Self::generate_message_return_type(&message.wrapped_output());
+                let ret_ty = Self::generate_message_return_type(message.callable());
+                let emits = Self::generate_message_emits(message.callable());
+                let is_eoa_only = message.callable().is_eoa_only();
+                let is_reentrancy_guarded = message.callable().is_non_reentrant();
                 let label = [trait_ident.to_string(), message_ident.to_string()].join("::");
                 quote_spanned!(message_span=>
                     #( #cfg_attrs )*
@@ -291,6 +306,11 @@ impl Metadata<'_> {
                         .returns(#ret_ty)
                         .mutates(#mutates)
                         .payable(#is_payable)
+                        .eoa_only(#is_eoa_only)
+                        .reentrancy_guarded(#is_reentrancy_guarded)
+                        .emits([
+                            #( #emits ),*
+                        ])
                         .docs([
                             #( #message_docs ),*
                         ])
@@ -300,11 +320,33 @@ impl Metadata<'_> {
             .collect()
     }
 
-    /// Generates ink! metadata for the given return type.
-    fn generate_message_return_type(ret_ty: &syn::Type) -> TokenStream2 {
-        let type_spec = generate_type_spec(ret_ty);
+    /// Generates ink! metadata for the events declared to be emitted by the message.
+    fn generate_message_emits(message: &ir::Message) -> Vec<TokenStream2> {
+        message
+            .emits()
+            .iter()
+            .map(|event| {
+                quote_spanned!(event.span()=> {
+                    let spec = <#event as ::ink::metadata::EventMetadata>::event_spec();
+                    ::std::format!("{}::{}", spec.module_path(), spec.label())
+                })
+            })
+            .collect()
+    }
+
+    /// Generates ink! metadata for the return type of the given message.
+    ///
+    /// If the message returns a `Result<T, E>`, the error type `E` is recorded
+    /// separately.
+    fn generate_message_return_type(message: &ir::Message) -> TokenStream2 {
+        let type_spec = generate_type_spec(&message.wrapped_output());
+        let result_error = match message.output() {
+            Some(output) => quote! { ::ink::result_error_type_spec!(#output) },
+            None => quote! { ::core::option::Option::None },
+        };
         quote! {
             ::ink::metadata::ReturnTypeSpec::new(#type_spec)
+                .with_result_error(#result_error)
         }
     }
 
@@ -328,9 +370,50 @@ impl Metadata<'_> {
                     ::ink::ConstructorResult<()>,
                 >("ink_primitives::ConstructorResult")
             })
+            .with_result_error(if #constructor_info::IS_RESULT {
+                ::core::option::Option::Some(
+                    ::ink::metadata::TypeSpec::of_type::<#constructor_info::Error>(),
+                )
+            } else {
+                ::core::option::Option::None
+            })
         )
     }
 
+    /// Generates ink! metadata for all ink! trait definitions which are called via
+    /// `contract_ref!` or `message_builder!` within the contract.
+    ///
+    /// The trait paths are resolved in the scope of the ink! module, so aliased or
+    /// re-exported trait paths all refer to the same ink! trait definition. See
+    /// [`collect_called_traits`] for the limitations of this best-effort analysis.
+    fn generate_imports(&self) -> impl Iterator<Item = TokenStream2> {
+        let items = self.contract.module().items();
+        let mut trait_paths = Vec::new();
+        collect_called_traits(quote! { #( #items )* }, &mut trait_paths);
+        trait_paths.into_iter().map(|trait_path| {
+            let span = trait_path.span();
+            quote_spanned!(span=>
+                {
+                    #[allow(non_camel_case_types)]
+                    type __ink_TraitInfo = <::ink::reflect::TraitDefinitionRegistry<Environment>
+                        as #trait_path>::__ink_TraitInfo;
+                    ::ink::metadata::ImportSpec::new(
+                        ::std::format!(
+                            "{}::{}",
+                            <__ink_TraitInfo as ::ink::reflect::TraitInfo>::PATH,
+                            <__ink_TraitInfo as ::ink::reflect::TraitInfo>::NAME,
+                        ),
+                        <__ink_TraitInfo as ::ink::reflect::TraitInfo>::MESSAGES
+                            .iter()
+                            .map(|(label, selector)| {
+                                ::ink::metadata::ImportMessageSpec::new(*label, *selector)
+                            }),
+                    )
+                }
+            )
+        })
+    }
+
     fn generate_environment(&self) -> TokenStream2 {
         let span = self.contract.module().span();
 
@@ -363,6 +446,58 @@ impl Metadata<'_> {
     }
 }
 
+/// Collects the trait paths of all `contract_ref!` and `message_builder!` invocations
+/// within `tokens`, without duplicates.
+///
+/// # Note
+///
+/// This is a best-effort syntactic scan of the ink! module, since macros cannot
+/// resolve names:
+///
+/// - Invocations of renamed macros (e.g. `use ink::contract_ref as r; r!(Erc20)`) or of
+///   the macros from within other macros and from outside the ink! module are missed.
+/// - Invocations within unused code (e.g. a function which is never called) are collected
+///   nonetheless.
+/// - The same trait referred to via different paths (e.g. a `use` alias) is collected
+///   once per path. The duplicates are removed from the metadata by the resolved trait
+///   path.
+fn collect_called_traits(tokens: TokenStream2, trait_paths: &mut Vec<syn::Path>) {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident)
+                if ident == "contract_ref" || ident == "message_builder" =>
+            {
+                if !matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == '!')
+                {
+                    continue
+                }
+                tokens.next();
+                if let Some(TokenTree::Group(args)) = tokens.next() {
+                    let first_arg = |input: syn::parse::ParseStream| {
+                        let trait_path = input.parse::<syn::Path>()?;
+                        input.parse::<TokenStream2>()?;
+                        Ok(trait_path)
+                    };
+                    if let Ok(trait_path) =
+                        syn::parse::Parser::parse2(first_arg, args.stream())
+                    {
+                        let key = quote! { #trait_path }.to_string();
+                        if !trait_paths
+                            .iter()
+                            .any(|known| quote! { #known }.to_string() == key)
+                        {
+                            trait_paths.push(trait_path);
+                        }
+                    }
+                }
+            }
+            TokenTree::Group(group) => collect_called_traits(group.stream(), trait_paths),
+            _ => (),
+        }
+    }
+}
+
 /// Generates the ink! metadata for the given type.
 pub fn generate_type_spec(ty: &syn::Type) -> TokenStream2 {
     fn without_display_name(ty: &syn::Type) -> TokenStream2 {
@@ -407,6 +542,84 @@ mod tests {
             .collect()
     }
 
+    fn called_traits(tokens: TokenStream2) -> Vec<String> {
+        let mut trait_paths = Vec::new();
+        collect_called_traits(tokens, &mut trait_paths);
+        trait_paths
+            .iter()
+            .map(|trait_path| quote! { #trait_path }.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn collect_called_traits_works() {
+        assert_eq!(
+            called_traits(quote! {
+                pub struct Contract {
+                    token: ink::contract_ref!(erc20::Erc20),
+                }
+                impl Contract {
+                    fn total_supply(&self, token: AccountId) -> Balance {
+                        let token: contract_ref!(erc20::Erc20, CustomEnv) = token.into();
+                        let builder = ink::message_builder!(Flip);
+                        token.total_supply()
+                    }
+                }
+            }),
+            vec!["erc20 :: Erc20".to_string(), "Flip".to_string()],
+        );
+    }
+
+    #[test]
+    fn collect_called_traits_keeps_aliased_paths() {
+        assert_eq!(
+            called_traits(quote! {
+                use erc20::Erc20 as Token;
+                fn total_supply(token: AccountId) -> Balance {
+                    let token: contract_ref!(Token) = token.into();
+                    let other: contract_ref!(erc20::Erc20) = token.into();
+                    let same: contract_ref!(Token) = token.into();
+                    token.total_supply()
+                }
+            }),
+            vec!["Token".to_string(), "erc20 :: Erc20".to_string()],
+        );
+    }
+
+    #[test]
+    fn collect_called_traits_misses_renamed_macros() {
+        assert!(called_traits(quote! {
+            use ink::contract_ref as token_ref;
+            fn total_supply(token: AccountId) -> Balance {
+                let token: token_ref!(Erc20) = token.into();
+                token.total_supply()
+            }
+        })
+        .is_empty());
+    }
+
+    #[test]
+    fn collect_called_traits_includes_unused_code() {
+        assert_eq!(
+            called_traits(quote! {
+                #[allow(dead_code)]
+                fn never_called(token: AccountId) {
+                    let _: contract_ref!(Erc20) = token.into();
+                }
+            }),
+            vec!["Erc20".to_string()],
+        );
+    }
+
+    #[test]
+    fn collect_called_traits_ignores_other_tokens() {
+        assert!(called_traits(quote! {
+            fn contract_ref() {}
+            let message_builder = contract_ref;
+        })
+        .is_empty());
+    }
+
     #[test]
     fn extract_doc_comments_works() {
         assert_eq!(
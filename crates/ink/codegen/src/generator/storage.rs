@@ -89,7 +89,10 @@ impl Storage<'_> {
         let ident = storage.ident();
         let generics = storage.generics();
         let attrs = storage.attrs();
-        let fields = storage.fields();
+        let fields = storage
+            .fields()
+            .zip(storage.field_ciphers())
+            .map(|(field, cipher)| Self::generate_storage_field(field, cipher));
         quote_spanned!( span =>
             #(#attrs)*
             #[::ink::storage_item]
@@ -106,4 +109,27 @@ impl Storage<'_> {
             };
         )
     }
+
+    /// Generates a field of the storage struct.
+    ///
+    /// Fields annotated with `#[ink(encrypted = C)]` are stored as
+    /// `ink::storage::Encrypted<T, C>`, so that their values are encrypted with the
+    /// cipher `C` before they are written to the contract storage.
+    fn generate_storage_field(
+        field: &syn::Field,
+        cipher: Option<&syn::Path>,
+    ) -> TokenStream2 {
+        let Some(cipher) = cipher else {
+            return quote! { #field }
+        };
+        let span = field.span();
+        let attrs = &field.attrs;
+        let vis = &field.vis;
+        let ident = &field.ident;
+        let ty = &field.ty;
+        quote_spanned!(span =>
+            #( #attrs )*
+            #vis #ident: ::ink::storage::Encrypted<#ty, #cipher>
+        )
+    }
 }
@@ -66,6 +66,16 @@ impl GenerateCode for StorageItem<'_> {
                     ::ink::storage::traits::Storable,
                 )]
             };
+            if let Some(env) = self.item.config().env() {
+                derive.extend(quote! {
+                    #[ink(env = #env)]
+                });
+            }
+            if let Some(key) = self.item.config().key() {
+                derive.extend(quote! {
+                    #[ink(key = #key)]
+                });
+            }
         }
 
         let type_check = self.generate_type_check();
@@ -184,18 +194,16 @@ impl<'a> StorageItem<'a> {
     }
 
     fn generate_type_check(&self) -> TokenStream2 {
-        let fields = self
-            .item
-            .all_used_types()
-            .into_iter()
-            .enumerate()
-            .map(|(i, ty)| {
+        let fields = self.item.all_used_types().into_iter().enumerate().map(
+            |(i, (cfg_attrs, ty))| {
                 let field_name = format_ident!("field_{}", i);
                 let span = ty.span();
                 quote_spanned!(span =>
+                    #( #cfg_attrs )*
                     #field_name: #ty
                 )
-            });
+            },
+        );
         let generics = self.item.generics();
         let salt = self.item.salt();
 
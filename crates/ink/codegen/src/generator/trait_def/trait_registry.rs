@@ -252,6 +252,15 @@ impl TraitRegistry<'_> {
         let trait_call_forwarder = self.trait_def.call_forwarder_ident();
         let trait_message_builder = self.trait_def.message_builder_ident();
         let trait_message_info = self.generate_info_for_trait_messages();
+        let trait_messages = self.trait_def.trait_def.item().iter_items().filter_map(
+            |(trait_item, selector)| {
+                trait_item.filter_map_message().map(|message| {
+                    let label = message.ident().to_string();
+                    let selector_bytes = selector.hex_lits();
+                    quote! { (#label, [ #( #selector_bytes ),* ]) }
+                })
+            },
+        );
         quote_spanned!(span =>
             #[doc(hidden)]
             #[allow(non_camel_case_types)]
@@ -270,6 +279,10 @@ impl TraitRegistry<'_> {
                 const PATH: &'static ::core::primitive::str = ::core::module_path!();
 
                 const NAME: &'static ::core::primitive::str = ::core::stringify!(#trait_ident);
+
+                const MESSAGES: &'static [(&'static ::core::primitive::str, [::core::primitive::u8; 4usize])] = &[
+                    #( #trait_messages ),*
+                ];
             }
 
             impl<E> ::ink::codegen::TraitCallForwarder for #trait_info_ident<E>
@@ -202,6 +202,11 @@ impl InkAttribute {
                     "first equal ink! attribute argument here"
                 )));
             }
+            // A message may declare to emit several events.
+            if arg.kind().kind() == AttributeArgKind::Emits {
+                seen.insert(arg);
+                continue
+            }
             if let Some(seen) = seen2.get(&arg.kind().kind()) {
                 return Err(format_err!(
                     arg.span(),
@@ -245,9 +250,36 @@ impl InkAttribute {
             ));
         }
         Self::ensure_no_duplicate_args(&args)?;
+        Self::ensure_no_conflicting_selector_args(&args)?;
         Ok(Self { args })
     }
 
+    /// Ensures that the given ink! attribute arguments do not specify both a `selector`
+    /// and a `selector_name`.
+    ///
+    /// # Errors
+    ///
+    /// If the given ink! attribute arguments contain both a `selector` and a
+    /// `selector_name` argument.
+    fn ensure_no_conflicting_selector_args(
+        args: &[ir::AttributeFrag],
+    ) -> Result<(), syn::Error> {
+        use crate::error::ExtError as _;
+        let find = |kind| args.iter().find(|arg| arg.kind().kind() == kind);
+        if let (Some(selector), Some(selector_name)) = (
+            find(AttributeArgKind::Selector),
+            find(AttributeArgKind::SelectorName),
+        ) {
+            return Err(format_err!(
+                selector_name.span(),
+                "encountered conflicting ink! attribute arguments `selector` and \
+                `selector_name`",
+            )
+            .into_combine(format_err!(selector.span(), "`selector` argument here")));
+        }
+        Ok(())
+    }
+
     /// Returns the first ink! attribute argument.
     pub fn first(&self) -> &AttributeFrag {
         self.args
@@ -284,6 +316,16 @@ impl InkAttribute {
         })
     }
 
+    /// Returns the name the selector is composed from if any.
+    pub fn selector_name(&self) -> Option<String> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::SelectorName(name) = arg.kind() {
+                return Some(name.clone());
+            }
+            None
+        })
+    }
+
     /// Returns the signature topic of the ink! attribute if any.
     pub fn signature_topic_hex(&self) -> Option<String> {
         self.args().find_map(|arg| {
@@ -294,6 +336,40 @@ impl InkAttribute {
         })
     }
 
+    /// Returns the events declared by the `emits` arguments of the ink! attribute.
+    pub fn emits(&self) -> Vec<syn::Path> {
+        self.args()
+            .filter_map(|arg| {
+                if let ir::AttributeArg::Emits(path) = arg.kind() {
+                    return Some(path.path().clone());
+                }
+                None
+            })
+            .collect()
+    }
+
+    /// Returns the cipher declared by the `encrypted` argument of the ink! attribute if
+    /// any.
+    pub fn encrypted(&self) -> Option<syn::Path> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::Encrypted(path) = arg.kind() {
+                return Some(path.path().clone());
+            }
+            None
+        })
+    }
+
+    /// Returns the minimum value declared by the `min_value` argument of the ink!
+    /// attribute if any.
+    pub fn min_value(&self) -> Option<u128> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::MinValue(value) = arg.kind() {
+                return Some(*value);
+            }
+            None
+        })
+    }
+
     /// Returns `true` if the ink! attribute contains the `payable` argument.
     pub fn is_payable(&self) -> bool {
         self.args()
@@ -306,6 +382,24 @@ impl InkAttribute {
             .any(|arg| matches!(arg.kind(), AttributeArg::Default))
     }
 
+    /// Returns `true` if the ink! attribute contains the `default_impl` argument.
+    pub fn is_default_impl(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::DefaultImpl))
+    }
+
+    /// Returns `true` if the ink! attribute contains the `non_reentrant` argument.
+    pub fn is_non_reentrant(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::NonReentrant))
+    }
+
+    /// Returns `true` if the ink! attribute contains the `eoa_only` argument.
+    pub fn is_eoa_only(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::EoaOnly))
+    }
+
     /// Returns `true` if the ink! attribute contains the wildcard selector.
     pub fn has_wildcard_selector(&self) -> bool {
         self.args().any(|arg| {
@@ -370,9 +464,17 @@ pub enum AttributeArgKind {
     Payable,
     /// `#[ink(default)]`
     Default,
+    /// `#[ink(default_impl)]`
+    DefaultImpl,
+    /// `#[ink(non_reentrant)]`
+    NonReentrant,
+    /// `#[ink(eoa_only)]`
+    EoaOnly,
     /// `#[ink(selector = _)]`
     /// `#[ink(selector = 0xDEADBEEF)]`
     Selector,
+    /// `#[ink(selector_name = "my_message")]`
+    SelectorName,
     /// `#[ink(signature_topic =
     /// "325c98ff66bd0d9d1c10789ae1f2a17bdfb2dcf6aa3d8092669afafdef1cb72d")]`
     SignatureTopicArg,
@@ -384,6 +486,12 @@ pub enum AttributeArgKind {
     Implementation,
     /// `#[ink(handle_status = flag: bool)]`
     HandleStatus,
+    /// `#[ink(emits = E: Path)]`
+    Emits,
+    /// `#[ink(encrypted = C: Path)]`
+    Encrypted,
+    /// `#[ink(min_value = N: u128)]`
+    MinValue,
 }
 
 /// An ink! specific attribute flag.
@@ -424,6 +532,21 @@ pub enum AttributeArg {
     /// Applied on ink! constructors or messages in order to indicate
     /// they are default.
     Default,
+    /// `#[ink(default_impl)]`
+    ///
+    /// Applied on ink! constructors in order to additionally generate a `default`
+    /// constructor which calls them with the default value of each argument.
+    DefaultImpl,
+    /// `#[ink(non_reentrant)]`
+    ///
+    /// Applied on `&mut self` ink! messages in order to reject calls to them while
+    /// they are already being executed further up the call stack.
+    NonReentrant,
+    /// `#[ink(eoa_only)]`
+    ///
+    /// Applied on ink! messages in order to reject calls to them which do not
+    /// originate from an externally owned account, i.e. calls from other contracts.
+    EoaOnly,
     /// Can be either one of:
     ///
     /// - `#[ink(selector = 0xDEADBEEF)]` Applied on ink! constructors or messages to
@@ -431,6 +554,12 @@ pub enum AttributeArg {
     /// - `#[ink(selector = _)]` Applied on ink! messages to define a fallback messages
     ///   that is invoked if no other ink! message matches a given selector.
     Selector(SelectorOrWildcard),
+    /// `#[ink(selector_name = "my_message")]`
+    ///
+    /// Applied on ink! constructors or messages to compute their selector from the
+    /// given name instead of from their identifier. This allows renaming them without
+    /// changing their selectors.
+    SelectorName(String),
     /// `#[ink(signature_topic =
     /// "325c98ff66bd0d9d1c10789ae1f2a17bdfb2dcf6aa3d8092669afafdef1cb72d")]`
     SignatureTopic(String),
@@ -464,6 +593,22 @@ pub enum AttributeArg {
     ///
     /// Default value: `true`
     HandleStatus(bool),
+    /// `#[ink(emits = MyEvent)]`
+    ///
+    /// Applied on ink! messages to declare the event they emit. The event is
+    /// recorded for the message in the contract metadata.
+    Emits(PathArg),
+    /// `#[ink(encrypted = MyCipher)]`
+    ///
+    /// Applied on fields of the ink! storage struct to encrypt their value with the
+    /// given [`Cipher`](https://docs.rs/ink_storage/latest/ink_storage/trait.Cipher.html)
+    /// before it is written to the contract storage.
+    Encrypted(PathArg),
+    /// `#[ink(min_value = N: u128)]`
+    ///
+    /// Applied on ink! constructors to require that at least the given value is
+    /// transferred when instantiating the contract. Implies `payable`.
+    MinValue(u128),
 }
 
 impl core::fmt::Display for AttributeArgKind {
@@ -478,6 +623,7 @@ impl core::fmt::Display for AttributeArgKind {
             Self::Selector => {
                 write!(f, "selector = S:[u8; 4] || _")
             }
+            Self::SelectorName => write!(f, "selector_name = N:string"),
             Self::SignatureTopicArg => {
                 write!(f, "signature_topic = S:[u8; 32]")
             }
@@ -490,6 +636,12 @@ impl core::fmt::Display for AttributeArgKind {
             Self::Implementation => write!(f, "impl"),
             Self::HandleStatus => write!(f, "handle_status"),
             Self::Default => write!(f, "default"),
+            Self::DefaultImpl => write!(f, "default_impl"),
+            Self::NonReentrant => write!(f, "non_reentrant"),
+            Self::EoaOnly => write!(f, "eoa_only"),
+            Self::Emits => write!(f, "emits = E:Path"),
+            Self::Encrypted => write!(f, "encrypted = C:Path"),
+            Self::MinValue => write!(f, "min_value = N:u128"),
         }
     }
 }
@@ -505,12 +657,19 @@ impl AttributeArg {
             Self::Constructor => AttributeArgKind::Constructor,
             Self::Payable => AttributeArgKind::Payable,
             Self::Selector(_) => AttributeArgKind::Selector,
+            Self::SelectorName(_) => AttributeArgKind::SelectorName,
             Self::SignatureTopic(_) => AttributeArgKind::SignatureTopicArg,
             Self::Function(_) => AttributeArgKind::Function,
             Self::Namespace(_) => AttributeArgKind::Namespace,
             Self::Implementation => AttributeArgKind::Implementation,
             Self::HandleStatus(_) => AttributeArgKind::HandleStatus,
             Self::Default => AttributeArgKind::Default,
+            Self::DefaultImpl => AttributeArgKind::DefaultImpl,
+            Self::NonReentrant => AttributeArgKind::NonReentrant,
+            Self::EoaOnly => AttributeArgKind::EoaOnly,
+            Self::Emits(_) => AttributeArgKind::Emits,
+            Self::Encrypted(_) => AttributeArgKind::Encrypted,
+            Self::MinValue(_) => AttributeArgKind::MinValue,
         }
     }
 }
@@ -525,6 +684,7 @@ impl core::fmt::Display for AttributeArg {
             Self::Constructor => write!(f, "constructor"),
             Self::Payable => write!(f, "payable"),
             Self::Selector(selector) => core::fmt::Display::fmt(&selector, f),
+            Self::SelectorName(name) => write!(f, "selector_name = {name:?}"),
             Self::SignatureTopic(hash) => {
                 write!(f, "signature_topic = {:?}", hash)
             }
@@ -537,6 +697,12 @@ impl core::fmt::Display for AttributeArg {
             Self::Implementation => write!(f, "impl"),
             Self::HandleStatus(value) => write!(f, "handle_status = {value:?}"),
             Self::Default => write!(f, "default"),
+            Self::DefaultImpl => write!(f, "default_impl"),
+            Self::NonReentrant => write!(f, "non_reentrant"),
+            Self::EoaOnly => write!(f, "eoa_only"),
+            Self::Emits(path) => write!(f, "emits = {path}"),
+            Self::Encrypted(path) => write!(f, "encrypted = {path}"),
+            Self::MinValue(value) => write!(f, "min_value = {value}"),
         }
     }
 }
@@ -619,6 +785,52 @@ impl core::fmt::Display for SelectorOrWildcard {
     }
 }
 
+/// A path given as the value of an ink! attribute argument, e.g. the event of
+/// `#[ink(emits = MyEvent)]`.
+///
+/// Paths are ordered by their token representation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathArg {
+    path: syn::Path,
+}
+
+impl PathArg {
+    /// Returns the underlying path.
+    pub fn path(&self) -> &syn::Path {
+        &self.path
+    }
+}
+
+impl From<syn::Path> for PathArg {
+    fn from(path: syn::Path) -> Self {
+        Self { path }
+    }
+}
+
+impl Parse for PathArg {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        input.parse().map(|path| Self { path })
+    }
+}
+
+impl PartialOrd for PathArg {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PathArg {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.to_string().cmp(&other.to_string())
+    }
+}
+
+impl core::fmt::Display for PathArg {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", self.path.to_token_stream())
+    }
+}
+
 /// An ink! namespace applicable to a trait implementation block.
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Namespace {
@@ -869,6 +1081,7 @@ impl TryFrom<syn::Attribute> for InkAttribute {
             .collect();
 
         Self::ensure_no_duplicate_args(&args)?;
+        Self::ensure_no_conflicting_selector_args(&args)?;
         if args.is_empty() {
             return Err(format_err_spanned!(
                 attr,
@@ -944,6 +1157,16 @@ impl Parse for AttributeFrag {
                         SelectorOrWildcard::try_from(&name_value.value)
                             .map(AttributeArg::Selector)
                     }
+                    "selector_name" => {
+                        if let Some(name) = name_value.value.as_string() {
+                            Ok(AttributeArg::SelectorName(name))
+                        } else {
+                            Err(format_err_spanned!(
+                                name_value.value,
+                                "expected String type for `N` in #[ink(selector_name = N)]",
+                            ))
+                        }
+                    }
                     "namespace" => {
                         Namespace::try_from(&name_value.value)
                             .map(AttributeArg::Namespace)
@@ -974,6 +1197,42 @@ impl Parse for AttributeFrag {
                             ))
                         }
                     }
+                    "emits" => {
+                        if let Some(path) = name_value.value.as_path() {
+                            Ok(AttributeArg::Emits(path.clone().into()))
+                        } else {
+                            Err(format_err_spanned!(
+                                name_value.value,
+                                "expected path to an event type for `E` in #[ink(emits = E)]",
+                            ))
+                        }
+                    }
+                    "encrypted" => {
+                        if let Some(path) = name_value.value.as_path() {
+                            Ok(AttributeArg::Encrypted(path.clone().into()))
+                        } else {
+                            Err(format_err_spanned!(
+                                name_value.value,
+                                "expected path to a cipher type for `C` in #[ink(encrypted = C)]",
+                            ))
+                        }
+                    }
+                    "min_value" => {
+                        if let Some(lit_int) = name_value.value.as_lit_int() {
+                            let value = lit_int.base10_parse::<u128>()
+                                .map_err(|error| {
+                                    format_err_spanned!(
+                                        lit_int,
+                                        "could not parse `N` in `#[ink(min_value = N)]` into a `u128` integer: {}", error)
+                                })?;
+                            Ok(AttributeArg::MinValue(value))
+                        } else {
+                            Err(format_err_spanned!(
+                                name_value.value,
+                                "expected `u128` integer type for `N` in #[ink(min_value = N)]",
+                            ))
+                        }
+                    }
                     "handle_status" => {
                         if let Some(value) = name_value.value.as_bool() {
                             Ok(AttributeArg::HandleStatus(value))
@@ -1008,6 +1267,9 @@ impl Parse for AttributeFrag {
                     "anonymous" => Ok(AttributeArg::Anonymous),
                     "payable" => Ok(AttributeArg::Payable),
                     "default" => Ok(AttributeArg::Default),
+                    "default_impl" => Ok(AttributeArg::DefaultImpl),
+                    "non_reentrant" => Ok(AttributeArg::NonReentrant),
+                    "eoa_only" => Ok(AttributeArg::EoaOnly),
                     "impl" => Ok(AttributeArg::Implementation),
                     _ => match ident.to_string().as_str() {
                         "function" => Err(format_err_spanned!(
@@ -1030,6 +1292,26 @@ impl Parse for AttributeFrag {
                            "encountered #[ink(selector)] that is missing its u32 parameter. \
                             Did you mean #[ink(selector = value: u32)] ?"
                         )),
+                        "selector_name" => Err(format_err_spanned!(
+                            path,
+                           "encountered #[ink(selector_name)] that is missing its string parameter. \
+                            Did you mean #[ink(selector_name = name: str)] ?"
+                        )),
+                        "emits" => Err(format_err_spanned!(
+                            path,
+                           "encountered #[ink(emits)] that is missing its event parameter. \
+                            Did you mean #[ink(emits = E: Path)] ?"
+                        )),
+                        "encrypted" => Err(format_err_spanned!(
+                            path,
+                           "encountered #[ink(encrypted)] that is missing its cipher parameter. \
+                            Did you mean #[ink(encrypted = C: Path)] ?"
+                        )),
+                        "min_value" => Err(format_err_spanned!(
+                            path,
+                           "encountered #[ink(min_value)] that is missing its value parameter. \
+                            Did you mean #[ink(min_value = N: u128)] ?"
+                        )),
                         _ => Err(format_err_spanned!(
                             path,
                             "encountered unknown ink! attribute argument: {}",
@@ -1218,6 +1500,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn selector_name_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(selector_name = "transfer")]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::SelectorName(
+                "transfer".to_string(),
+            )])),
+        );
+    }
+
+    #[test]
+    fn selector_name_is_not_a_selector() {
+        let attr =
+            <ir::InkAttribute as TryFrom<syn::Attribute>>::try_from(syn::parse_quote! {
+                #[ink(message, selector_name = "transfer")]
+            })
+            .unwrap();
+        assert_eq!(attr.selector(), None);
+        assert_eq!(attr.selector_name(), Some("transfer".to_string()));
+    }
+
+    #[test]
+    fn selector_name_invalid_type() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(selector_name = 42)]
+            },
+            Err("expected String type for `N` in #[ink(selector_name = N)]"),
+        );
+    }
+
+    #[test]
+    fn selector_and_selector_name_conflict() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(selector = 42, selector_name = "transfer")]
+            },
+            Err(
+                "encountered conflicting ink! attribute arguments `selector` and \
+                `selector_name`",
+            ),
+        );
+    }
+
     #[test]
     fn wildcard_selector_works() {
         assert_attribute_try_from(
@@ -1276,6 +1604,36 @@ mod tests {
         )
     }
 
+    #[test]
+    fn default_impl_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(default_impl)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::DefaultImpl])),
+        )
+    }
+
+    #[test]
+    fn non_reentrant_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(non_reentrant)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::NonReentrant])),
+        )
+    }
+
+    #[test]
+    fn eoa_only_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(eoa_only)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::EoaOnly])),
+        )
+    }
+
     #[test]
     fn namespace_works() {
         assert_attribute_try_from(
@@ -1421,6 +1779,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn emits_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(emits = crate::Transferred)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Emits(
+                syn::parse_quote! { crate::Transferred },
+            )])),
+        );
+    }
+
+    #[test]
+    fn repeated_emits_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(emits = crate::Transferred, emits = crate::Approved)]
+            },
+            Ok(test::Attribute::Ink(vec![
+                AttributeArg::Emits(syn::parse_quote! { crate::Transferred }),
+                AttributeArg::Emits(syn::parse_quote! { crate::Approved }),
+            ])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(emits = crate::Transferred, emits = crate::Transferred)]
+            },
+            Err("encountered duplicate ink! attribute arguments"),
+        );
+    }
+
+    #[test]
+    fn emits_invalid_parameter_type() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(emits = "Transferred")]
+            },
+            Err("expected path to an event type for `E` in #[ink(emits = E)]"),
+        );
+    }
+
+    #[test]
+    fn encrypted_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(encrypted = crate::XorCipher)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Encrypted(
+                syn::parse_quote! { crate::XorCipher },
+            )])),
+        );
+    }
+
+    #[test]
+    fn encrypted_missing_parameter() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(encrypted)]
+            },
+            Err(
+                "encountered #[ink(encrypted)] that is missing its cipher parameter. \
+                Did you mean #[ink(encrypted = C: Path)] ?",
+            ),
+        );
+    }
+
+    #[test]
+    fn min_value_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(min_value = 1_000)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::MinValue(1_000)])),
+        );
+    }
+
+    #[test]
+    fn min_value_invalid_parameter_type() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(min_value = "1000")]
+            },
+            Err("expected `u128` integer type for `N` in #[ink(min_value = N)]"),
+        );
+    }
+
     #[test]
     fn compound_mixed_works() {
         assert_attribute_try_from(
@@ -31,6 +31,8 @@ pub struct Config {
     env: Option<Environment>,
     /// The set of attributes that can be passed to call builder in the codegen.
     whitelisted_attributes: WhitelistedAttributes,
+    /// The maximum number of messages the contract is allowed to define.
+    max_messages: Option<usize>,
 }
 
 impl TryFrom<ast::AttributeArgs> for Config {
@@ -39,6 +41,7 @@ impl TryFrom<ast::AttributeArgs> for Config {
     fn try_from(args: ast::AttributeArgs) -> Result<Self, Self::Error> {
         let mut env: Option<(Environment, ast::MetaNameValue)> = None;
         let mut whitelisted_attributes = WhitelistedAttributes::default();
+        let mut max_messages: Option<(usize, ast::MetaNameValue)> = None;
 
         for arg in args.into_iter() {
             if arg.name().is_ident("env") {
@@ -65,6 +68,33 @@ impl TryFrom<ast::AttributeArgs> for Config {
                         "expected a string literal value for `keep_attr` ink! configuration argument",
                     ));
                 }
+            } else if arg.name().is_ident("max_messages") {
+                if let Some((_, ast)) = max_messages {
+                    return Err(duplicate_config_err(
+                        ast,
+                        arg,
+                        "max_messages",
+                        "contract",
+                    ));
+                }
+                let max_messages_info = arg
+                    .name_value()
+                    .zip(arg.value().and_then(ast::MetaValue::as_lit_int));
+                if let Some((name_value, lit_int)) = max_messages_info {
+                    let value = lit_int.base10_parse::<usize>().map_err(|error| {
+                        format_err_spanned!(
+                            lit_int,
+                            "could not parse `N` in `max_messages = N` into a `usize` integer: {}",
+                            error
+                        )
+                    })?;
+                    max_messages = Some((value, name_value.clone()))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected an integer value for `max_messages` ink! configuration argument",
+                    ));
+                }
             } else {
                 return Err(format_err_spanned!(
                     arg,
@@ -75,6 +105,7 @@ impl TryFrom<ast::AttributeArgs> for Config {
         Ok(Config {
             env: env.map(|(value, _)| value),
             whitelisted_attributes,
+            max_messages: max_messages.map(|(value, _)| value),
         })
     }
 }
@@ -95,6 +126,12 @@ impl Config {
     pub fn whitelisted_attributes(&self) -> &WhitelistedAttributes {
         &self.whitelisted_attributes
     }
+
+    /// Returns the maximum number of messages the contract is allowed to define if
+    /// specified.
+    pub fn max_messages(&self) -> Option<usize> {
+        self.max_messages
+    }
 }
 
 /// The environmental types definition.
@@ -145,6 +182,7 @@ mod tests {
                     path: syn::parse_quote! { ::my::env::Types },
                 }),
                 whitelisted_attributes: Default::default(),
+                max_messages: None,
             }),
         )
     }
@@ -196,6 +234,7 @@ mod tests {
             Ok(Config {
                 env: None,
                 whitelisted_attributes: attrs,
+                max_messages: None,
             }),
         )
     }
@@ -215,4 +254,37 @@ mod tests {
             Err("expected a string literal value for `keep_attr` ink! configuration argument"),
         );
     }
+
+    #[test]
+    fn max_messages_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                max_messages = 2
+            },
+            Ok(Config {
+                env: None,
+                whitelisted_attributes: Default::default(),
+                max_messages: Some(2),
+            }),
+        )
+    }
+
+    #[test]
+    fn max_messages_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { max_messages = "2" },
+            Err("expected an integer value for `max_messages` ink! configuration argument"),
+        );
+    }
+
+    #[test]
+    fn max_messages_duplicate_fails() {
+        assert_try_from(
+            syn::parse_quote! {
+                max_messages = 2,
+                max_messages = 3,
+            },
+            Err("encountered duplicate ink! contract `max_messages` configuration argument"),
+        );
+    }
 }
@@ -15,6 +15,7 @@
 use crate::{
     ast,
     ir,
+    ir::Callable as _,
 };
 use proc_macro2::TokenStream as TokenStream2;
 
@@ -66,12 +67,42 @@ impl Contract {
         let module = syn::parse2::<syn::ItemMod>(ink_module)?;
         let ink_config = ir::Config::try_from(config)?;
         let ink_module = ir::ItemMod::try_from(module)?;
+        if let Some(max_messages) = ink_config.max_messages() {
+            Self::ensure_max_messages(&ink_module, max_messages)?;
+        }
         Ok(Self {
             item: ink_module,
             config: ink_config,
         })
     }
 
+    /// Ensures that the ink! module defines at most `max_messages` ink! messages.
+    ///
+    /// Both messages of inherent and of trait implementation blocks are counted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error pointing to the first ink! message exceeding the limit.
+    fn ensure_max_messages(
+        ink_module: &ir::ItemMod,
+        max_messages: usize,
+    ) -> Result<(), syn::Error> {
+        let mut messages = ink_module
+            .impls()
+            .flat_map(|item_impl| item_impl.iter_messages());
+        if let Some(message) = messages.nth(max_messages) {
+            let count = max_messages + 1 + messages.count();
+            return Err(format_err_spanned!(
+                message.callable().ident(),
+                "the ink! contract defines {} messages which exceeds the configured \
+                 maximum of `max_messages = {}`",
+                count,
+                max_messages,
+            ))
+        }
+        Ok(())
+    }
+
     /// Returns the ink! inline module definition.
     ///
     /// # Note
@@ -98,6 +129,7 @@ impl Contract {
     /// are the following:
     ///
     /// - `types`: To specify `Environment` different from the default environment types.
+    /// - `max_messages`: To limit the number of messages the contract may define.
     ///
     /// Note that we might add more configuration fields in the future if
     /// necessary.
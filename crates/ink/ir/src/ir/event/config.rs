@@ -27,6 +27,16 @@ pub struct EventConfig {
 
     /// Manually specified signature topic hash.
     signature_topic_hex: Option<String>,
+
+    /// The hash used for topic values, i.e. either `"blake2"` (the default) or
+    /// `"keccak"`.
+    topic_hash: Option<String>,
+
+    /// Additional traits derived for the event, e.g. `Clone`, `Debug` or `PartialEq`.
+    derives: Vec<syn::Path>,
+
+    /// Manually specified version of the event schema.
+    schema_version: Option<u16>,
 }
 
 impl TryFrom<ast::AttributeArgs> for EventConfig {
@@ -35,6 +45,9 @@ impl TryFrom<ast::AttributeArgs> for EventConfig {
     fn try_from(args: ast::AttributeArgs) -> Result<Self, Self::Error> {
         let mut anonymous: Option<syn::Path> = None;
         let mut signature_topic: Option<syn::LitStr> = None;
+        let mut topic_hash: Option<syn::LitStr> = None;
+        let mut derives: Option<(Vec<syn::Path>, syn::LitStr)> = None;
+        let mut schema_version: Option<(u16, syn::LitInt)> = None;
         for arg in args.into_iter() {
             if arg.name().is_ident("anonymous") {
                 if let Some(lit_bool) = anonymous {
@@ -74,6 +87,73 @@ impl TryFrom<ast::AttributeArgs> for EventConfig {
                         "expected a string literal value for `signature_topic` ink! event item configuration argument",
                     ));
                 }
+            } else if arg.name().is_ident("topic_hash") {
+                if let Some(lit_str) = topic_hash {
+                    return Err(duplicate_config_err(lit_str, arg, "topic_hash", "event"));
+                }
+                match arg.value().and_then(ast::MetaValue::as_lit_string) {
+                    Some(lit_str)
+                        if lit_str.value() == "blake2" || lit_str.value() == "keccak" =>
+                    {
+                        topic_hash = Some(lit_str.clone())
+                    }
+                    _ => {
+                        return Err(format_err_spanned!(
+                            arg,
+                            "expected either `\"blake2\"` or `\"keccak\"` for `topic_hash` ink! event item configuration argument",
+                        ));
+                    }
+                }
+            } else if arg.name().is_ident("derive") {
+                if let Some((_, lit_str)) = derives {
+                    return Err(duplicate_config_err(lit_str, arg, "derive", "event"));
+                }
+                if let Some(lit_str) = arg.value().and_then(ast::MetaValue::as_lit_string)
+                {
+                    let paths = lit_str
+                        .value()
+                        .split(',')
+                        .map(|derive| syn::parse_str::<syn::Path>(derive.trim()))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|_| {
+                            format_err_spanned!(
+                                lit_str,
+                                "expected a string with traits separated by `,` for `derive` ink! event item configuration argument",
+                            )
+                        })?;
+                    derives = Some((paths, lit_str.clone()))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a string literal value for `derive` ink! event item configuration argument",
+                    ));
+                }
+            } else if arg.name().is_ident("schema_version") {
+                if let Some((_, lit_int)) = schema_version {
+                    return Err(duplicate_config_err(
+                        lit_int,
+                        arg,
+                        "schema_version",
+                        "event",
+                    ));
+                }
+                match arg.value().and_then(ast::MetaValue::as_lit_int) {
+                    Some(lit_int) => {
+                        let version = lit_int.base10_parse::<u16>().map_err(|_| {
+                            format_err_spanned!(
+                                lit_int,
+                                "expected a `u16` integer value for `schema_version` ink! event item configuration argument",
+                            )
+                        })?;
+                        schema_version = Some((version, lit_int.clone()))
+                    }
+                    None => {
+                        return Err(format_err_spanned!(
+                            arg,
+                            "expected an integer literal value for `schema_version` ink! event item configuration argument",
+                        ));
+                    }
+                }
             } else {
                 return Err(format_err_spanned!(
                     arg,
@@ -85,7 +165,10 @@ impl TryFrom<ast::AttributeArgs> for EventConfig {
         Ok(EventConfig::new(
             anonymous.is_some(),
             signature_topic.map(|lit_str| lit_str.value()),
-        ))
+        )
+        .with_topic_hash(topic_hash.map(|lit_str| lit_str.value()))
+        .with_derives(derives.map(|(paths, _)| paths).unwrap_or_default())
+        .with_schema_version(schema_version.map(|(version, _)| version)))
     }
 }
 
@@ -95,6 +178,27 @@ impl EventConfig {
         Self {
             anonymous,
             signature_topic_hex,
+            topic_hash: None,
+            derives: Vec::new(),
+            schema_version: None,
+        }
+    }
+
+    /// Sets the hash used for topic values which do not fit into a topic.
+    pub fn with_topic_hash(self, topic_hash: Option<String>) -> Self {
+        Self { topic_hash, ..self }
+    }
+
+    /// Sets the additional traits derived for the event.
+    pub fn with_derives(self, derives: Vec<syn::Path>) -> Self {
+        Self { derives, ..self }
+    }
+
+    /// Sets the version of the event schema.
+    pub fn with_schema_version(self, schema_version: Option<u16>) -> Self {
+        Self {
+            schema_version,
+            ..self
         }
     }
 
@@ -107,4 +211,19 @@ impl EventConfig {
     pub fn signature_topic_hex(&self) -> Option<&str> {
         self.signature_topic_hex.as_deref()
     }
+
+    /// Returns the manually specified topic hash.
+    pub fn topic_hash(&self) -> Option<&str> {
+        self.topic_hash.as_deref()
+    }
+
+    /// Returns the additional traits derived for the event.
+    pub fn derives(&self) -> &[syn::Path] {
+        &self.derives
+    }
+
+    /// Returns the manually specified version of the event schema.
+    pub fn schema_version(&self) -> Option<u16> {
+        self.schema_version
+    }
 }
@@ -107,6 +107,28 @@ impl Event {
         self.config.signature_topic_hex()
     }
 
+    /// Returns the manually specified hash for topic values which do not fit into a
+    /// topic, if any.
+    pub fn topic_hash(&self) -> Option<&str> {
+        self.config.topic_hash()
+    }
+
+    /// Returns the additional traits derived for the event, as specified with
+    /// `derive = "Clone, Debug, PartialEq"`.
+    pub fn derives(&self) -> &[syn::Path] {
+        self.config.derives()
+    }
+
+    /// Returns the manually specified version of the event schema, if any.
+    ///
+    /// # Note
+    ///
+    /// The schema version is only recorded in the metadata, it does not affect the
+    /// signature topic.
+    pub fn schema_version(&self) -> Option<u16> {
+        self.config.schema_version()
+    }
+
     /// Returns a list of `cfg` attributes if any.
     pub fn get_cfg_attrs(&self, span: Span) -> Vec<TokenStream2> {
         extract_cfg_attributes(&self.item.attrs, span)
@@ -318,4 +340,84 @@ mod tests {
             "cannot use use `anonymous` with `signature_topic`",
         )
     }
+
+    #[test]
+    fn derive_config_works() {
+        let event = Event::new(
+            quote::quote! { derive = "Clone, Debug, core::cmp::PartialEq" },
+            quote::quote! {
+                pub struct MyEvent {
+                    #[ink(topic)]
+                    field_1: i32,
+                }
+            },
+        )
+        .unwrap();
+        let expected: [syn::Path; 3] = [
+            syn::parse_quote!(Clone),
+            syn::parse_quote!(Debug),
+            syn::parse_quote!(core::cmp::PartialEq),
+        ];
+        assert_eq!(event.derives(), &expected[..]);
+    }
+
+    #[test]
+    fn derive_config_invalid_value_fails() {
+        let result = Event::new(
+            quote::quote! { derive = 1u8 },
+            quote::quote! {
+                pub struct MyEvent {}
+            },
+        );
+        assert_eq!(
+            result.map_err(|err| err.to_string()),
+            Err("expected a string literal value for `derive` ink! event item configuration argument".to_string())
+        );
+    }
+
+    #[test]
+    fn schema_version_config_works() {
+        let event = Event::new(
+            quote::quote! { schema_version = 2 },
+            quote::quote! {
+                pub struct MyEvent {}
+            },
+        )
+        .unwrap();
+        assert_eq!(event.schema_version(), Some(2));
+
+        let event = Event::new(
+            quote::quote! {},
+            quote::quote! {
+                pub struct MyEvent {}
+            },
+        )
+        .unwrap();
+        assert_eq!(event.schema_version(), None);
+    }
+
+    #[test]
+    fn schema_version_config_invalid_value_fails() {
+        let result = Event::new(
+            quote::quote! { schema_version = 65536 },
+            quote::quote! {
+                pub struct MyEvent {}
+            },
+        );
+        assert_eq!(
+            result.map_err(|err| err.to_string()),
+            Err("expected a `u16` integer value for `schema_version` ink! event item configuration argument".to_string())
+        );
+
+        let result = Event::new(
+            quote::quote! { schema_version = "2" },
+            quote::quote! {
+                pub struct MyEvent {}
+            },
+        );
+        assert_eq!(
+            result.map_err(|err| err.to_string()),
+            Err("expected an integer literal value for `schema_version` ink! event item configuration argument".to_string())
+        );
+    }
 }
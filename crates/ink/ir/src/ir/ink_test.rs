@@ -19,6 +19,10 @@ use proc_macro2::TokenStream as TokenStream2;
 pub struct InkTest {
     /// The function which was annotated.
     pub item_fn: syn::ItemFn,
+    /// The cases of a parameterized test, if any.
+    ///
+    /// Each case holds one value for every argument of the annotated function.
+    cases: Vec<Vec<syn::Expr>>,
 }
 
 impl TryFrom<syn::ItemFn> for InkTest {
@@ -26,20 +30,205 @@ impl TryFrom<syn::ItemFn> for InkTest {
 
     fn try_from(item_fn: syn::ItemFn) -> Result<Self, Self::Error> {
         idents_lint::ensure_no_ink_identifiers(&item_fn)?;
-        Ok(Self { item_fn })
+        Ok(Self {
+            item_fn,
+            cases: Vec::new(),
+        })
     }
 }
 
 impl InkTest {
     /// Returns `Ok` if the test matches all requirements for an ink! test definition.
     pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
-        if !attr.is_empty() {
+        let cases = if attr.is_empty() {
+            Vec::new()
+        } else {
+            parse_cases(attr)?
+        };
+        let item_fn = syn::parse2::<syn::ItemFn>(input)?;
+        let mut ink_test = InkTest::try_from(item_fn)?;
+        if !cases.is_empty() {
+            ensure_cases_match_inputs(&ink_test.item_fn.sig, &cases)?;
+        }
+        ink_test.cases = cases;
+        Ok(ink_test)
+    }
+
+    /// Returns the cases of a parameterized test.
+    ///
+    /// Returns an empty slice if the test is not parameterized.
+    pub fn cases(&self) -> &[Vec<syn::Expr>] {
+        &self.cases
+    }
+}
+
+/// Parses the `cases = [(..), ..]` argument of a parameterized ink! test.
+fn parse_cases(attr: TokenStream2) -> Result<Vec<Vec<syn::Expr>>, syn::Error> {
+    let name_value = syn::parse2::<syn::MetaNameValue>(attr.clone()).map_err(|_| {
+        format_err_spanned!(attr, "unexpected attribute input for ink! test definition")
+    })?;
+    if !name_value.path.is_ident("cases") {
+        return Err(format_err_spanned!(
+            name_value.path,
+            "unexpected attribute input for ink! test definition"
+        ))
+    }
+    let syn::Expr::Array(array) = name_value.value else {
+        return Err(format_err_spanned!(
+            name_value.value,
+            "expected an array of tuples for `cases` ink! test configuration argument"
+        ))
+    };
+    if array.elems.is_empty() {
+        return Err(format_err_spanned!(
+            array,
+            "expected at least one case for `cases` ink! test configuration argument"
+        ))
+    }
+    array
+        .elems
+        .into_iter()
+        .map(|case| {
+            match case {
+                syn::Expr::Tuple(tuple) => Ok(tuple.elems.into_iter().collect()),
+                syn::Expr::Paren(paren) => Ok(vec![*paren.expr]),
+                other => {
+                    Err(format_err_spanned!(
+                        other,
+                        "expected a tuple for every case of `cases` ink! test configuration argument"
+                    ))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Ensures that every case of a parameterized ink! test provides exactly one value
+/// for every argument of the test function.
+fn ensure_cases_match_inputs(
+    sig: &syn::Signature,
+    cases: &[Vec<syn::Expr>],
+) -> Result<(), syn::Error> {
+    if let Some(receiver) = sig.receiver() {
+        return Err(format_err_spanned!(
+            receiver,
+            "parameterized ink! tests must not have a `self` receiver"
+        ))
+    }
+    if sig.inputs.is_empty() {
+        return Err(format_err_spanned!(
+            sig,
+            "parameterized ink! tests must have at least one argument"
+        ))
+    }
+    let expected = sig.inputs.len();
+    for case in cases {
+        if case.len() != expected {
             return Err(format_err_spanned!(
-                attr,
-                "unexpected attribute input for ink! test definition"
+                sig.inputs,
+                "expected {} values in every case of the parameterized ink! test, found {}",
+                expected,
+                case.len(),
             ))
         }
-        let item_fn = syn::parse2::<syn::ItemFn>(input)?;
-        InkTest::try_from(item_fn)
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn try_from_tokens(
+        attr: TokenStream2,
+        input: TokenStream2,
+    ) -> Result<InkTest, syn::Error> {
+        InkTest::new(attr, input)
+    }
+
+    #[test]
+    fn cases_works() {
+        let ink_test = try_from_tokens(
+            quote::quote! { cases = [(1, 2), (3, 4)] },
+            quote::quote! {
+                fn add_works(a: u32, b: u32) {}
+            },
+        )
+        .unwrap();
+        let cases = ink_test
+            .cases()
+            .iter()
+            .map(|case| {
+                case.iter()
+                    .map(|expr| quote::quote!(#expr).to_string())
+                    .collect()
+            })
+            .collect::<Vec<Vec<String>>>();
+        assert_eq!(cases, vec![vec!["1", "2"], vec!["3", "4"]]);
+    }
+
+    #[test]
+    fn no_cases_works() {
+        let ink_test = try_from_tokens(
+            quote::quote! {},
+            quote::quote! {
+                fn it_works() {}
+            },
+        )
+        .unwrap();
+        assert!(ink_test.cases().is_empty());
+    }
+
+    fn assert_try_from_fails(attr: TokenStream2, input: TokenStream2, expected: &str) {
+        assert_eq!(
+            try_from_tokens(attr, input)
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn unknown_argument_fails() {
+        assert_try_from_fails(
+            quote::quote! { foo = [(1, 2)] },
+            quote::quote! {
+                fn add_works(a: u32, b: u32) {}
+            },
+            "unexpected attribute input for ink! test definition",
+        )
+    }
+
+    #[test]
+    fn cases_not_an_array_fails() {
+        assert_try_from_fails(
+            quote::quote! { cases = (1, 2) },
+            quote::quote! {
+                fn add_works(a: u32, b: u32) {}
+            },
+            "expected an array of tuples for `cases` ink! test configuration argument",
+        )
+    }
+
+    #[test]
+    fn case_arity_mismatch_fails() {
+        assert_try_from_fails(
+            quote::quote! { cases = [(1, 2), (3, 4, 5)] },
+            quote::quote! {
+                fn add_works(a: u32, b: u32) {}
+            },
+            "expected 2 values in every case of the parameterized ink! test, found 3",
+        )
+    }
+
+    #[test]
+    fn cases_without_arguments_fails() {
+        assert_try_from_fails(
+            quote::quote! { cases = [(1, 2)] },
+            quote::quote! {
+                fn it_works() {}
+            },
+            "parameterized ink! tests must have at least one argument",
+        )
     }
 }
@@ -43,6 +43,9 @@ use syn::spanned::Spanned as _;
 pub struct Storage {
     /// The underlying `struct` Rust item.
     ast: syn::ItemStruct,
+    /// The ciphers of the fields annotated with `#[ink(encrypted = C)]`, in the
+    /// order of the fields.
+    ciphers: Vec<Option<syn::Path>>,
 }
 
 impl quote::ToTokens for Storage {
@@ -92,15 +95,45 @@ impl TryFrom<syn::ItemStruct> for Storage {
             },
         )?;
         utils::ensure_pub_visibility("storage structs", struct_span, &item_struct.vis)?;
+        let mut fields = item_struct.fields;
+        let ciphers = fields
+            .iter_mut()
+            .map(Self::sanitize_field)
+            .collect::<Result<Vec<_>, syn::Error>>()?;
         Ok(Self {
             ast: syn::ItemStruct {
                 attrs: other_attrs,
+                fields,
                 ..item_struct
             },
+            ciphers,
         })
     }
 }
 
+impl Storage {
+    /// Strips the ink! attributes of the storage `field` and returns its cipher if the
+    /// field is annotated with `#[ink(encrypted = C)]`.
+    fn sanitize_field(field: &mut syn::Field) -> Result<Option<syn::Path>, syn::Error> {
+        if !ir::contains_ink_attributes(&field.attrs) {
+            return Ok(None)
+        }
+        let (ink_attrs, other_attrs) = ir::sanitize_attributes(
+            field.span(),
+            core::mem::take(&mut field.attrs),
+            &ir::AttributeArgKind::Encrypted,
+            |arg| {
+                match arg.kind() {
+                    ir::AttributeArg::Encrypted(_) => Ok(()),
+                    _ => Err(None),
+                }
+            },
+        )?;
+        field.attrs = other_attrs;
+        Ok(ink_attrs.encrypted())
+    }
+}
+
 impl Storage {
     /// Returns the non-ink! attributes of the ink! storage struct.
     pub fn attrs(&self) -> &[syn::Attribute] {
@@ -121,6 +154,14 @@ impl Storage {
     pub fn fields(&self) -> syn::punctuated::Iter<syn::Field> {
         self.ast.fields.iter()
     }
+
+    /// Returns an iterator yielding the cipher of every field of the storage struct.
+    ///
+    /// Yields `None` for the fields which are not annotated with
+    /// `#[ink(encrypted = C)]`.
+    pub fn field_ciphers(&self) -> impl Iterator<Item = Option<&syn::Path>> {
+        self.ciphers.iter().map(Option::as_ref)
+    }
 }
 
 #[cfg(test)]
@@ -204,6 +245,39 @@ mod tests {
         )
     }
 
+    #[test]
+    fn encrypted_field_works() {
+        let item: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage)]
+            pub struct MyStorage {
+                field_1: i32,
+                #[ink(encrypted = XorCipher)]
+                field_2: bool,
+            }
+        };
+        let storage = Storage::try_from(item).unwrap();
+        let cipher: syn::Path = syn::parse_quote! { XorCipher };
+        assert_eq!(
+            storage.field_ciphers().collect::<Vec<_>>(),
+            vec![None, Some(&cipher)]
+        );
+        assert!(storage.fields().all(|field| field.attrs.is_empty()));
+    }
+
+    #[test]
+    fn invalid_field_attribute_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    #[ink(payable)]
+                    field_1: i32,
+                }
+            },
+            "unexpected first ink! attribute argument",
+        )
+    }
+
     #[test]
     fn non_pub_storage_struct() {
         assert_try_from_fails(
@@ -108,6 +108,10 @@ where
         <C as Callable>::user_provided_selector(self.callable)
     }
 
+    fn selector_name(&self) -> Option<&str> {
+        <C as Callable>::selector_name(self.callable)
+    }
+
     fn is_payable(&self) -> bool {
         <C as Callable>::is_payable(self.callable)
     }
@@ -163,6 +167,14 @@ pub trait Callable {
     /// Returns the selector of the ink! callable if any has been manually set.
     fn user_provided_selector(&self) -> Option<&ir::Selector>;
 
+    /// Returns the name the selector of the ink! callable is composed from if it has
+    /// been manually set.
+    ///
+    /// # Note
+    ///
+    /// Setting the name is done using the `#[ink(selector_name = "...")]` attribute.
+    fn selector_name(&self) -> Option<&str>;
+
     /// Returns `true` if the ink! callable is flagged as payable.
     ///
     /// # Note
@@ -204,7 +216,7 @@ pub trait Callable {
 ///
 /// Given
 ///
-/// - the identifier `i` of the callable
+/// - the identifier `i` of the callable, or its optionally set selector name
 /// - the optionally set selector `s` of the callable
 /// - the `impl` blocks trait path in case it implements a trait, `P`
 /// - 16 kB blocks optional user provided namespace `S`
@@ -324,7 +336,10 @@ where
     if let Some(selector) = callable.user_provided_selector() {
         return *selector
     }
-    let callable_ident = callable.ident().to_string().into_bytes();
+    let callable_ident = match callable.selector_name() {
+        Some(name) => name.as_bytes().to_vec(),
+        None => callable.ident().to_string().into_bytes(),
+    };
     let namespace_bytes = item_impl
         .namespace()
         .map(|namespace| namespace.as_bytes().to_vec())
@@ -665,4 +680,42 @@ mod tests {
             b"MyTrait::my_message".to_vec(),
         );
     }
+
+    #[test]
+    fn compose_selector_with_selector_name_works() {
+        // Renaming `my_message` to `my_message_v2` keeps the selector stable.
+        assert_compose_selector::<ir::Message, _>(
+            syn::parse_quote! {
+                #[ink(impl)]
+                impl MyStorage {}
+            },
+            syn::parse_quote! {
+                #[ink(message, selector_name = "my_message")]
+                fn my_message_v2(&self) {}
+            },
+            b"my_message".to_vec(),
+        );
+        assert_compose_selector::<ir::Message, _>(
+            syn::parse_quote! {
+                #[ink(impl)]
+                impl ::my::full::path::MyTrait for MyStorage {}
+            },
+            syn::parse_quote! {
+                #[ink(message, selector_name = "my_message")]
+                fn my_message_v2(&self) {}
+            },
+            b"::my::full::path::MyTrait::my_message".to_vec(),
+        );
+        assert_compose_selector::<ir::Constructor, _>(
+            syn::parse_quote! {
+                #[ink(impl, namespace = "my_namespace")]
+                impl MyStorage {}
+            },
+            syn::parse_quote! {
+                #[ink(constructor, selector_name = "new")]
+                fn new_v2() -> Self {}
+            },
+            b"my_namespace::new".to_vec(),
+        );
+    }
 }
@@ -72,6 +72,9 @@ pub struct Constructor {
     is_payable: bool,
     /// If the ink! constructor is default.
     is_default: bool,
+    /// If a `default` constructor which calls this ink! constructor with the default
+    /// value of each argument shall be generated.
+    is_default_impl: bool,
     /// An optional user provided selector.
     ///
     /// # Note
@@ -79,6 +82,11 @@ pub struct Constructor {
     /// This overrides the computed selector, even when using a manual namespace
     /// for the parent implementation block.
     selector: Option<SelectorOrWildcard>,
+    /// An optional user provided name the selector is composed from instead of the
+    /// identifier of the ink! constructor.
+    selector_name: Option<String>,
+    /// The minimum value which must be transferred to the ink! constructor, if any.
+    min_value: Option<u128>,
 }
 
 impl quote::ToTokens for Constructor {
@@ -142,7 +150,10 @@ impl Constructor {
                     ir::AttributeArg::Constructor
                     | ir::AttributeArg::Payable
                     | ir::AttributeArg::Default
-                    | ir::AttributeArg::Selector(_) => Ok(()),
+                    | ir::AttributeArg::DefaultImpl
+                    | ir::AttributeArg::Selector(_)
+                    | ir::AttributeArg::SelectorName(_)
+                    | ir::AttributeArg::MinValue(_) => Ok(()),
                     _ => Err(None),
                 }
             },
@@ -158,13 +169,19 @@ impl TryFrom<syn::ImplItemFn> for Constructor {
         Self::ensure_return(&method_item)?;
         Self::ensure_no_self_receiver(&method_item)?;
         let (ink_attrs, other_attrs) = Self::sanitize_attributes(&method_item)?;
-        let is_payable = ink_attrs.is_payable();
+        let min_value = ink_attrs.min_value();
+        let is_payable = ink_attrs.is_payable() || min_value.is_some();
         let is_default = ink_attrs.is_default();
+        let is_default_impl = ink_attrs.is_default_impl();
         let selector = ink_attrs.selector();
+        let selector_name = ink_attrs.selector_name();
         Ok(Constructor {
             selector,
+            selector_name,
+            min_value,
             is_payable,
             is_default,
+            is_default_impl,
             item: syn::ImplItemFn {
                 attrs: other_attrs,
                 ..method_item
@@ -189,6 +206,10 @@ impl Callable for Constructor {
         None
     }
 
+    fn selector_name(&self) -> Option<&str> {
+        self.selector_name.as_deref()
+    }
+
     fn has_wildcard_selector(&self) -> bool {
         matches!(self.selector, Some(SelectorOrWildcard::Wildcard))
     }
@@ -244,6 +265,63 @@ impl Constructor {
             syn::ReturnType::Type(_, return_type) => Some(return_type),
         }
     }
+
+    /// Returns `true` if a `default` constructor which calls this ink! constructor
+    /// with the default value of each argument is generated.
+    ///
+    /// Flagged via the `#[ink(default_impl)]` attribute.
+    pub fn is_default_impl(&self) -> bool {
+        self.is_default_impl
+    }
+
+    /// Returns the minimum value which must be transferred to the ink! constructor,
+    /// if any.
+    ///
+    /// Flagged via the `#[ink(min_value = N)]` attribute, which implies `payable`.
+    pub fn min_value(&self) -> Option<u128> {
+        self.min_value
+    }
+
+    /// Returns the `default` ink! constructor generated for this ink! constructor.
+    ///
+    /// # Note
+    ///
+    /// The generated constructor calls this ink! constructor with the default value
+    /// of each argument. Arguments which do not implement `Default` are reported by
+    /// the Rust compiler at the argument type.
+    pub(super) fn generate_default_impl(&self) -> Self {
+        let span = self.item.span();
+        let ident = self.ident();
+        let output = &self.item.sig.output;
+        let cfg_attrs = self
+            .attrs()
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"));
+        let doc =
+            format!(" Calls [`Self::{ident}`] with the default value of each argument.");
+        let args = self.inputs().map(|input| {
+            let ty = &*input.ty;
+            quote::quote_spanned!(ty.span()=>
+                <#ty as ::core::default::Default>::default()
+            )
+        });
+        Self {
+            item: syn::parse_quote_spanned!(span=>
+                #( #cfg_attrs )*
+                #[doc = #doc]
+                #[allow(clippy::should_implement_trait)]
+                pub fn default() #output {
+                    Self::#ident( #( #args ),* )
+                }
+            ),
+            is_payable: self.is_payable,
+            is_default: false,
+            is_default_impl: false,
+            selector: None,
+            selector_name: None,
+            min_value: self.min_value,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -337,6 +415,14 @@ mod tests {
                     pub fn my_constructor() -> Self {}
                 },
             ),
+            // A minimum value implies payable.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(constructor, min_value = 1000)]
+                    pub fn my_constructor() -> Self {}
+                },
+            ),
         ];
         for (expect_payable, item_method) in test_inputs {
             let is_payable = <ir::Constructor as TryFrom<_>>::try_from(item_method)
@@ -346,6 +432,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn min_value_works() {
+        let test_inputs: Vec<(Option<u128>, syn::ImplItemFn)> = vec![
+            (
+                None,
+                syn::parse_quote! {
+                    #[ink(constructor, payable)]
+                    fn my_constructor() -> Self {}
+                },
+            ),
+            (
+                Some(1000),
+                syn::parse_quote! {
+                    #[ink(constructor, min_value = 1000)]
+                    pub fn my_constructor() -> Self {}
+                },
+            ),
+        ];
+        for (expected_min_value, item_method) in test_inputs {
+            let min_value = <ir::Constructor as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .min_value();
+            assert_eq!(min_value, expected_min_value);
+        }
+    }
+
     #[test]
     fn is_default_works() {
         let test_inputs: Vec<(bool, syn::ImplItemFn)> = vec![
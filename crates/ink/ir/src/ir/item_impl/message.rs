@@ -109,6 +109,15 @@ pub struct Message {
     /// This overrides the computed selector, even when using a manual namespace
     /// for the parent implementation block.
     selector: Option<SelectorOrWildcard>,
+    /// An optional user provided name the selector is composed from instead of the
+    /// identifier of the ink! message.
+    selector_name: Option<String>,
+    /// An optional event the ink! message declares to emit.
+    emits: Vec<syn::Path>,
+    /// If the ink! message rejects reentrant calls.
+    is_non_reentrant: bool,
+    /// If the ink! message rejects calls from other contracts.
+    is_eoa_only: bool,
 }
 
 impl quote::ToTokens for Message {
@@ -188,7 +197,11 @@ impl Message {
                     ir::AttributeArg::Message
                     | ir::AttributeArg::Payable
                     | ir::AttributeArg::Default
-                    | ir::AttributeArg::Selector(_) => Ok(()),
+                    | ir::AttributeArg::Selector(_)
+                    | ir::AttributeArg::SelectorName(_)
+                    | ir::AttributeArg::NonReentrant
+                    | ir::AttributeArg::EoaOnly
+                    | ir::AttributeArg::Emits(_) => Ok(()),
                     _ => Err(None),
                 }
             },
@@ -207,15 +220,30 @@ impl TryFrom<syn::ImplItemFn> for Message {
         let is_payable = ink_attrs.is_payable();
         let is_default = ink_attrs.is_default();
         let selector = ink_attrs.selector();
-        Ok(Self {
+        let selector_name = ink_attrs.selector_name();
+        let emits = ink_attrs.emits();
+        let is_non_reentrant = ink_attrs.is_non_reentrant();
+        let is_eoa_only = ink_attrs.is_eoa_only();
+        let message = Self {
             is_payable,
             is_default,
             selector,
+            selector_name,
+            emits,
+            is_non_reentrant,
+            is_eoa_only,
             item: syn::ImplItemFn {
                 attrs: other_attrs,
                 ..method_item
             },
-        })
+        };
+        if message.is_non_reentrant && !message.receiver().is_ref_mut() {
+            return Err(format_err!(
+                message.item.sig.inputs,
+                "ink! messages flagged as `non_reentrant` must have a `&mut self` receiver",
+            ))
+        }
+        Ok(message)
     }
 }
 
@@ -235,6 +263,10 @@ impl Callable for Message {
         None
     }
 
+    fn selector_name(&self) -> Option<&str> {
+        self.selector_name.as_deref()
+    }
+
     fn has_wildcard_selector(&self) -> bool {
         matches!(self.selector, Some(SelectorOrWildcard::Wildcard))
     }
@@ -298,6 +330,34 @@ impl Message {
         }
     }
 
+    /// Returns `true` if the ink! message rejects reentrant calls.
+    ///
+    /// # Note
+    ///
+    /// Flagged via the `#[ink(non_reentrant)]` attribute.
+    pub fn is_non_reentrant(&self) -> bool {
+        self.is_non_reentrant
+    }
+
+    /// Returns `true` if the ink! message may only be called by externally owned
+    /// accounts.
+    ///
+    /// # Note
+    ///
+    /// Flagged via the `#[ink(eoa_only)]` attribute.
+    pub fn is_eoa_only(&self) -> bool {
+        self.is_eoa_only
+    }
+
+    /// Returns the events the ink! message declares to emit.
+    ///
+    /// # Note
+    ///
+    /// Each event is declared using an `#[ink(emits = MyEvent)]` attribute argument.
+    pub fn emits(&self) -> &[syn::Path] {
+        &self.emits
+    }
+
     /// Returns the return type of the ink! message if any.
     pub fn output(&self) -> Option<&syn::Type> {
         match &self.item.sig.output {
@@ -522,6 +582,110 @@ mod tests {
         }
     }
 
+    #[test]
+    fn emits_works() {
+        let test_inputs: Vec<(Vec<syn::Path>, syn::ImplItemFn)> = vec![
+            // No declared event.
+            (
+                vec![],
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            // Declared event.
+            (
+                vec![syn::parse_quote! { events::Transferred }],
+                syn::parse_quote! {
+                    #[ink(message, emits = events::Transferred)]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+            // Several declared events.
+            (
+                vec![
+                    syn::parse_quote! { events::Transferred },
+                    syn::parse_quote! { events::Approved },
+                ],
+                syn::parse_quote! {
+                    #[ink(message, emits = events::Transferred)]
+                    #[ink(emits = events::Approved)]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expect_emits, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.emits(), expect_emits);
+        }
+    }
+
+    #[test]
+    fn non_reentrant_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemFn)> = vec![
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, non_reentrant)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expect_non_reentrant, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.is_non_reentrant(), expect_non_reentrant);
+        }
+    }
+
+    #[test]
+    fn non_reentrant_ref_receiver_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message, non_reentrant)]
+                fn my_message(&self) {}
+            },
+            "ink! messages flagged as `non_reentrant` must have a `&mut self` receiver",
+        );
+    }
+
+    #[test]
+    fn eoa_only_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemFn)> = vec![
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, eoa_only)]
+                    fn my_message(&self) {}
+                },
+            ),
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, payable, eoa_only)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expect_eoa_only, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.is_eoa_only(), expect_eoa_only);
+        }
+    }
+
     #[test]
     fn receiver_works() {
         let test_inputs: Vec<(Receiver, syn::ImplItemFn)> = vec![
@@ -244,12 +244,14 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
                 "generic ink! implementation blocks are not supported",
             ))
         }
-        let impl_items = item_impl
+        let mut impl_items = item_impl
             .items
             .into_iter()
             .map(<ImplItem as TryFrom<_>>::try_from)
             .collect::<Result<Vec<_>, syn::Error>>()?;
         let is_trait_impl = item_impl.trait_.is_some();
+        let default_impl = Self::generate_default_impl(&impl_items, is_trait_impl)?;
+        impl_items.extend(default_impl.map(ImplItem::Constructor));
         for impl_item in &impl_items {
             /// Ensures that visibility of ink! messages and constructors is
             /// valid in dependency of the containing ink! `impl` block.
@@ -335,6 +337,43 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
 }
 
 impl ItemImpl {
+    /// Returns the `default` ink! constructor generated for the ink! constructor
+    /// flagged with `#[ink(default_impl)]` if any.
+    ///
+    /// # Errors
+    ///
+    /// - If an ink! constructor of a trait implementation block is flagged.
+    /// - If more than one ink! constructor is flagged.
+    fn generate_default_impl(
+        impl_items: &[ImplItem],
+        is_trait_impl: bool,
+    ) -> Result<Option<Constructor>, syn::Error> {
+        let mut flagged = impl_items
+            .iter()
+            .filter_map(ImplItem::filter_map_constructor)
+            .filter(|constructor| constructor.is_default_impl());
+        let Some(constructor) = flagged.next() else {
+            return Ok(None)
+        };
+        if is_trait_impl {
+            return Err(format_err!(
+                constructor.item.span(),
+                "`default_impl` is not allowed on ink! constructors in trait impl blocks",
+            ))
+        }
+        if let Some(duplicate) = flagged.next() {
+            return Err(format_err!(
+                duplicate.item.span(),
+                "encountered more than one ink! constructor flagged as `default_impl`",
+            )
+            .into_combine(format_err!(
+                constructor.item.span(),
+                "first ink! constructor flagged as `default_impl` here",
+            )))
+        }
+        Ok(Some(constructor.generate_default_impl()))
+    }
+
     /// Returns all non-ink! specific attributes of the implementation block.
     pub fn attrs(&self) -> &[syn::Attribute] {
         &self.attrs
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::ir;
+use crate::ir::{
+    self,
+    Callable as _,
+};
 
 #[test]
 fn is_ink_impl_block_eval_false_works() {
@@ -253,3 +256,52 @@ fn namespace_works() {
         ))
     )
 }
+
+#[test]
+fn default_impl_works() {
+    let impl_block: ir::ItemImpl =
+        <ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(syn::parse_quote! {
+            impl MyStorage {
+                #[ink(constructor, default_impl)]
+                pub fn new(a: i32, b: bool) -> Self {}
+            }
+        })
+        .unwrap();
+    let constructors = impl_block
+        .iter_constructors()
+        .map(|constructor| constructor.callable().ident().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(constructors, vec!["new", "default"]);
+    let default = impl_block
+        .iter_constructors()
+        .map(|constructor| constructor.callable())
+        .find(|constructor| constructor.ident() == "default")
+        .unwrap();
+    assert_eq!(default.inputs().count(), 0);
+    assert!(!default.is_default_impl());
+}
+
+#[test]
+fn default_impl_fails() {
+    assert_try_from_item_impl_fails(
+        syn::parse_quote! {
+            impl MyStorage {
+                #[ink(constructor, default_impl)]
+                pub fn new(a: i32) -> Self {}
+
+                #[ink(constructor, default_impl)]
+                pub fn new_2(b: bool) -> Self {}
+            }
+        },
+        "encountered more than one ink! constructor flagged as `default_impl`",
+    );
+    assert_try_from_item_impl_fails(
+        syn::parse_quote! {
+            impl MyTrait for MyStorage {
+                #[ink(constructor, default_impl)]
+                fn new(a: i32) -> Self {}
+            }
+        },
+        "`default_impl` is not allowed on ink! constructors in trait impl blocks",
+    );
+}
@@ -25,6 +25,12 @@ pub struct StorageItemConfig {
     /// If set to `false`, implementing all storage traits is disabled. In some cases
     /// this can be helpful to override the default implementation of the trait.
     derive: bool,
+    /// If specified, the `STORAGE_KEY_SALT` of this environment is combined with all
+    /// storage keys of the storage item.
+    env: Option<syn::Path>,
+    /// If specified, the root storage key of the storage item instead of the
+    /// automatically derived one.
+    key: Option<u32>,
 }
 
 impl TryFrom<ast::AttributeArgs> for StorageItemConfig {
@@ -32,6 +38,8 @@ impl TryFrom<ast::AttributeArgs> for StorageItemConfig {
 
     fn try_from(args: ast::AttributeArgs) -> Result<Self, Self::Error> {
         let mut derive: Option<syn::LitBool> = None;
+        let mut env: Option<(syn::Path, ast::MetaNameValue)> = None;
+        let mut key: Option<(u32, ast::MetaNameValue)> = None;
         for arg in args.into_iter() {
             if arg.name().is_ident("derive") {
                 if let Some(lit_bool) = derive {
@@ -51,6 +59,43 @@ impl TryFrom<ast::AttributeArgs> for StorageItemConfig {
                         "expected a bool literal value for `derive` ink! storage item configuration argument",
                     ));
                 }
+            } else if arg.name().is_ident("env") {
+                if let Some((_, ast)) = env {
+                    return Err(duplicate_config_err(ast, arg, "env", "storage item"));
+                }
+                let env_info = arg
+                    .name_value()
+                    .zip(arg.value().and_then(ast::MetaValue::as_path));
+                if let Some((name_value, path)) = env_info {
+                    env = Some((path.clone(), name_value.clone()))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a path value for `env` ink! storage item configuration argument",
+                    ));
+                }
+            } else if arg.name().is_ident("key") {
+                if let Some((_, ast)) = key {
+                    return Err(duplicate_config_err(ast, arg, "key", "storage item"));
+                }
+                let key_info = arg
+                    .name_value()
+                    .zip(arg.value().and_then(ast::MetaValue::as_lit_int));
+                if let Some((name_value, lit_int)) = key_info {
+                    let value = lit_int.base10_parse::<u32>().map_err(|error| {
+                        format_err_spanned!(
+                            lit_int,
+                            "could not parse `key` ink! storage item configuration argument into a `u32` integer: {}",
+                            error
+                        )
+                    })?;
+                    key = Some((value, name_value.clone()))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a `u32` integer value for `key` ink! storage item configuration argument",
+                    ));
+                }
             } else {
                 return Err(format_err_spanned!(
                     arg,
@@ -60,6 +105,8 @@ impl TryFrom<ast::AttributeArgs> for StorageItemConfig {
         }
         Ok(StorageItemConfig {
             derive: derive.map(|lit_bool| lit_bool.value).unwrap_or(true),
+            env: env.map(|(path, _)| path),
+            key: key.map(|(value, _)| value),
         })
     }
 }
@@ -69,4 +116,14 @@ impl StorageItemConfig {
     pub fn derive(&self) -> bool {
         self.derive
     }
+
+    /// Returns the environment configuration argument, if specified.
+    pub fn env(&self) -> Option<&syn::Path> {
+        self.env.as_ref()
+    }
+
+    /// Returns the root storage key configuration argument, if specified.
+    pub fn key(&self) -> Option<u32> {
+        self.key
+    }
 }
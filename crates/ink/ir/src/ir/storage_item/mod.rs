@@ -50,6 +50,25 @@ impl StorageItem {
             }
         }
 
+        if let Some(env) = config.env() {
+            if let Some(salt) = find_storage_key_salt(&ast) {
+                return Err(format_err_spanned!(
+                    env,
+                    "the `env` ink! storage item configuration argument cannot be combined with the generic `StorageKey` salt `{}`",
+                    salt.ident,
+                ))
+            }
+        }
+        if config.key().is_some() {
+            if let Some(salt) = find_storage_key_salt(&ast) {
+                return Err(format_err_spanned!(
+                    salt,
+                    "the `key` ink! storage item configuration argument cannot be combined with the generic `StorageKey` salt `{}`",
+                    salt.ident,
+                ))
+            }
+        }
+
         Ok(Self { ast, config })
     }
 
@@ -58,32 +77,36 @@ impl StorageItem {
         &self.ast
     }
 
-    /// Returns all types that were used in the storage declaration.
-    pub fn all_used_types(&self) -> Vec<syn::Type> {
-        let res: Vec<_> = match self.data().clone() {
-            syn::Data::Struct(st) => {
-                st.fields.iter().map(|field| field.ty.clone()).collect()
-            }
+    /// Returns all types that were used in the storage declaration, together with the
+    /// `#[cfg(..)]` attributes of their fields.
+    ///
+    /// The type of a field which is conditionally compiled might not exist if the field
+    /// is disabled, hence it must be used under the same `#[cfg(..)]` attributes.
+    pub fn all_used_types(&self) -> Vec<(Vec<syn::Attribute>, syn::Type)> {
+        let fields: Vec<syn::Field> = match self.data().clone() {
+            syn::Data::Struct(st) => st.fields.into_iter().collect(),
             syn::Data::Enum(en) => {
                 en.variants
-                    .iter()
-                    .flat_map(|variant| variant.fields.iter())
-                    .map(|field| field.ty.clone())
-                    .collect()
-            }
-            syn::Data::Union(un) => {
-                un.fields
-                    .named
-                    .iter()
-                    .map(|field| field.ty.clone())
+                    .into_iter()
+                    .flat_map(|variant| variant.fields.into_iter())
                     .collect()
             }
+            syn::Data::Union(un) => un.fields.named.into_iter().collect(),
         };
         let mut set = HashSet::new();
-        res.into_iter()
-            .filter(|ty| {
-                if !set.contains(ty) {
-                    set.insert(ty.clone());
+        fields
+            .into_iter()
+            .map(|field| {
+                let cfg_attrs = field
+                    .attrs
+                    .into_iter()
+                    .filter(|attr| attr.path().is_ident("cfg"))
+                    .collect::<Vec<_>>();
+                (cfg_attrs, field.ty)
+            })
+            .filter(|used_type| {
+                if !set.contains(used_type) {
+                    set.insert(used_type.clone());
                     true
                 } else {
                     false
@@ -130,9 +153,16 @@ impl StorageItem {
     /// Returns salt for storage key.
     pub fn salt(&self) -> TokenStream2 {
         if let Some(param) = find_storage_key_salt(&self.ast) {
-            param.ident.to_token_stream()
+            return param.ident.to_token_stream()
+        }
+        let salt = if let Some(env) = self.config.env() {
+            quote! { ::ink::env::StorageKeySalt<#env> }
         } else {
             quote! { () }
+        };
+        match self.config.key() {
+            Some(key) => quote! { ::ink::storage::traits::ManualKey<#key, #salt> },
+            None => salt,
         }
     }
 }
@@ -41,6 +41,14 @@ fn event_metadata_derive_struct(s: synstructure::Structure) -> syn::Result<Token
     assert_eq!(s.variants().len(), 1, "can only operate on structs");
     let span = s.ast().span();
 
+    // The other event configuration arguments are validated by the `Event` derive.
+    let schema_version = super::parse_arg_attrs(&s.ast().attrs)?
+        .iter()
+        .find(|arg| arg.path().is_ident("schema_version"))
+        .map(super::parse_schema_version_arg)
+        .transpose()?
+        .map(|version| quote_spanned!(span=> .schema_version(#version)));
+
     let variant = &s.variants()[0];
     let ident = variant.ast().ident;
 
@@ -94,6 +102,7 @@ fn event_metadata_derive_struct(s: synstructure::Structure) -> syn::Result<Token
                     .signature_topic(
                         <Self as ::ink::env::Event>::SIGNATURE_TOPIC
                     )
+                    #schema_version
                     .args([
                        #( #args ),*
                     ])
@@ -39,13 +39,67 @@ struct EventConfig {
     pub anonymous: bool,
     /// Event has a specified signature topic.
     pub signature_topic: Option<SignatureTopicArg>,
+    /// The hash used for topic values.
+    pub topic_hash: TopicHash,
 }
 
 impl EventConfig {
-    pub fn new(anonymous: bool, signature_topic: Option<SignatureTopicArg>) -> Self {
+    pub fn new(
+        anonymous: bool,
+        signature_topic: Option<SignatureTopicArg>,
+        topic_hash: TopicHash,
+    ) -> Self {
         EventConfig {
             anonymous,
             signature_topic,
+            topic_hash,
+        }
+    }
+}
+
+/// The hash used for topic values.
+#[derive(Default)]
+enum TopicHash {
+    /// `BLAKE2b` with 256-bit output of the encoding of topic values which do not fit
+    /// into a topic, the default.
+    #[default]
+    Blake2,
+    /// `Keccak256` of the bytes of `String` and `Vec<u8>` topic values, as used for
+    /// indexed dynamic values of EVM logs.
+    ///
+    /// Topic values of any other type, e.g. `u128` or `AccountId`, are not hashed if
+    /// their encoding fits into a topic, like for `Blake2`.
+    Keccak,
+}
+
+impl TryFrom<&syn::Meta> for TopicHash {
+    type Error = syn::Error;
+
+    fn try_from(meta: &syn::Meta) -> Result<Self, Self::Error> {
+        if let syn::Meta::NameValue(syn::MetaNameValue {
+            value:
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit_str),
+                    ..
+                }),
+            ..
+        }) = meta
+        {
+            match lit_str.value().as_str() {
+                "blake2" => Ok(TopicHash::Blake2),
+                "keccak" => Ok(TopicHash::Keccak),
+                _ => {
+                    Err(format_err_spanned!(
+                        lit_str,
+                        "expected one of `\"blake2\"` or `\"keccak\"` for `topic_hash`",
+                    ))
+                }
+            }
+        } else {
+            Err(format_err_spanned!(
+                meta,
+                "expected a string literal value for `topic_hash`, e.g. `topic_hash = \"keccak\"`",
+            ))
         }
     }
 }
@@ -56,6 +110,8 @@ impl TryFrom<&[syn::Meta]> for EventConfig {
     fn try_from(args: &[syn::Meta]) -> Result<Self, Self::Error> {
         let mut anonymous: Option<&syn::Meta> = None;
         let mut signature_topic: Option<&syn::Meta> = None;
+        let mut topic_hash: Option<&syn::Meta> = None;
+        let mut schema_version: Option<&syn::Meta> = None;
         for arg in args.iter() {
             if arg.path().is_ident("anonymous") {
                 if let Some(a_meta) = anonymous {
@@ -90,6 +146,21 @@ impl TryFrom<&[syn::Meta]> for EventConfig {
                         ));
                     }
                 }
+            } else if arg.path().is_ident("topic_hash") {
+                if let Some(meta) = topic_hash {
+                    return Err(duplicate_config_err(meta, arg, "topic_hash", "event"));
+                }
+                topic_hash = Some(arg);
+            } else if arg.path().is_ident("schema_version") {
+                if let Some(meta) = schema_version {
+                    return Err(duplicate_config_err(
+                        meta,
+                        arg,
+                        "schema_version",
+                        "event",
+                    ));
+                }
+                schema_version = Some(arg);
             } else {
                 return Err(format_err_spanned!(
                     arg,
@@ -104,7 +175,23 @@ impl TryFrom<&[syn::Meta]> for EventConfig {
             None
         };
 
-        Ok(EventConfig::new(anonymous.is_some(), signature_topic))
+        let topic_hash = if let Some(meta) = topic_hash {
+            TopicHash::try_from(meta)?
+        } else {
+            TopicHash::default()
+        };
+
+        // The schema version is only recorded in the metadata, see the
+        // `EventMetadata` derive, but is validated here as well.
+        if let Some(meta) = schema_version {
+            parse_schema_version_arg(meta)?;
+        }
+
+        Ok(EventConfig::new(
+            anonymous.is_some(),
+            signature_topic,
+            topic_hash,
+        ))
     }
 }
 
@@ -204,21 +291,42 @@ fn event_derive_struct(mut s: synstructure::Structure) -> syn::Result<TokenStrea
         quote_spanned!(span=> ::core::option::Option::None)
     };
 
-    let topics = variant.bindings().iter().fold(quote!(), |acc, field| {
+    let topics = variant.bindings().iter().fold(quote!(builder.build::<Self>() #event_signature_topic), |acc, field| {
         let field_ty = &field.ast().ty;
         let field_span = field_ty.span();
-        quote_spanned!(field_span=>
-            #acc
-            .push_topic(::ink::as_option!(#field))
-        )
+        // The attributes of topic fields were already validated by the filter above.
+        if matches!(is_combined_topic(field.ast()), Ok(true)) {
+            let hash_ty = match config.topic_hash {
+                TopicHash::Blake2 => quote!(::ink::env::hash::Blake2x256),
+                TopicHash::Keccak => quote!(::ink::env::hash::Keccak256),
+            };
+            return quote_spanned!(field_span=>
+                #acc
+                .push_topic(::core::option::Option::Some(&{
+                    let mut output = <#hash_ty as ::ink::env::hash::HashOutput>::Type::default();
+                    ::ink::env::hash_encoded::<#hash_ty, #field_ty>(#field, &mut output);
+                    output
+                }))
+            )
+        }
+        match config.topic_hash {
+            TopicHash::Blake2 => {
+                quote_spanned!(field_span=>
+                    #acc
+                    .push_topic(::ink::as_option!(#field))
+                )
+            }
+            TopicHash::Keccak => {
+                quote_spanned!(field_span=>
+                    ::ink::push_keccak_topic!(#acc, #field)
+                )
+            }
+        }
     });
     let pat = variant.pat();
     let topics_builder = quote!(
         #pat => {
-            builder
-                .build::<Self>()
-                #event_signature_topic
-                #topics
+            #topics
                 .finish()
         }
     );
@@ -260,10 +368,34 @@ fn has_ink_topic_attribute(field: &synstructure::BindingInfo) -> syn::Result<boo
         ))
     } else {
         let attrs = parse_arg_attrs(&field.ast().attrs)?;
+        is_combined_topic(field.ast())?;
         has_ink_attribute(&attrs, "topic")
     }
 }
 
+/// Checks if the given field is annotated with `#[ink(topic(combine))]`, i.e. if its
+/// topic is the hash of its whole SCALE encoding.
+///
+/// Returns `Err` if `topic(..)` has any other argument than `combine`.
+fn is_combined_topic(field: &syn::Field) -> syn::Result<bool> {
+    for meta in parse_arg_attrs(&field.attrs)? {
+        if let syn::Meta::List(list) = &meta {
+            if list.path.is_ident("topic") {
+                return match list.parse_args::<syn::Ident>() {
+                    Ok(ident) if ident == "combine" => Ok(true),
+                    _ => {
+                        Err(syn::Error::new(
+                            list.span(),
+                            "expected `#[ink(topic(combine))]`",
+                        ))
+                    }
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
 /// Checks if the given attributes contain an `ink` attribute with the given path.
 fn has_ink_attribute(ink_attrs: &[syn::Meta], path: &str) -> syn::Result<bool> {
     let mut present = false;
@@ -311,6 +443,35 @@ fn parse_arg_attrs(attrs: &[syn::Attribute]) -> syn::Result<Vec<syn::Meta>> {
     Ok(ink_attrs)
 }
 
+/// Parses the version of the event schema from the `schema_version` attribute.
+///
+/// # Errors
+/// - Name-value pair is not specified correctly.
+/// - Provided value is not a `u16` integer literal.
+fn parse_schema_version_arg(meta: &syn::Meta) -> syn::Result<u16> {
+    if let syn::Meta::NameValue(syn::MetaNameValue {
+        value:
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit_int),
+                ..
+            }),
+        ..
+    }) = meta
+    {
+        lit_int.base10_parse::<u16>().map_err(|_| {
+            format_err_spanned!(
+                lit_int,
+                "expected a `u16` integer value for `schema_version`",
+            )
+        })
+    } else {
+        Err(format_err_spanned!(
+            meta,
+            "expected an integer literal value for `schema_version`, e.g. `schema_version = 2`",
+        ))
+    }
+}
+
 /// Parses signature topic from the list of attributes.
 ///
 /// # Errors
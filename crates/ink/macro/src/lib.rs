@@ -205,6 +205,33 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///
 ///     **Default value:** `DefaultEnvironment` defined in `ink_env` crate.
 ///
+/// - `max_messages: usize`
+///
+///     Tells the ink! code generator to fail compilation if the ink! smart contract
+///     defines more than the given number of messages. Messages of inherent and of
+///     trait implementation blocks are counted alike.
+///
+///     This is useful for runtimes which limit the number of dispatchable entries of
+///     a contract.
+///
+///     **Usage Example:**
+///     ```
+///     #[ink::contract(max_messages = 2)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** No limit.
+///
 /// ## Analysis
 ///
 /// The `#[ink::contract]` macro fully analyses its input smart contract
@@ -221,6 +248,10 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///
 ///     For more information visit the `ink::storage` crate documentation.
 ///
+///     A field annotated with `#[ink(encrypted = C)]` is stored as
+///     `ink::storage::Encrypted<T, C>`, its value is encrypted with the
+///     `ink::storage::Cipher` `C` before it is written to the contract storage.
+///
 ///     **Example:**
 ///
 ///     ```
@@ -390,6 +421,195 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///     # }
 ///     ```
 ///
+///     Alternatively the `selector_name` flag composes the selector from the given name
+///     instead of from the method's identifier; a namespace or trait path of the
+///     implementation block is still prepended. This allows renaming an ink! message or
+///     ink! constructor without changing its selector. `selector` and `selector_name`
+///     cannot be used together.
+///
+///     ```
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new(initial_value: bool) -> Self {
+///         #     Flipper { value: initial_value }
+///         # }
+///         /// Flips the current value, keeps the selector of the former `flip` message.
+///         #[ink(message, selector_name = "flip")]
+///         pub fn toggle(&mut self) {
+///             self.value = !self.value;
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Fallback messages:**
+///
+///     Calls with a selector that matches none of the ink! messages revert. An ink!
+///     message flagged with the wildcard selector `selector = _` is invoked for such
+///     calls instead, similar to Solidity's `fallback()` function. At most one ink!
+///     message may have the wildcard selector, and the only other message allowed
+///     next to it is one flagged with the wildcard complement `selector = @`. The raw
+///     call input, including the unmatched selector, can be read using
+///     `ink::env::decode_input`.
+///
+///     Note that `#[ink(default)]` does not define a fallback message, it only marks
+///     the message that off-chain consumers (e.g. UIs) should present by default.
+///
+///     ```
+///     # #[ink::contract]
+///     # mod proxy {
+///         # #[ink(storage)]
+///         # pub struct Proxy {}
+///     impl Proxy {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Proxy {}
+///         # }
+///         /// Handles all calls with an unknown selector.
+///         #[ink(message, selector = _)]
+///         pub fn fallback(&mut self) {
+///             let _selector = ink::env::decode_input::<[u8; 4]>().unwrap();
+///         }
+///
+///         /// Handles calls with the well-known wildcard complement selector.
+///         #[ink(message, selector = @)]
+///         pub fn complement(&mut self) {}
+///     }
+///     # }
+///     ```
+///
+///     **Reentrancy guard:**
+///
+///     An ink! message with a `&mut self` receiver can be flagged as `non_reentrant`.
+///     While such a message is being executed, a flag is set in a dedicated storage
+///     cell of the contract (see `ink::codegen::REENTRANCY_GUARD_KEY`). Calling any
+///     `non_reentrant` message of the same contract while the flag is set reverts with
+///     `ink::LangError::ReentrantCall`. The flag is cleared once the message returns.
+///     If the message reverts or panics, the storage changes of the call are rolled
+///     back, and this includes the flag.
+///
+///     Note that `pallet-contracts` already denies reentrant calls, unless the
+///     cross-contract call that leaves the contract is built with
+///     `CallFlags::ALLOW_REENTRY`. The guard protects `non_reentrant` messages of
+///     contracts that need to allow reentry, e.g. for callbacks into other messages.
+///     Messages that are not flagged are not affected by the guard. The guard is not
+///     applied when a message is called directly from an `#[ink::test]`.
+///
+///     ```
+///     # #[ink::contract]
+///     # mod vault {
+///         # #[ink(storage)]
+///         # pub struct Vault {
+///         #     value: Balance,
+///         # }
+///     impl Vault {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Vault { value: 0 }
+///         # }
+///         /// Withdraws all funds, cannot be re-entered by the recipient.
+///         #[ink(message, non_reentrant)]
+///         pub fn withdraw(&mut self) {
+///             let value = core::mem::take(&mut self.value);
+///             self.env().transfer(self.env().caller(), value).unwrap();
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Externally owned account callers only:**
+///
+///     An ink! message can be flagged as `eoa_only` in order to reject calls made by
+///     other contracts. Such messages revert with `ink::LangError::CallerNotOrigin`
+///     unless the caller is the origin of the call stack, i.e. a plain account (see
+///     `ink::env::caller_is_origin`). The flag is also recorded as `eoaOnly` in the
+///     message metadata. The check is not applied when a message is called directly
+///     from an `#[ink::test]`.
+///
+///     ```
+///     # #[ink::contract]
+///     # mod admin {
+///         # #[ink(storage)]
+///         # pub struct Admin {
+///         #     admin: AccountId,
+///         # }
+///     impl Admin {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Admin { admin: Self::env().caller() }
+///         # }
+///         /// Hands over the admin role, cannot be called by other contracts.
+///         #[ink(message, eoa_only)]
+///         pub fn set_admin(&mut self, admin: AccountId) {
+///             assert_eq!(self.env().caller(), self.admin);
+///             self.admin = admin;
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Generated `default` constructor:**
+///
+///     Flagging an ink! constructor of an inherent implementation block with
+///     `default_impl` additionally generates an ink! constructor named `default`
+///     without arguments. It calls the flagged constructor with the default value of
+///     each of its arguments, hence all arguments have to implement `Default`. At most
+///     one ink! constructor can be flagged.
+///
+///     ```
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         /// Also generates `Flipper::default()`, which calls `Flipper::new(false)`.
+///         #[ink(constructor, default_impl)]
+///         pub fn new(initial_value: bool) -> Self {
+///             Flipper { value: initial_value }
+///         }
+///         # #[ink(message)]
+///         # pub fn get(&self) -> bool {
+///         #     self.value
+///         # }
+///     }
+///     # }
+///     ```
+///
+///     **Minimum endowment:**
+///
+///     Flagging an ink! constructor with `min_value = N` makes it payable and rejects
+///     the instantiation before the constructor body runs if less than `N` is
+///     transferred.
+///
+///     ```
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         /// Requires an endowment of at least 1000.
+///         #[ink(constructor, min_value = 1000)]
+///         pub fn new(initial_value: bool) -> Self {
+///             Flipper { value: initial_value }
+///         }
+///         # #[ink(message)]
+///         # pub fn get(&self) -> bool {
+///         #     self.value
+///         # }
+///     }
+///     # }
+///     ```
+///
 /// ## Interacting with the Contract Executor
 ///
 /// The `ink_env` crate provides facilities to interact with the contract executor that
@@ -481,6 +701,11 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// An ink! message can declare the events it emits using the `emits` flag, e.g.
+/// `#[ink(message, emits = Transferred, emits = Approved)]`. The declared types must be
+/// events and are recorded for the message in the contract metadata. Note that the
+/// events still have to be emitted explicitly by the message.
+///
 /// ## Example: Flipper
 ///
 /// The below code shows the complete implementation of the so-called Flipper
@@ -663,6 +888,18 @@ pub fn trait_definition(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// `signature_topic` and `anonymous` are conflicting arguments.
 ///
+/// A field annotated with `#[ink(topic(combine))]` is published as a single topic
+/// holding the hash of its SCALE encoding, even if the encoding would fit into a topic.
+/// This allows indexing an event by a struct-typed field as a whole.
+///
+/// Additional traits can be derived for the event with `derive = "Clone, Debug"`, e.g. to
+/// compare emitted events in unit tests. This is equivalent to annotating the event with
+/// `#[derive(Clone, Debug)]`, which is supported as well.
+///
+/// The version of the event schema can be specified with `schema_version = <u16>` to let
+/// off-chain indexers detect breaking changes to the event. It defaults to `1` and is
+/// only recorded in the metadata, the signature topic is unaffected.
+///
 /// # Examples
 ///
 /// ```
@@ -689,6 +926,22 @@ pub fn trait_definition(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     #[ink(topic)]
 ///     pub topic: [u8; 32],
 /// }
+///
+/// // Setting `derive = "<traits>"` derives the given traits for the event.
+/// #[ink::event(derive = "Clone, Debug, PartialEq")]
+/// pub struct MyComparableEvent {
+///     pub field: u32,
+///     #[ink(topic)]
+///     pub topic: [u8; 32],
+/// }
+///
+/// // Setting `schema_version = <u16>` records the version of the event in the metadata.
+/// #[ink::event(schema_version = 2)]
+/// pub struct MyVersionedEvent {
+///     pub field: u32,
+///     #[ink(topic)]
+///     pub topic: [u8; 32],
+/// }
 /// ```
 #[proc_macro_attribute]
 pub fn event(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -820,8 +1073,8 @@ pub fn event(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// ## Header Arguments
 ///
-/// The `#[ink::storage_item]` macro can be provided with an additional comma-separated
-/// header argument:
+/// The `#[ink::storage_item]` macro can be provided with additional comma-separated
+/// header arguments:
 ///
 /// - `derive: bool`
 ///
@@ -846,6 +1099,72 @@ pub fn event(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     ```
 ///
 ///     **Default value:** true.
+///
+/// - `env: impl Environment`
+///
+///     Combines the storage keys of all fields with the `STORAGE_KEY_SALT` of the given
+///     environment. This is useful if the storage layout has to match the one of e.g. a
+///     runtime pallet. It cannot be combined with a generic `StorageKey` salt.
+///
+///     If `derive = false` is set, `#[ink(env = ...)]` has to be provided to the
+///     `StorageKey` derive as well.
+///
+///     **Usage Example:**
+///     ```
+///     use ink::storage::traits::StorageKey;
+///
+///     #[derive(Clone)]
+///     pub struct PalletEnvironment;
+///
+///     impl ink::env::Environment for PalletEnvironment {
+///         const MAX_EVENT_TOPICS: usize = 4;
+///         const STORAGE_KEY_SALT: ink::primitives::Key = 0x1234;
+///
+///         type AccountId = ink::primitives::AccountId;
+///         type Balance = u128;
+///         type Hash = ink::primitives::Hash;
+///         type Timestamp = u64;
+///         type BlockNumber = u32;
+///         type ChainExtension = ink::env::NoChainExtension;
+///     }
+///
+///     #[ink::storage_item(env = PalletEnvironment)]
+///     struct Data {
+///         value: ink::storage::Lazy<u32>,
+///     }
+///
+///     assert_eq!(<Data as StorageKey>::KEY, 0x1234);
+///     ```
+///
+///     **Default value:** the storage keys are not salted.
+///
+/// - `key: u32`
+///
+///     Pins the root storage key of the storage item to the given value instead of
+///     deriving it from the field position. This keeps the storage key stable if the
+///     item is moved within the contract storage, e.g. across an upgrade. It cannot be
+///     combined with a generic `StorageKey` salt and is combined with the salt of `env`
+///     if both are given.
+///
+///     Uniqueness of the key is not checked, the author has to make sure it doesn't
+///     collide with any other storage key of the contract.
+///
+///     If `derive = false` is set, `#[ink(key = ...)]` has to be provided to the
+///     `StorageKey` derive as well.
+///
+///     **Usage Example:**
+///     ```
+///     use ink::storage::traits::StorageKey;
+///
+///     #[ink::storage_item(key = 0xDEADBEEF)]
+///     struct Data {
+///         value: ink::storage::Lazy<u32>,
+///     }
+///
+///     assert_eq!(<Data as StorageKey>::KEY, 0xDEADBEEF);
+///     ```
+///
+///     **Default value:** the root key is derived from the position of the item.
 #[proc_macro_attribute]
 pub fn storage_item(attr: TokenStream, item: TokenStream) -> TokenStream {
     storage_item::generate(attr.into(), item.into()).into()
@@ -889,6 +1208,24 @@ pub fn storage_item(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// # Parameterized Tests
+///
+/// A test can be run for several inputs by providing them with the `cases`
+/// argument. One test is generated for every case, named after the annotated
+/// function with a `_case_<index>` suffix. The values of a case are bound to the
+/// arguments of the test function in order.
+///
+/// ```
+/// #[cfg(test)]
+/// mod tests {
+///     // Expands into the tests `add_works_case_0` and `add_works_case_1`.
+///     #[ink::test(cases = [(1, 2, 3), (40, 2, 42)])]
+///     fn add_works(a: u32, b: u32, expected: u32) {
+///         assert_eq!(a + b, expected);
+///     }
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
     ink_test::generate(attr.into(), item.into()).into()
@@ -1437,6 +1774,13 @@ synstructure::decl_derive!(
     ///
     /// If the event is annotated with `#[ink(anonymous)]` then no signature topic is generated.
     /// `#[ink(signature_topic = _)]` should not be used.
+    ///
+    /// # Optional Topics
+    ///
+    /// For a topic field of type `Option<T>`, `Some(value)` publishes the same topic as a
+    /// field of type `T` with `value` would. `None` publishes the fixed
+    /// [`ink::env::event::NONE_TOPIC`] sentinel, which is distinct from the topic of e.g.
+    /// `Some(AccountId::from([0x00; 32]))`.
     event::event_derive
 );
 
@@ -1532,9 +1876,13 @@ synstructure::decl_derive!(
     storage::storable_hint_derive
 );
 synstructure::decl_derive!(
-    [StorageKey] =>
+    [StorageKey, attributes(ink)] =>
     /// Derives `ink::storage`'s `StorageKey` trait for the given `struct` or `enum`.
     ///
+    /// The storage key is the generic `StorageKey` salt of the type if there is one.
+    /// Otherwise it is the `Environment::STORAGE_KEY_SALT` of the environment specified
+    /// via `#[ink(env = ...)]`, or `0` if no environment is specified.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1594,6 +1942,28 @@ synstructure::decl_derive!(
     /// ```
     storage::storage_layout_derive
 );
+synstructure::decl_derive!(
+    [ConstMaxEncodedLen, attributes(codec)] =>
+    /// Derives `ink::storage`'s `ConstMaxEncodedLen` trait for the given `struct` or `enum`.
+    ///
+    /// Fields marked with `#[codec(skip)]` are ignored, fields marked with
+    /// `#[codec(compact)]` are bounded by their compact encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ink::storage::traits::ConstMaxEncodedLen;
+    ///
+    /// #[derive(ConstMaxEncodedLen)]
+    /// enum Balance {
+    ///     Free(u128),
+    ///     Reserved { amount: u128, until: Option<u32> },
+    /// }
+    ///
+    /// assert_eq!(<Balance as ConstMaxEncodedLen>::MAX_ENCODED_LEN, 22);
+    /// ```
+    storage::const_max_encoded_len_derive
+);
 
 /// Derive the re-exported traits `ink::scale::Encode`, `ink::scale::Decode` and
 /// `ink::scale_info::TypeInfo`. It enables using the built in derive macros for these
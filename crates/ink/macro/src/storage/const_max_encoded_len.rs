@@ -0,0 +1,90 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned;
+
+/// Returns `true` if the field carries the `#[codec(<flag>)]` attribute.
+fn has_codec_flag(field: &syn::Field, flag: &str) -> bool {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("codec"))
+        .any(|attr| {
+            let mut found = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                found |= meta.path.is_ident(flag);
+                Ok(())
+            });
+            found
+        })
+}
+
+/// The maximum encoded length of all fields of the variant, encoded one after another.
+fn variant_max_encoded_len(variant: &synstructure::VariantInfo) -> TokenStream2 {
+    variant
+        .ast()
+        .fields
+        .iter()
+        .filter(|field| !has_codec_flag(field, "skip"))
+        .fold(quote!(0usize), |acc, field| {
+            let ty = &field.ty;
+            let span = ty.span();
+            if has_codec_flag(field, "compact") {
+                quote_spanned!(span =>
+                    #acc.saturating_add(<::ink::scale::Compact<#ty> as ::ink::storage::traits::ConstMaxEncodedLen>::MAX_ENCODED_LEN)
+                )
+            } else {
+                quote_spanned!(span =>
+                    #acc.saturating_add(<#ty as ::ink::storage::traits::ConstMaxEncodedLen>::MAX_ENCODED_LEN)
+                )
+            }
+        })
+}
+
+/// Derives `ink_storage`'s `ConstMaxEncodedLen` trait for the given `struct` or `enum`.
+pub fn const_max_encoded_len_derive(mut s: synstructure::Structure) -> TokenStream2 {
+    s.add_bounds(synstructure::AddBounds::Fields)
+        .underscore_const(true);
+    let max_encoded_len = match &s.ast().data {
+        syn::Data::Struct(_) => variant_max_encoded_len(&s.variants()[0]),
+        syn::Data::Enum(_) => {
+            let variants = s.variants().iter().map(variant_max_encoded_len);
+            // The variant index is encoded as a single byte in front of the fields.
+            quote! {{
+                let mut max = 0usize;
+                #(
+                    let len = #variants;
+                    if len > max {
+                        max = len;
+                    }
+                )*
+                max.saturating_add(1)
+            }}
+        }
+        _ => {
+            panic!("cannot derive `ConstMaxEncodedLen` for Rust `union` items")
+        }
+    };
+
+    s.gen_impl(quote! {
+        gen impl ::ink::storage::traits::ConstMaxEncodedLen for @Self {
+            const MAX_ENCODED_LEN: ::core::primitive::usize = #max_encoded_len;
+        }
+    })
+}
@@ -17,12 +17,14 @@
 //! This crate provides helpers to define your very own custom storage data
 //! structures that work along the `ink_storage` data structures.
 
+mod const_max_encoded_len;
 mod storable;
 mod storable_hint;
 mod storage_key;
 mod storage_layout;
 
 pub use self::{
+    const_max_encoded_len::const_max_encoded_len_derive,
     storable::storable_derive,
     storable_hint::storable_hint_derive,
     storage_key::storage_key_derive,
@@ -23,10 +23,9 @@ pub fn storage_key_derive(mut s: synstructure::Structure) -> TokenStream2 {
     s.add_bounds(synstructure::AddBounds::None)
         .underscore_const(true);
 
-    let salt = if let Some(param) = find_storage_key_salt(s.ast()) {
-        param.ident.to_token_stream()
-    } else {
-        quote! { () }
+    let salt = match storage_key_salt(s.ast()) {
+        Ok(salt) => salt,
+        Err(err) => return err.to_compile_error(),
     };
 
     s.gen_impl(quote! {
@@ -35,3 +34,59 @@ pub fn storage_key_derive(mut s: synstructure::Structure) -> TokenStream2 {
         }
     })
 }
+
+/// Returns the salt of the storage key.
+///
+/// This is either the generic `StorageKey` parameter of the type, the
+/// `ink::env::StorageKeySalt` of the environment specified via `#[ink(env = ...)]`
+/// or `()`. A root key specified via `#[ink(key = ...)]` is combined with the latter
+/// two.
+fn storage_key_salt(ast: &syn::DeriveInput) -> syn::Result<TokenStream2> {
+    let mut env: Option<syn::Path> = None;
+    let mut key: Option<syn::LitInt> = None;
+    for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident("ink")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("env") {
+                if env.is_some() {
+                    return Err(meta.error("encountered duplicate `env` argument"))
+                }
+                env = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("key") {
+                if key.is_some() {
+                    return Err(meta.error("encountered duplicate `key` argument"))
+                }
+                key = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("unsupported `StorageKey` derive argument"))
+            }
+            Ok(())
+        })?;
+    }
+
+    if let Some(param) = find_storage_key_salt(ast) {
+        let arg = match (env, key) {
+            (Some(env), _) => Some(("env", env.to_token_stream())),
+            (None, Some(key)) => Some(("key", key.to_token_stream())),
+            (None, None) => None,
+        };
+        if let Some((name, tokens)) = arg {
+            return Err(syn::Error::new_spanned(
+                tokens,
+                format!(
+                    "`{name}` cannot be combined with the generic `StorageKey` salt `{}`",
+                    param.ident
+                ),
+            ))
+        }
+        return Ok(param.ident.to_token_stream())
+    }
+
+    let salt = match env {
+        Some(env) => quote! { ::ink::env::StorageKeySalt<#env> },
+        None => quote! { () },
+    };
+    match key {
+        Some(key) => Ok(quote! { ::ink::storage::traits::ManualKey<#key, #salt> }),
+        None => Ok(salt),
+    }
+}
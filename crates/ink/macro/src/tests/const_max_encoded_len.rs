@@ -0,0 +1,118 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::const_max_encoded_len_derive;
+
+#[test]
+fn unit_struct_works() {
+    crate::test_derive! {
+        const_max_encoded_len_derive {
+            struct UnitStruct;
+        }
+        expands to {
+            const _: () = {
+                impl ::ink::storage::traits::ConstMaxEncodedLen for UnitStruct {
+                    const MAX_ENCODED_LEN: ::core::primitive::usize = 0usize;
+                }
+            };
+        }
+    }
+}
+
+#[test]
+fn struct_works() {
+    crate::test_derive! {
+        const_max_encoded_len_derive {
+            struct NamedFields {
+                a: i32,
+                #[codec(compact)]
+                b: u64,
+                #[codec(skip)]
+                c: [u8; 32],
+            }
+        }
+        expands to {
+            const _: () = {
+                impl ::ink::storage::traits::ConstMaxEncodedLen for NamedFields {
+                    const MAX_ENCODED_LEN: ::core::primitive::usize = 0usize
+                        .saturating_add(<i32 as ::ink::storage::traits::ConstMaxEncodedLen>::MAX_ENCODED_LEN)
+                        .saturating_add(<::ink::scale::Compact<u64> as ::ink::storage::traits::ConstMaxEncodedLen>::MAX_ENCODED_LEN);
+                }
+            };
+        }
+        no_build
+    }
+}
+
+#[test]
+fn enum_works() {
+    crate::test_derive! {
+        const_max_encoded_len_derive {
+            enum MixedEnum {
+                A,
+                B(i32, [u8; 32]),
+                C { a: i32 },
+            }
+        }
+        expands to {
+            const _: () = {
+                impl ::ink::storage::traits::ConstMaxEncodedLen for MixedEnum {
+                    const MAX_ENCODED_LEN: ::core::primitive::usize = {
+                        let mut max = 0usize;
+                        let len = 0usize;
+                        if len > max {
+                            max = len;
+                        }
+                        let len = 0usize
+                            .saturating_add(<i32 as ::ink::storage::traits::ConstMaxEncodedLen>::MAX_ENCODED_LEN)
+                            .saturating_add(<[u8; 32] as ::ink::storage::traits::ConstMaxEncodedLen>::MAX_ENCODED_LEN);
+                        if len > max {
+                            max = len;
+                        }
+                        let len = 0usize
+                            .saturating_add(<i32 as ::ink::storage::traits::ConstMaxEncodedLen>::MAX_ENCODED_LEN);
+                        if len > max {
+                            max = len;
+                        }
+                        max.saturating_add(1)
+                    };
+                }
+            };
+        }
+        no_build
+    }
+}
+
+#[test]
+fn generic_struct_works() {
+    crate::test_derive! {
+        const_max_encoded_len_derive {
+            struct GenericStruct<T> {
+                a: Option<T>,
+            }
+        }
+        expands to {
+            const _: () = {
+                impl<T> ::ink::storage::traits::ConstMaxEncodedLen for GenericStruct<T>
+                where
+                    Option<T>: ::ink::storage::traits::ConstMaxEncodedLen
+                {
+                    const MAX_ENCODED_LEN: ::core::primitive::usize = 0usize
+                        .saturating_add(<Option<T> as ::ink::storage::traits::ConstMaxEncodedLen>::MAX_ENCODED_LEN);
+                }
+            };
+        }
+        no_build
+    }
+}
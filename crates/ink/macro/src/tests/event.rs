@@ -186,6 +186,52 @@ fn struct_with_fields_and_some_topics() {
     }
 }
 
+#[test]
+fn struct_with_combined_topic() {
+    crate::test_derive! {
+        event_derive {
+            #[derive(scale::Encode)]
+            struct Event {
+                #[ink(topic(combine))]
+                field_1: (u32, u64),
+            }
+        }
+        expands to {
+            const _: () = {
+                impl ::ink::env::Event for Event {
+                    type RemainingTopics = [::ink::env::event::state::HasRemainingTopics; 2usize];
+
+                    const SIGNATURE_TOPIC: ::core::option::Option<[::core::primitive::u8; 32]> =
+                        ::core::option::Option::Some( ::ink::blake2x256!("Event((u32,u64))") );
+
+                    fn topics<E, B>(
+                        &self,
+                        builder: ::ink::env::event::TopicsBuilder<::ink::env::event::state::Uninit, E, B>,
+                    ) -> <B as ::ink::env::event::TopicsBuilderBackend<E>>::Output
+                    where
+                        E: ::ink::env::Environment,
+                        B: ::ink::env::event::TopicsBuilderBackend<E>,
+                    {
+                        match self {
+                            Event { field_1 : __binding_0 , } => {
+                                builder
+                                    .build::<Self>()
+                                    .push_topic(Self::SIGNATURE_TOPIC.as_ref())
+                                    .push_topic(::core::option::Option::Some(&{
+                                        let mut output = <::ink::env::hash::Blake2x256 as ::ink::env::hash::HashOutput>::Type::default();
+                                        ::ink::env::hash_encoded::<::ink::env::hash::Blake2x256, (u32, u64)>(__binding_0, &mut output);
+                                        output
+                                    }))
+                                    .finish()
+                            }
+                        }
+                    }
+                }
+            };
+        } no_build
+    }
+}
+
 #[test]
 fn custom_signature_topic() {
     crate::test_derive! {
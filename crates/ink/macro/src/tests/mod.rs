@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod const_max_encoded_len;
 mod event;
 mod event_metadata;
 mod storable;
@@ -20,6 +21,7 @@ mod storage_key;
 mod storage_layout;
 
 use crate::storage::{
+    const_max_encoded_len_derive,
     storable_derive,
     storable_hint_derive,
     storage_key_derive,
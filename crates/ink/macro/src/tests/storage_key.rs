@@ -190,3 +190,47 @@ fn enum_salt_works() {
         no_build
     }
 }
+
+#[test]
+fn struct_env_works() {
+    crate::test_derive! {
+        storage_key_derive {
+            #[ink(env = crate::CustomEnvironment)]
+            struct NamedFields {
+                a: i32,
+                b: [u8; 32],
+                d: Box<i32>,
+            }
+        }
+        expands to {
+            const _: () = {
+                impl ::ink::storage::traits::StorageKey for NamedFields {
+                    const KEY: ::ink::primitives::Key = <::ink::env::StorageKeySalt<crate::CustomEnvironment> as ::ink::storage::traits::StorageKey>::KEY;
+                }
+            };
+        }
+        no_build
+    }
+}
+
+#[test]
+fn struct_key_works() {
+    crate::test_derive! {
+        storage_key_derive {
+            #[ink(key = 0xDEADBEEF)]
+            struct NamedFields {
+                a: i32,
+                b: [u8; 32],
+                d: Box<i32>,
+            }
+        }
+        expands to {
+            const _: () = {
+                impl ::ink::storage::traits::StorageKey for NamedFields {
+                    const KEY: ::ink::primitives::Key = <::ink::storage::traits::ManualKey<0xDEADBEEF, ()> as ::ink::storage::traits::StorageKey>::KEY;
+                }
+            };
+        }
+        no_build
+    }
+}
@@ -31,3 +31,50 @@ where
     }
     Ok(())
 }
+
+/// Returns `Ok` if the caller transferred at least `min_value` to the callee.
+///
+/// # Errors
+///
+/// If the caller transferred less than `min_value`, or if `min_value` exceeds the
+/// range of the balance type, in which case no transfer can satisfy it.
+#[inline]
+pub fn ensure_min_value<E>(min_value: u128) -> Result<(), DispatchError>
+where
+    E: Environment,
+{
+    let transferred = ink_env::transferred_value::<E>();
+    match <E as Environment>::Balance::try_from(min_value) {
+        Ok(min_value) if transferred >= min_value => Ok(()),
+        _ => Err(DispatchError::TransferredValueTooLow),
+    }
+}
+
+/// The storage key of the flag guarding `non_reentrant` messages against reentrancy.
+///
+/// Equals `selector_id!("ink::reentrancy_guard")`.
+pub const REENTRANCY_GUARD_KEY: ink_primitives::Key = 0x212912B3;
+
+/// Marks the contract as executing a `non_reentrant` message.
+///
+/// Returns `false` if the contract already is executing a `non_reentrant` message,
+/// i.e. if the current call is a reentrant one.
+///
+/// # Note
+///
+/// The flag does not need to be reset if the message reverts or traps, since in
+/// this case all storage changes of the call are rolled back.
+#[inline]
+pub fn enter_non_reentrant() -> bool {
+    if ink_env::contains_contract_storage(&REENTRANCY_GUARD_KEY).is_some() {
+        return false
+    }
+    ink_env::set_contract_storage(&REENTRANCY_GUARD_KEY, &true);
+    true
+}
+
+/// Marks the contract as no longer executing a `non_reentrant` message.
+#[inline]
+pub fn exit_non_reentrant() {
+    ink_env::clear_contract_storage(&REENTRANCY_GUARD_KEY);
+}
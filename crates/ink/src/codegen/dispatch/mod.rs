@@ -17,9 +17,16 @@ mod info;
 mod type_check;
 
 pub use self::{
-    execution::deny_payment,
+    execution::{
+        deny_payment,
+        ensure_min_value,
+        enter_non_reentrant,
+        exit_non_reentrant,
+        REENTRANCY_GUARD_KEY,
+    },
     info::ContractCallBuilder,
     type_check::{
+        DispatchEvent,
         DispatchInput,
         DispatchOutput,
     },
@@ -67,3 +67,22 @@ where
 pub struct DispatchOutput<T>(T)
 where
     T: scale::Encode + 'static;
+
+/// Used to check if `T` is allowed as event declared by an ink! message.
+///
+/// # Note
+///
+/// An event declared via `#[ink(emits = E)]` must implement [`ink_env::Event`].
+///
+/// # Example
+///
+/// This fails to compile since `Foo` is not an event.
+///
+/// ```compile_fail
+/// # use ink::codegen::DispatchEvent;
+/// struct Foo {}
+/// const _: () = ink::codegen::utils::consume_type::<DispatchEvent<Foo>>();
+/// ```
+pub struct DispatchEvent<T>(T)
+where
+    T: ink_env::Event;
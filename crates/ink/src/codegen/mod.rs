@@ -23,9 +23,14 @@ pub mod utils;
 pub use self::{
     dispatch::{
         deny_payment,
+        ensure_min_value,
+        enter_non_reentrant,
+        exit_non_reentrant,
         ContractCallBuilder,
+        DispatchEvent,
         DispatchInput,
         DispatchOutput,
+        REENTRANCY_GUARD_KEY,
     },
     env::{
         Env,
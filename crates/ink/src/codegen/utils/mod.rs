@@ -16,8 +16,13 @@
 
 mod identity_type;
 mod same_type;
+mod signature_topics;
 
 pub use self::{
     identity_type::consume_type,
     same_type::IsSameType,
+    signature_topics::{
+        count_signature_topics,
+        signature_topics,
+    },
 };
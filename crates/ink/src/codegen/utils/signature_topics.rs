@@ -0,0 +1,96 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Returns `true` if both signature topics are equal.
+const fn topic_eq(lhs: &[u8; 32], rhs: &[u8; 32]) -> bool {
+    let mut i = 0;
+    while i < 32 {
+        if lhs[i] != rhs[i] {
+            return false
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if the topic at `index` already occurs before it.
+const fn is_repeated(topics: &[Option<[u8; 32]>], index: usize) -> bool {
+    let Some(topic) = &topics[index] else {
+        return false
+    };
+    let mut i = 0;
+    while i < index {
+        if let Some(other) = &topics[i] {
+            if topic_eq(topic, other) {
+                return true
+            }
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns the number of distinct signature topics, not counting the `None` topics of
+/// anonymous events.
+pub const fn count_signature_topics(topics: &[Option<[u8; 32]>]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < topics.len() {
+        if topics[i].is_some() && !is_repeated(topics, i) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Returns the `N` distinct signature topics in the order of their first occurrence,
+/// skipping the `None` topics of anonymous events.
+///
+/// # Panics
+///
+/// If `N` is not the [`count_signature_topics`] of `topics`.
+pub const fn signature_topics<const N: usize>(
+    topics: &[Option<[u8; 32]>],
+) -> [[u8; 32]; N] {
+    let mut signatures = [[0; 32]; N];
+    let mut count = 0;
+    let mut i = 0;
+    while i < topics.len() {
+        if let Some(topic) = topics[i] {
+            if !is_repeated(topics, i) {
+                signatures[count] = topic;
+                count += 1;
+            }
+        }
+        i += 1;
+    }
+    assert!(count == N, "the number of signature topics must be `N`");
+    signatures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_topics_skips_anonymous_and_repeated_topics() {
+        const TOPICS: &[Option<[u8; 32]>] =
+            &[Some([1; 32]), None, Some([2; 32]), Some([1; 32])];
+        const COUNT: usize = count_signature_topics(TOPICS);
+        const SIGNATURES: [[u8; 32]; COUNT] = signature_topics(TOPICS);
+
+        assert_eq!(SIGNATURES, [[1; 32], [2; 32]]);
+    }
+}
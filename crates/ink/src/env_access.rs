@@ -416,6 +416,117 @@ where
         ink_env::minimum_balance::<E>()
     }
 
+    /// Returns the balance of the executed contract that can be spent without the
+    /// account falling below the minimum balance (i.e. the chain's existential deposit).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// #[ink(message)]
+    /// pub fn withdraw_all(&mut self) {
+    ///     let amount = self.env().spendable_balance();
+    ///     self.env().transfer(self.env().caller(), amount).unwrap();
+    /// }
+    /// #
+    /// #     }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::spendable_balance`]
+    pub fn spendable_balance(self) -> E::Balance {
+        ink_env::spendable_balance::<E>()
+    }
+
+    /// Returns the tip paid by the origin of the transaction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// #[ink(message)]
+    /// pub fn transaction_tip(&self) -> Balance {
+    ///     self.env().transaction_tip()
+    /// }
+    /// #
+    /// #     }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::transaction_tip`]
+    pub fn transaction_tip(self) -> E::Balance {
+        ink_env::transaction_tip::<E>()
+    }
+
+    /// Returns the author of the current block, if known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// #[ink(message)]
+    /// pub fn block_author(&self) -> Option<AccountId> {
+    ///     self.env().block_author()
+    /// }
+    /// #
+    /// #     }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::block_author`]
+    pub fn block_author(self) -> Option<E::AccountId> {
+        ink_env::block_author::<E>()
+    }
+
+    /// Returns by how much the balance of the executed contract increased since the
+    /// call started.
+    ///
+    /// # Note
+    ///
+    /// This is only available in the off-chain environment.
+    ///
+    /// For more details visit: [`ink_env::balance_delta_since_call_start`]
+    #[cfg(feature = "std")]
+    pub fn balance_delta_since_call_start(self) -> E::Balance {
+        ink_env::balance_delta_since_call_start::<E>()
+    }
+
     /// Emits an event.
     pub fn emit_event<Evt>(self, event: Evt)
     where
@@ -910,6 +1021,51 @@ where
         output
     }
 
+    /// Computes the hash of the given bytes using the cryptographic hash `H`.
+    ///
+    /// This is a shorthand for [`EnvAccess::hash_bytes`], which makes it convenient to
+    /// mix different hash functions, e.g. `Keccak256` and `Blake2x256`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     use ink::env::hash::{
+    /// #         Blake2x256,
+    /// #         Keccak256,
+    /// #     };
+    /// #
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// #[ink(message)]
+    /// pub fn hashes(&self, input: Vec<u8>) -> ([u8; 32], [u8; 32]) {
+    ///     (
+    ///         self.env().hash::<Keccak256>(&input),
+    ///         self.env().hash::<Blake2x256>(&input),
+    ///     )
+    /// }
+    /// #    }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::hash_bytes`]
+    pub fn hash<H>(self, input: &[u8]) -> <H as HashOutput>::Type
+    where
+        H: CryptoHash,
+    {
+        self.hash_bytes::<H>(input)
+    }
+
     /// Computes the hash of the given SCALE encoded value using the cryptographic hash
     /// `H`.
     ///
@@ -1178,6 +1334,70 @@ where
         ink_env::caller_is_origin::<E>()
     }
 
+    /// Checks whether the current call is a dry-run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// #[ink(message)]
+    /// pub fn is_dry_run(&mut self) -> bool {
+    ///     self.env().is_dry_run()
+    /// }
+    /// #    }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::is_dry_run`]
+    pub fn is_dry_run(self) -> bool {
+        ink_env::is_dry_run()
+    }
+
+    /// Returns the version of the runtime, if known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// #[ink(message)]
+    /// pub fn supports_new_feature(&self) -> bool {
+    ///     self.env()
+    ///         .runtime_version()
+    ///         .is_some_and(|version| version.spec_version >= 100)
+    /// }
+    /// #    }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::runtime_version`]
+    pub fn runtime_version(self) -> Option<ink_env::RuntimeVersion> {
+        ink_env::runtime_version()
+    }
+
     /// Returns the code hash of the contract at the given `account` id.
     ///
     /// # Example
@@ -1270,11 +1490,59 @@ where
     ///
     /// # Note
     ///
+    /// The storage of the contract is kept as is, the caller is responsible for the new
+    /// code having a compatible storage layout. It can be checked up front with
+    /// `ink_metadata::storage_layout_compatible`.
+    ///
     /// For more details visit: [`ink_env::set_code_hash`]
     pub fn set_code_hash(self, code_hash: &E::Hash) -> Result<()> {
         ink_env::set_code_hash::<E>(code_hash)
     }
 
+    /// Tries to trigger a runtime dispatchable, i.e. an extrinsic from a pallet.
+    ///
+    /// `call` is SCALE encoded and must be decodable to a valid instance of the
+    /// `RuntimeCall` enum of the runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #[derive(scale::Encode)]
+    /// enum RuntimeCall {
+    ///     #[codec(index = 0)]
+    ///     System(SystemCall),
+    /// }
+    ///
+    /// #[derive(scale::Encode)]
+    /// enum SystemCall {
+    ///     #[codec(index = 0)]
+    ///     Remark { remark: ink::prelude::vec::Vec<u8> },
+    /// }
+    ///
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// #[ink(message)]
+    /// pub fn remark(&mut self, remark: ink::prelude::vec::Vec<u8>) {
+    ///     self.env()
+    ///         .call_runtime(&RuntimeCall::System(SystemCall::Remark { remark }))
+    ///         .unwrap_or_else(|err| panic!("runtime call failed: {:?}", err))
+    /// }
+    /// #    }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::call_runtime`]
     pub fn call_runtime<Call: scale::Encode>(self, call: &Call) -> Result<()> {
         ink_env::call_runtime::<E, _>(call)
     }
@@ -1357,3 +1625,48 @@ where
         ink_env::xcm_send::<E, _>(dest, msg)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::{
+        hash::{
+            Blake2x256,
+            Keccak256,
+        },
+        DefaultEnvironment,
+    };
+
+    static TEST_INPUT: &[u8] = b"DEAD_BEEF";
+
+    fn env() -> EnvAccess<'static, DefaultEnvironment> {
+        EnvAccess::default()
+    }
+
+    #[test]
+    fn hash_keccak_256_works() {
+        assert_eq!(
+            env().hash::<Keccak256>(TEST_INPUT),
+            [
+                24, 230, 209, 59, 127, 30, 158, 244, 60, 177, 132, 150, 167, 244, 64, 69,
+                184, 123, 185, 44, 211, 199, 208, 179, 14, 64, 126, 140, 217, 69, 36,
+                216
+            ]
+        );
+    }
+
+    #[test]
+    fn hash_blake2_256_works() {
+        assert_eq!(
+            env().hash::<Blake2x256>(TEST_INPUT),
+            [
+                244, 247, 235, 182, 194, 161, 28, 69, 34, 106, 237, 7, 57, 87, 190, 12,
+                92, 171, 91, 176, 135, 52, 247, 94, 8, 112, 94, 183, 140, 101, 208, 120
+            ]
+        );
+        assert_eq!(
+            env().hash::<Blake2x256>(TEST_INPUT),
+            env().hash_bytes::<Blake2x256>(TEST_INPUT)
+        );
+    }
+}
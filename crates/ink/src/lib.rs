@@ -26,14 +26,20 @@ pub mod option_info;
 #[doc(hidden)]
 pub mod result_info;
 
+#[macro_use]
+#[doc(hidden)]
+pub mod topic_info;
+
 #[cfg_attr(not(feature = "show-codegen-docs"), doc(hidden))]
 pub mod codegen;
 
+pub mod math;
 pub mod reflect;
 
 mod chain_extension;
 mod contract_ref;
 mod env_access;
+mod max_encoded_len;
 mod message_builder;
 
 pub use ink_env as env;
@@ -49,6 +55,7 @@ pub use xcm;
 pub mod storage {
     pub mod traits {
         pub use ink_macro::{
+            ConstMaxEncodedLen,
             Storable,
             StorableHint,
             StorageKey,
@@ -57,8 +64,12 @@ pub mod storage {
         pub use ink_storage::traits::*;
     }
     pub use ink_storage::{
+        BloomMapping,
+        Cipher,
+        Encrypted,
         Lazy,
         Mapping,
+        Paginated,
         StorageVec,
     };
 }
@@ -92,4 +103,5 @@ pub use ink_primitives::{
     ConstructorResult,
     LangError,
     MessageResult,
+    Revert,
 };
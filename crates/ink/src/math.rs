@@ -0,0 +1,301 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for fee calculations and fixed-point arithmetic.
+//!
+//! A basis point is a hundredth of a percent, i.e. `10_000` basis points are `100%`.
+//! [`Fixed`] is a decimal number with 18 fractional digits, e.g. for the prices and
+//! interest rates of AMM or lending contracts.
+
+/// The number of basis points which make up `100%`.
+pub const MAX_BPS: u16 = 10_000;
+
+/// Returns `amount * bps / 10_000`, rounded down.
+///
+/// The intermediate product is never computed at full width, hence this does not
+/// overflow for large `amount`s. The result saturates at `u128::MAX` if it does not fit
+/// into a `u128`, which can only happen for `bps` greater than [`MAX_BPS`].
+pub fn apply_bps(amount: u128, bps: u16) -> u128 {
+    let bps = u128::from(bps);
+    let max_bps = u128::from(MAX_BPS);
+    // `amount * bps / max_bps` is equal to `q * bps + r * bps / max_bps` with
+    // `amount = q * max_bps + r`, since `q * bps` is a whole number.
+    let quotient = amount / max_bps;
+    let remainder = amount % max_bps;
+    quotient
+        .saturating_mul(bps)
+        .saturating_add(remainder * bps / max_bps)
+}
+
+/// Splits a fee of `bps` basis points off `amount`.
+///
+/// Returns `(amount - fee, fee)` where the fee is computed by [`apply_bps`]. The
+/// remaining amount is `0` if `bps` is greater than [`MAX_BPS`].
+pub fn split_fee(amount: u128, bps: u16) -> (u128, u128) {
+    let fee = apply_bps(amount, bps);
+    (amount.saturating_sub(fee), fee)
+}
+
+/// An unsigned fixed-point decimal number with [`Fixed::DECIMALS`] fractional digits.
+///
+/// The number is stored as an integer scaled by `10^18`, i.e. `1.5` is stored as
+/// `1_500_000_000_000_000_000`. The largest representable number is thus roughly
+/// `3.4 * 10^20`.
+///
+/// All operations are checked: they return `None` on overflow, underflow or division
+/// by zero. Results which are not exactly representable are rounded down, i.e. towards
+/// zero.
+///
+/// # Example
+///
+/// ```
+/// use ink::math::Fixed;
+///
+/// let price = Fixed::from_rational(3, 2).unwrap();
+/// let amount = Fixed::from_integer(10).unwrap();
+/// assert_eq!(amount.checked_mul(price), Fixed::from_integer(15));
+/// ```
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    scale::Encode,
+    scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Fixed(u128);
+
+impl Fixed {
+    /// The number of fractional decimal digits.
+    pub const DECIMALS: u32 = 18;
+
+    /// The scaling factor of the inner representation, i.e. `10^DECIMALS`.
+    const SCALE: u128 = 10u128.pow(Self::DECIMALS);
+
+    /// The number zero.
+    pub const ZERO: Self = Self(0);
+
+    /// The number one.
+    pub const ONE: Self = Self(Self::SCALE);
+
+    /// The largest representable number.
+    pub const MAX: Self = Self(u128::MAX);
+
+    /// Creates a number from its inner representation, i.e. the number multiplied by
+    /// `10^18`.
+    pub const fn from_inner(inner: u128) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the inner representation of the number, i.e. the number multiplied by
+    /// `10^18`.
+    pub const fn into_inner(self) -> u128 {
+        self.0
+    }
+
+    /// Creates a number from an integer.
+    ///
+    /// Returns `None` if the number is not representable.
+    pub fn from_integer(integer: u128) -> Option<Self> {
+        integer.checked_mul(Self::SCALE).map(Self)
+    }
+
+    /// Creates the number `numerator / denominator`, rounded down.
+    ///
+    /// Returns `None` if `denominator` is zero or the number is not representable.
+    pub fn from_rational(numerator: u128, denominator: u128) -> Option<Self> {
+        mul_div(numerator, Self::SCALE, denominator).map(Self)
+    }
+
+    /// Returns the integer part of the number.
+    pub const fn trunc(self) -> u128 {
+        self.0 / Self::SCALE
+    }
+
+    /// Returns `self + rhs`, or `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Returns `self - rhs`, or `None` if `rhs` is greater than `self`.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Returns `self * rhs` rounded down, or `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        mul_div(self.0, rhs.0, Self::SCALE).map(Self)
+    }
+
+    /// Returns `self / rhs` rounded down, or `None` if `rhs` is zero or on overflow.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        mul_div(self.0, Self::SCALE, rhs.0).map(Self)
+    }
+}
+
+/// Returns `a * b / divisor` rounded down, or `None` if `divisor` is zero or the
+/// result does not fit into a `u128`.
+///
+/// The intermediate product is computed at 256 bit width, hence it does not overflow.
+fn mul_div(a: u128, b: u128, divisor: u128) -> Option<u128> {
+    if divisor == 0 {
+        return None
+    }
+    let (high, low) = widening_mul(a, b);
+    if high >= divisor {
+        // The quotient is at least `2^128`.
+        return None
+    }
+    // Binary long division of `high * 2^128 + low` by `divisor`, starting with the
+    // remainder `high < divisor`.
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        // The shifted remainder is less than `2 * divisor`, hence subtracting `divisor`
+        // once is enough and the difference fits into a `u128`.
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+    Some(quotient)
+}
+
+/// Returns the full 256 bit product of `a` and `b` as `(high, low)` 128 bit halves.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    // Sum of the middle 64 bit limbs, at most `3 * (2^64 - 1)`.
+    let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+    let low = (middle << 64) | (low_low & MASK);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (high, low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_bps_works() {
+        assert_eq!(apply_bps(1_000, 30), 3);
+        assert_eq!(apply_bps(999, 30), 2);
+        assert_eq!(apply_bps(12_345, 2_500), 3_086);
+    }
+
+    #[test]
+    fn apply_bps_edge_cases() {
+        assert_eq!(apply_bps(1_000, 0), 0);
+        assert_eq!(apply_bps(1_000, MAX_BPS), 1_000);
+        assert_eq!(apply_bps(0, MAX_BPS), 0);
+        assert_eq!(apply_bps(u128::MAX, 0), 0);
+    }
+
+    #[test]
+    fn apply_bps_does_not_overflow() {
+        // A naive `u128::MAX * 30` would overflow.
+        assert_eq!(
+            apply_bps(u128::MAX, 30),
+            1020847100762815390390123822295304634
+        );
+        assert_eq!(apply_bps(u128::MAX, MAX_BPS), u128::MAX);
+        assert_eq!(apply_bps(u128::MAX - 1, MAX_BPS), u128::MAX - 1);
+        assert_eq!(apply_bps(u128::MAX, u16::MAX), u128::MAX);
+    }
+
+    #[test]
+    fn split_fee_works() {
+        assert_eq!(split_fee(1_000, 30), (997, 3));
+        assert_eq!(split_fee(1_000, 0), (1_000, 0));
+        assert_eq!(split_fee(1_000, MAX_BPS), (0, 1_000));
+        assert_eq!(
+            split_fee(u128::MAX, 5_000),
+            (u128::MAX - u128::MAX / 2, u128::MAX / 2)
+        );
+    }
+
+    #[test]
+    fn fixed_mul_div_precision() {
+        let one_and_a_half = Fixed::from_rational(3, 2).unwrap();
+        assert_eq!(one_and_a_half.into_inner(), 1_500_000_000_000_000_000);
+        assert_eq!(
+            one_and_a_half.checked_mul(one_and_a_half),
+            Fixed::from_rational(9, 4)
+        );
+
+        // `1 / 3` is rounded down to 18 decimals and stays rounded down when
+        // multiplied.
+        let third = Fixed::ONE
+            .checked_div(Fixed::from_integer(3).unwrap())
+            .unwrap();
+        assert_eq!(third.into_inner(), 333_333_333_333_333_333);
+        assert_eq!(
+            third.checked_mul(Fixed::from_integer(3).unwrap()),
+            Some(Fixed::from_inner(999_999_999_999_999_999))
+        );
+
+        let price = Fixed::from_inner(1_234_567_890_123_456_789);
+        let amount = Fixed::from_integer(1_000_000).unwrap();
+        assert_eq!(
+            price.checked_mul(amount),
+            Some(Fixed::from_inner(1_234_567_890_123_456_789_000_000))
+        );
+        assert_eq!(
+            amount.checked_mul(price).unwrap().checked_div(amount),
+            Some(price)
+        );
+        assert_eq!(Fixed::from_integer(7).unwrap().trunc(), 7);
+        assert_eq!(third.trunc(), 0);
+    }
+
+    #[test]
+    fn fixed_add_sub_works() {
+        let two = Fixed::from_integer(2).unwrap();
+        assert_eq!(two.checked_add(Fixed::ONE), Fixed::from_integer(3));
+        assert_eq!(two.checked_sub(Fixed::ONE), Some(Fixed::ONE));
+        assert_eq!(Fixed::ONE.checked_sub(two), None);
+        assert_eq!(Fixed::MAX.checked_add(Fixed::from_inner(1)), None);
+    }
+
+    #[test]
+    fn fixed_overflow_errors() {
+        assert_eq!(Fixed::from_integer(u128::MAX), None);
+        assert_eq!(
+            Fixed::MAX.checked_mul(Fixed::from_integer(2).unwrap()),
+            None
+        );
+        assert_eq!(Fixed::MAX.checked_mul(Fixed::ONE), Some(Fixed::MAX));
+        assert_eq!(
+            Fixed::MAX.checked_div(Fixed::from_rational(1, 2).unwrap()),
+            None
+        );
+        assert_eq!(Fixed::ONE.checked_div(Fixed::ZERO), None);
+        assert_eq!(Fixed::from_rational(1, 0), None);
+    }
+}
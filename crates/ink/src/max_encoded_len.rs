@@ -0,0 +1,60 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Asserts at compile time that the maximum encoded length of a type does not exceed
+/// the given number of bytes.
+///
+/// This helps to keep e.g. the value types of a [`Mapping`](crate::storage::Mapping)
+/// small. The type must implement
+/// [`ConstMaxEncodedLen`](crate::storage::traits::ConstMaxEncodedLen), which can be
+/// derived. Types with an unbounded encoding, such as `Vec<u8>`, do not implement it and
+/// are rejected as well.
+///
+/// # Example
+///
+/// ```
+/// #[derive(ink::storage::traits::ConstMaxEncodedLen)]
+/// struct Value {
+///     free: u128,
+///     reserved: Option<u32>,
+/// }
+///
+/// ink::assert_max_encoded_len!(Value, 21);
+/// ```
+#[macro_export]
+macro_rules! assert_max_encoded_len {
+    ( $ty:ty, $max:expr $(,)? ) => {
+        const _: () = ::core::assert!(
+            <$ty as $crate::storage::traits::ConstMaxEncodedLen>::MAX_ENCODED_LEN <= $max,
+            ::core::concat!(
+                "the maximum encoded length of `",
+                ::core::stringify!($ty),
+                "` exceeds the bound of ",
+                ::core::stringify!($max),
+                " bytes",
+            ),
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn assert_max_encoded_len_works() {
+        assert_max_encoded_len!(u8, 1);
+        assert_max_encoded_len!(u128, 16);
+        assert_max_encoded_len!((u32, Option<u64>), 32);
+        assert_max_encoded_len!([u8; 32], 32);
+    }
+}
@@ -46,3 +46,81 @@ pub trait ContractName {
     /// The name of the ink! smart contract.
     const NAME: &'static str;
 }
+
+/// Describes a dispatchable ink! message of an ink! smart contract.
+///
+/// # Note
+///
+/// Unlike the contract metadata this information is also available in `no_std`
+/// environments, e.g. for on-chain routers or introspection.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MessageInfo {
+    /// The selector of the ink! message.
+    pub selector: [u8; 4],
+    /// Yields `true` if the ink! message mutates the ink! storage.
+    pub mutates: bool,
+    /// Yields `true` if the ink! message is payable.
+    pub payable: bool,
+    /// The label of the ink! message.
+    pub label: &'static str,
+}
+
+/// Stores a table of all dispatchable ink! messages of the ink! smart contract.
+///
+/// # Note
+///
+/// The messages are listed in the order of their definition, messages of trait
+/// implementation blocks included.
+///
+/// # Usage
+///
+/// ```
+/// #[ink::contract]
+/// pub mod contract {
+///     #[ink(storage)]
+///     pub struct Contract {}
+///
+///     impl Contract {
+///         #[ink(constructor)]
+///         pub fn constructor() -> Self {
+///             Self {}
+///         }
+///
+///         #[ink(message)]
+///         pub fn message1(&self) {}
+///
+///         #[ink(message, payable, selector = 0xC0DECAFE)]
+///         pub fn message2(&mut self) {}
+///     }
+/// }
+///
+/// use contract::Contract;
+///
+/// # use ink::reflect::{ContractMessages, MessageInfo};
+/// assert_eq!(
+///     Contract::__ink_messages(),
+///     &[
+///         MessageInfo {
+///             selector: ink::selector_bytes!("message1"),
+///             mutates: false,
+///             payable: false,
+///             label: "message1",
+///         },
+///         MessageInfo {
+///             selector: [0xC0, 0xDE, 0xCA, 0xFE],
+///             mutates: true,
+///             payable: true,
+///             label: "message2",
+///         },
+///     ],
+/// );
+/// ```
+pub trait ContractMessages {
+    /// The table of all dispatchable ink! messages of the ink! smart contract.
+    const MESSAGES: &'static [MessageInfo];
+
+    /// Returns the table of all dispatchable ink! messages of the ink! smart contract.
+    fn __ink_messages() -> &'static [MessageInfo] {
+        Self::MESSAGES
+    }
+}
@@ -496,6 +496,8 @@ pub enum DispatchError {
     CouldNotReadInput,
     /// Invalidly paid an unpayable dispatchable.
     PaidUnpayableMessage,
+    /// Paid less than the minimum value of a dispatchable.
+    TransferredValueTooLow,
 }
 
 impl Display for DispatchError {
@@ -514,6 +516,7 @@ impl DispatchError {
             Self::InvalidParameters => "unable to decode input",
             Self::CouldNotReadInput => "could not read input",
             Self::PaidUnpayableMessage => "paid an unpayable message",
+            Self::TransferredValueTooLow => "paid less than the minimum value",
         }
     }
 }
@@ -28,7 +28,11 @@ mod dispatch;
 mod trait_def;
 
 pub use self::{
-    contract::ContractName,
+    contract::{
+        ContractMessages,
+        ContractName,
+        MessageInfo,
+    },
     dispatch::{
         ConstructorOutput,
         ConstructorOutputValue,
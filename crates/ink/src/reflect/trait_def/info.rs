@@ -146,4 +146,7 @@ pub trait TraitInfo {
     ///
     /// This is just for convenience.
     const NAME: &'static str;
+
+    /// The labels and selectors of all ink! messages of the ink! trait.
+    const MESSAGES: &'static [(&'static str, [u8; 4])];
 }
@@ -41,6 +41,91 @@ macro_rules! is_result_type {
     }};
 }
 
+pub struct IsRevertType<T> {
+    marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<E> IsRevertType<crate::Revert<E>> {
+    // We need to allow for dead code at this point because
+    // the Rust compiler thinks this function is unused even
+    // though it acts as the specialized case for detection.
+    #[allow(dead_code)]
+    pub const VALUE: bool = true;
+}
+
+pub trait IsRevertTypeFallback {
+    const VALUE: bool = false;
+}
+impl<T> IsRevertTypeFallback for IsRevertType<T> {}
+
+/// Returns `true` if the given type is a [`Revert`][`crate::Revert`] type.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! is_revert_type {
+    ( $T:ty $(,)? ) => {{
+        #[allow(unused_imports)]
+        use $crate::result_info::IsRevertTypeFallback as _;
+
+        $crate::result_info::IsRevertType::<$T>::VALUE
+    }};
+}
+
+#[cfg(feature = "std")]
+pub struct ResultErrorTypeSpec<T> {
+    marker: core::marker::PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "std")]
+impl<T, E> ResultErrorTypeSpec<::core::result::Result<T, E>>
+where
+    E: scale_info::TypeInfo + 'static,
+{
+    // We need to allow for dead code at this point because
+    // the Rust compiler thinks this function is unused even
+    // though it acts as the specialized case for detection.
+    #[allow(dead_code)]
+    pub fn value() -> Option<ink_metadata::TypeSpec> {
+        Some(ink_metadata::TypeSpec::of_type::<E>())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> ResultErrorTypeSpec<crate::Revert<E>>
+where
+    E: scale_info::TypeInfo + 'static,
+{
+    // We need to allow for dead code at this point because
+    // the Rust compiler thinks this function is unused even
+    // though it acts as the specialized case for detection.
+    #[allow(dead_code)]
+    pub fn value() -> Option<ink_metadata::TypeSpec> {
+        Some(ink_metadata::TypeSpec::of_type::<E>())
+    }
+}
+
+#[cfg(feature = "std")]
+pub trait ResultErrorTypeSpecFallback {
+    fn value() -> Option<ink_metadata::TypeSpec> {
+        None
+    }
+}
+#[cfg(feature = "std")]
+impl<T> ResultErrorTypeSpecFallback for ResultErrorTypeSpec<T> {}
+
+/// Returns the metadata type specification of `E` if the given type is a
+/// `Result<T, E>` or a `Revert<E>` type.
+#[cfg(feature = "std")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! result_error_type_spec {
+    ( $T:ty $(,)? ) => {{
+        #[allow(unused_imports)]
+        use $crate::result_info::ResultErrorTypeSpecFallback as _;
+
+        $crate::result_info::ResultErrorTypeSpec::<$T>::value()
+    }};
+}
+
 pub struct IsResultErr<'lt, T>(pub &'lt T);
 
 impl<T, E> IsResultErr<'_, ::core::result::Result<T, E>> {
@@ -99,6 +184,46 @@ mod tests {
         assert!(is_result_type!(MyResult));
     }
 
+    #[test]
+    fn is_revert_type_works() {
+        assert!(!is_revert_type!(bool));
+        assert!(!is_revert_type!(Result<(), ()>));
+        assert!(!is_revert_type!(Option<crate::Revert<()>>));
+
+        assert!(is_revert_type!(crate::Revert<()>));
+        assert!(is_revert_type!(crate::Revert<Result<(), u8>>));
+
+        // Check that type aliases work, too.
+        type MyRevert = crate::Revert<u32>;
+        assert!(is_revert_type!(MyRevert));
+    }
+
+    #[test]
+    fn result_error_type_spec_works() {
+        assert_eq!(result_error_type_spec!(bool), None);
+        assert_eq!(result_error_type_spec!(Option<i32>), None);
+
+        assert_eq!(
+            result_error_type_spec!(Result<(), u8>),
+            Some(ink_metadata::TypeSpec::of_type::<u8>())
+        );
+        assert_eq!(
+            result_error_type_spec!(Result<u8, Result<(), String>>),
+            Some(ink_metadata::TypeSpec::of_type::<Result<(), String>>())
+        );
+
+        // Check that type aliases work, too.
+        type MyResult = Result<(), u32>;
+        assert_eq!(
+            result_error_type_spec!(MyResult),
+            Some(ink_metadata::TypeSpec::of_type::<u32>())
+        );
+        assert_eq!(
+            result_error_type_spec!(crate::Revert<u8>),
+            Some(ink_metadata::TypeSpec::of_type::<u8>())
+        );
+    }
+
     #[test]
     fn is_result_err_works() {
         assert!(!is_result_err!(true));
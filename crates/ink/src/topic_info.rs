@@ -0,0 +1,103 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ink_env::{
+    event::{
+        SomeRemainingTopics,
+        TopicsBuilder,
+        TopicsBuilderBackend,
+    },
+    hash::Keccak256,
+    Environment,
+};
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+
+pub struct KeccakTopic<'lt, T>(pub Option<&'lt T>);
+
+macro_rules! impl_dynamic_keccak_topic {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl KeccakTopic<'_, $ty> {
+                #[inline]
+                // We need to allow for dead code at this point because
+                // the Rust compiler thinks this function is unused even
+                // though it acts as the specialized case for detection.
+                #[allow(dead_code)]
+                pub fn push<S, E, B>(
+                    self,
+                    builder: TopicsBuilder<S, E, B>,
+                ) -> TopicsBuilder<<S as SomeRemainingTopics>::Next, E, B>
+                where
+                    E: Environment,
+                    S: SomeRemainingTopics,
+                    B: TopicsBuilderBackend<E>,
+                {
+                    builder.push_hashed_topic::<Keccak256, _>(self.0)
+                }
+            }
+        )*
+    };
+}
+impl_dynamic_keccak_topic!(String, &String, Vec<u8>, &Vec<u8>);
+
+pub trait KeccakTopicFallback {
+    fn push<S, E, B>(
+        self,
+        builder: TopicsBuilder<S, E, B>,
+    ) -> TopicsBuilder<<S as SomeRemainingTopics>::Next, E, B>
+    where
+        E: Environment,
+        S: SomeRemainingTopics,
+        B: TopicsBuilderBackend<E>;
+}
+impl<T> KeccakTopicFallback for KeccakTopic<'_, T>
+where
+    T: scale::Encode,
+{
+    #[inline]
+    fn push<S, E, B>(
+        self,
+        builder: TopicsBuilder<S, E, B>,
+    ) -> TopicsBuilder<<S as SomeRemainingTopics>::Next, E, B>
+    where
+        E: Environment,
+        S: SomeRemainingTopics,
+        B: TopicsBuilderBackend<E>,
+    {
+        builder.push_topic(self.0)
+    }
+}
+
+/// Pushes the given expression as another topic of an event with
+/// `topic_hash = "keccak"`.
+///
+/// Like indexed dynamic values of EVM logs, the topic of a `String` or `Vec<u8>` is the
+/// `keccak256` of its bytes. Values of any other type are pushed like the topics of
+/// other events, i.e. their SCALE encoding if it fits into a topic.
+///
+/// # Note
+///
+/// This given expression may be an `Option` of such a value, see [`as_option!`].
+#[macro_export]
+#[doc(hidden)]
+macro_rules! push_keccak_topic {
+    ( $builder:expr, $e:expr $(,)? ) => {{
+        #[allow(unused_imports)]
+        use $crate::topic_info::KeccakTopicFallback as _;
+        $crate::topic_info::KeccakTopic($crate::as_option!($e)).push($builder)
+    }};
+}
@@ -0,0 +1,77 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: u32,
+    }
+
+    impl Contract {
+        #[ink(constructor, default_impl)]
+        pub fn new(value: u32) -> Self {
+            Self { value }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            self.value
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn generate_metadata() -> ink_metadata::InkProject {
+        extern "Rust" {
+            fn __ink_generate_metadata() -> ink_metadata::InkProject;
+        }
+
+        unsafe { __ink_generate_metadata() }
+    }
+
+    #[test]
+    fn default_impl_constructor_appears_in_metadata() {
+        let metadata = generate_metadata();
+
+        let constructors = metadata
+            .spec()
+            .constructors()
+            .iter()
+            .map(|constructor| {
+                (
+                    constructor.label().as_str(),
+                    constructor.selector().to_bytes().to_vec(),
+                    constructor.args().len(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            constructors,
+            vec![
+                ("new", ink::selector_bytes!("new").to_vec(), 1),
+                ("default", ink::selector_bytes!("default").to_vec(), 0),
+            ]
+        );
+
+        let default = &metadata.spec().constructors()[1];
+        assert_eq!(
+            default.docs(),
+            vec!["Calls [`Self::new`] with the default value of each argument."]
+        );
+    }
+}
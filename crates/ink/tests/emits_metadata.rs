@@ -0,0 +1,103 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[ink::contract]
+mod contract {
+    #[ink(event)]
+    pub struct Flipped {
+        value: bool,
+    }
+
+    #[ink(event)]
+    pub struct Reset {}
+
+    #[ink(storage)]
+    pub struct Contract {
+        value: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: false }
+        }
+
+        #[ink(message, emits = Flipped)]
+        pub fn flip(&mut self) {
+            self.value = !self.value;
+            self.env().emit_event(Flipped { value: self.value });
+        }
+
+        #[ink(message, emits = Flipped, emits = Reset)]
+        pub fn reset(&mut self) {
+            if self.value {
+                self.flip();
+            }
+            self.env().emit_event(Reset {});
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn generate_metadata() -> ink_metadata::InkProject {
+        extern "Rust" {
+            fn __ink_generate_metadata() -> ink_metadata::InkProject;
+        }
+
+        unsafe { __ink_generate_metadata() }
+    }
+
+    #[test]
+    fn message_lists_emitted_event() {
+        let metadata = generate_metadata();
+        let messages = metadata.spec().messages();
+
+        let flip = messages.iter().find(|m| m.label() == "flip").unwrap();
+        assert_eq!(
+            flip.emits(),
+            ["emits_metadata::contract::Flipped".to_string()]
+        );
+
+        let flipped = metadata
+            .spec()
+            .events()
+            .iter()
+            .find(|e| e.label() == "Flipped")
+            .unwrap();
+        assert_eq!(
+            flip.emits()[0],
+            format!("{}::{}", flipped.module_path(), flipped.label())
+        );
+
+        let reset = messages.iter().find(|m| m.label() == "reset").unwrap();
+        assert_eq!(
+            reset.emits(),
+            [
+                "emits_metadata::contract::Flipped".to_string(),
+                "emits_metadata::contract::Reset".to_string()
+            ]
+        );
+
+        let get = messages.iter().find(|m| m.label() == "get").unwrap();
+        assert!(get.emits().is_empty());
+    }
+}
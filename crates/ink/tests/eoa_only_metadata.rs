@@ -0,0 +1,65 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        admin: AccountId,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                admin: Self::env().caller(),
+            }
+        }
+
+        #[ink(message, eoa_only)]
+        pub fn set_admin(&mut self, admin: AccountId) {
+            self.admin = admin;
+        }
+
+        #[ink(message)]
+        pub fn admin(&self) -> AccountId {
+            self.admin
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn generate_metadata() -> ink_metadata::InkProject {
+        extern "Rust" {
+            fn __ink_generate_metadata() -> ink_metadata::InkProject;
+        }
+
+        unsafe { __ink_generate_metadata() }
+    }
+
+    #[test]
+    fn message_is_flagged_as_eoa_only() {
+        let metadata = generate_metadata();
+        let messages = metadata.spec().messages();
+
+        let set_admin = messages.iter().find(|m| m.label() == "set_admin").unwrap();
+        assert!(set_admin.eoa_only());
+
+        let admin = messages.iter().find(|m| m.label() == "admin").unwrap();
+        assert!(!admin.eoa_only());
+    }
+}
@@ -23,6 +23,11 @@ pub struct EventExternal {
     f2: u32,
 }
 
+#[ink::event(schema_version = 2)]
+pub struct EventVersioned {
+    f5: u32,
+}
+
 #[ink::contract]
 mod contract {
     #[ink(storage)]
@@ -66,7 +71,7 @@ mod tests {
     fn collects_all_events() {
         let metadata = generate_metadata();
 
-        assert_eq!(metadata.spec().events().len(), 2);
+        assert_eq!(metadata.spec().events().len(), 3);
 
         let event_external = metadata
             .spec()
@@ -104,4 +109,44 @@ mod tests {
         assert_eq!(arg_f4.docs(), &["f4 docs"]);
         assert!(!arg_f4.indexed());
     }
+
+    #[test]
+    fn records_schema_version() {
+        let metadata = generate_metadata();
+        let schema_version = |event: &str| {
+            metadata
+                .spec()
+                .events()
+                .iter()
+                .find(|e| e.label() == event)
+                .unwrap_or_else(|| panic!("{event} should be present"))
+                .schema_version()
+        };
+
+        assert_eq!(schema_version("EventVersioned"), 2);
+        assert_eq!(
+            schema_version("EventExternal"),
+            ink_metadata::DEFAULT_EVENT_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn records_which_fields_are_indexed() {
+        let metadata = generate_metadata();
+        let indexed = |event: &str| {
+            metadata
+                .spec()
+                .events()
+                .iter()
+                .find(|e| e.label() == event)
+                .unwrap_or_else(|| panic!("{event} should be present"))
+                .args()
+                .iter()
+                .map(|a| (a.label().as_str(), a.indexed()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(indexed("EventExternal"), [("f1", false), ("f2", true)]);
+        assert_eq!(indexed("EventInline"), [("f3", true), ("f4", false)]);
+    }
 }
@@ -0,0 +1,101 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[ink::trait_definition]
+pub trait Erc20 {
+    /// Returns the total token supply.
+    #[ink(message)]
+    fn total_supply(&self) -> u128;
+
+    /// Transfers `value` amount of tokens from the caller's account to account `to`.
+    #[ink(message)]
+    fn transfer(&mut self, to: ink::primitives::AccountId, value: u128) -> bool;
+}
+
+#[ink::contract]
+mod contract {
+    use super::{
+        Erc20,
+        Erc20 as Token,
+    };
+
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn total_supply_of(&self, token: AccountId) -> u128 {
+            let token: ink::contract_ref!(Erc20) = token.into();
+            token.total_supply()
+        }
+
+        #[ink(message)]
+        pub fn transfer_to(&mut self, token: AccountId, to: AccountId) -> bool {
+            let mut token: ink::contract_ref!(Token) = token.into();
+            token.transfer(to, 1)
+        }
+
+        #[ink(message)]
+        pub fn total_supply_via_path(&self, token: AccountId) -> u128 {
+            let token: ink::contract_ref!(super::Erc20) = token.into();
+            token.total_supply()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn generate_metadata() -> ink_metadata::InkProject {
+        extern "Rust" {
+            fn __ink_generate_metadata() -> ink_metadata::InkProject;
+        }
+
+        unsafe { __ink_generate_metadata() }
+    }
+
+    /// The contract refers to `Erc20` by its name, a `use` alias and a path, which all
+    /// resolve to the same ink! trait definition.
+    #[test]
+    fn collects_imported_traits() {
+        let metadata = generate_metadata();
+
+        assert_eq!(metadata.imports().len(), 1);
+
+        let erc20 = &metadata.imports()[0];
+        assert_eq!(erc20.path(), "imports_metadata::Erc20");
+
+        let messages = erc20
+            .messages()
+            .iter()
+            .map(|message| (message.label(), message.selector().to_bytes().to_vec()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    "total_supply",
+                    ink::selector_bytes!("Erc20::total_supply").to_vec()
+                ),
+                ("transfer", ink::selector_bytes!("Erc20::transfer").to_vec()),
+            ]
+        );
+    }
+}
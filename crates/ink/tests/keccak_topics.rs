@@ -0,0 +1,99 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ink::{
+    env::{
+        hash::Keccak256,
+        DefaultEnvironment,
+    },
+    primitives::AccountId,
+};
+
+#[ink::event(topic_hash = "keccak")]
+pub struct Transfer {
+    #[ink(topic)]
+    name: String,
+    #[ink(topic)]
+    amount: u128,
+    #[ink(topic)]
+    to: AccountId,
+}
+
+#[ink::event(anonymous, topic_hash = "keccak")]
+pub struct Anonymous {
+    #[ink(topic)]
+    data: Vec<u8>,
+    #[ink(topic)]
+    flag: bool,
+    #[ink(topic)]
+    hash: [u8; 32],
+    #[ink(topic)]
+    memo: Option<String>,
+}
+
+fn keccak256(bytes: &[u8]) -> Vec<u8> {
+    let mut output = [0u8; 32];
+    ink::env::hash_bytes::<Keccak256>(bytes, &mut output);
+    output.to_vec()
+}
+
+/// The topic of a static value is its SCALE encoding padded with zeros.
+fn padded(encoded: &[u8]) -> Vec<u8> {
+    let mut topic = vec![0u8; 32];
+    topic[..encoded.len()].copy_from_slice(encoded);
+    topic
+}
+
+#[test]
+fn only_dynamic_topics_are_keccak_hashed() {
+    ink::env::test::run_test::<DefaultEnvironment, _>(|_| {
+        ink::env::emit_event::<DefaultEnvironment, _>(Transfer {
+            name: String::from("ink"),
+            amount: 42,
+            to: AccountId::from([0x07; 32]),
+        });
+        ink::env::emit_event::<DefaultEnvironment, _>(Anonymous {
+            data: vec![1, 2, 3],
+            flag: true,
+            hash: [0x09; 32],
+            memo: Some(String::from("memo")),
+        });
+
+        let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[0].topics,
+            vec![
+                <Transfer as ink::env::Event>::SIGNATURE_TOPIC
+                    .unwrap()
+                    .to_vec(),
+                keccak256(b"ink"),
+                padded(&42u128.to_le_bytes()),
+                vec![0x07; 32],
+            ]
+        );
+        assert_eq!(
+            events[1].topics,
+            vec![
+                keccak256(&[1, 2, 3]),
+                padded(&[1]),
+                vec![0x09; 32],
+                keccak256(b"memo"),
+            ]
+        );
+
+        Ok(())
+    })
+    .unwrap()
+}
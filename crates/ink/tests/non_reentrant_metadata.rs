@@ -0,0 +1,63 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: u32,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(message, non_reentrant)]
+        pub fn increment(&mut self) {
+            self.value += 1;
+        }
+
+        #[ink(message)]
+        pub fn reset(&mut self) {
+            self.value = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn generate_metadata() -> ink_metadata::InkProject {
+        extern "Rust" {
+            fn __ink_generate_metadata() -> ink_metadata::InkProject;
+        }
+
+        unsafe { __ink_generate_metadata() }
+    }
+
+    #[test]
+    fn message_is_flagged_as_reentrancy_guarded() {
+        let metadata = generate_metadata();
+        let messages = metadata.spec().messages();
+
+        let increment = messages.iter().find(|m| m.label() == "increment").unwrap();
+        assert!(increment.reentrancy_guarded());
+
+        let reset = messages.iter().find(|m| m.label() == "reset").unwrap();
+        assert!(!reset.reentrancy_guarded());
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[ink::contract]
+mod contract {
+    #[derive(Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum Error {
+        Failed,
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    #[derive(Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum NotAResult {
+        Ok(u32),
+        Err(u8),
+    }
+
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+
+        #[ink(constructor)]
+        pub fn try_new() -> core::result::Result<Self, u8> {
+            Ok(Self {})
+        }
+
+        #[ink(message)]
+        pub fn try_get(&self) -> core::result::Result<u32, Error> {
+            Err(Error::Failed)
+        }
+
+        #[ink(message)]
+        pub fn try_get_aliased(&self) -> Result<u32> {
+            Err(Error::Failed)
+        }
+
+        #[ink(message)]
+        pub fn read(&self) -> u32 {
+            0
+        }
+
+        #[ink(message)]
+        pub fn get_enum(&self) -> NotAResult {
+            NotAResult::Ok(0)
+        }
+
+        #[ink(message)]
+        pub fn set(&mut self) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use scale_info::{
+        form::PortableForm,
+        Type,
+        TypeDef,
+        TypeDefPrimitive,
+    };
+
+    fn generate_metadata() -> ink_metadata::InkProject {
+        extern "Rust" {
+            fn __ink_generate_metadata() -> ink_metadata::InkProject;
+        }
+
+        unsafe { __ink_generate_metadata() }
+    }
+
+    /// Resolves the `result_error` type of the given return type, if any.
+    fn resolve_result_error<'a>(
+        metadata: &'a ink_metadata::InkProject,
+        return_type: &ink_metadata::ReturnTypeSpec<PortableForm>,
+    ) -> Option<&'a Type<PortableForm>> {
+        return_type.result_error().map(|result_error| {
+            metadata
+                .registry()
+                .resolve(result_error.ty().id)
+                .expect("result error type must be registered")
+        })
+    }
+
+    #[test]
+    fn result_error_is_only_recorded_for_results() {
+        let metadata = generate_metadata();
+
+        let messages = metadata
+            .spec()
+            .messages()
+            .iter()
+            .map(|message| {
+                (
+                    message.label().as_str(),
+                    resolve_result_error(&metadata, message.return_type())
+                        .map(|ty| ty.path.to_string()),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    "try_get",
+                    Some("result_error_metadata::contract::Error".into())
+                ),
+                (
+                    "try_get_aliased",
+                    Some("result_error_metadata::contract::Error".into())
+                ),
+                ("read", None),
+                ("get_enum", None),
+                ("set", None),
+            ]
+        );
+
+        let constructors = metadata
+            .spec()
+            .constructors()
+            .iter()
+            .map(|constructor| {
+                (
+                    constructor.label().as_str(),
+                    resolve_result_error(&metadata, constructor.return_type())
+                        .map(|ty| ty.type_def.clone()),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            constructors,
+            vec![
+                ("new", None),
+                ("try_new", Some(TypeDef::Primitive(TypeDefPrimitive::U8)))
+            ]
+        );
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: bool,
+        owner: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct Flipped {
+        #[ink(topic)]
+        by: AccountId,
+        value: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                value: false,
+                owner: None,
+            }
+        }
+
+        /// Flips the value.
+        #[ink(message)]
+        pub fn flip(&mut self) {
+            self.value = !self.value;
+            self.env().emit_event(Flipped {
+                by: self.env().caller(),
+                value: self.value,
+            });
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+
+        #[ink(message)]
+        pub fn set_owner(&mut self, owner: Option<AccountId>) {
+            self.owner = owner;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn generate_metadata() -> ink_metadata::InkProject {
+        extern "Rust" {
+            fn __ink_generate_metadata() -> ink_metadata::InkProject;
+        }
+
+        unsafe { __ink_generate_metadata() }
+    }
+
+    #[test]
+    fn typescript_interface_declares_messages() {
+        let typescript = generate_metadata().typescript_interface("Contract");
+
+        assert!(typescript.contains("export interface Contract {\n"));
+        assert!(
+            typescript.contains("  /**\n   * Flips the value.\n   */\n  flip(): void;\n")
+        );
+        assert!(typescript.contains("  get(): boolean;\n"));
+        assert!(typescript.contains("  set_owner(owner: Uint8Array | null): void;\n"));
+    }
+
+    #[test]
+    fn typescript_interface_declares_events() {
+        let typescript = generate_metadata().typescript_interface("Contract");
+
+        assert!(typescript.contains(
+            "export interface Flipped {\n  by: Uint8Array;\n  value: boolean;\n}\n"
+        ));
+        assert!(typescript.contains("export type ContractEvent = Flipped;\n"));
+    }
+}
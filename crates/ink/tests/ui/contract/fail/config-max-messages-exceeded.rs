@@ -0,0 +1,32 @@
+#[ink::trait_definition]
+pub trait Getter {
+    #[ink(message)]
+    fn get(&self) -> bool;
+}
+
+#[ink::contract(max_messages = 1)]
+mod contract {
+    use super::Getter;
+
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn message(&self) {}
+    }
+
+    impl Getter for Contract {
+        #[ink(message)]
+        fn get(&self) -> bool {
+            true
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, selector = 0xCAFEBABA, selector_name = "message")]
+        pub fn message(&self) {}
+    }
+}
+
+fn main() {}
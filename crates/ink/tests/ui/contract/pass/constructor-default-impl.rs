@@ -0,0 +1,31 @@
+use contract::Contract;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: u32,
+        flag: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor, default_impl)]
+        pub fn new(value: u32, flag: bool) -> Self {
+            Self { value, flag }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> (u32, bool) {
+            (self.value, self.flag)
+        }
+    }
+}
+
+fn main() {
+    const ID: u32 = ::ink::selector_id!("default");
+    assert_eq!(
+        <Contract as ::ink::reflect::DispatchableConstructorInfo<ID>>::SELECTOR,
+        ::ink::selector_bytes!("default"),
+    );
+    assert_eq!(Contract::default().get(), (0, false));
+}
@@ -0,0 +1,52 @@
+#[ink::event]
+pub struct ExternalEvent {
+    value: u8,
+}
+
+#[ink::event(anonymous)]
+pub struct ExternalAnonymousEvent {}
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    #[ink(event)]
+    pub struct Event0 {}
+
+    #[ink(
+        event,
+        signature_topic = "1111111111111111111111111111111111111111111111111111111111111111"
+    )]
+    pub struct Event1 {
+        #[ink(topic)]
+        arg_1: i8,
+    }
+
+    #[ink(event, anonymous)]
+    pub struct Event2 {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, emits = Event0, emits = super::ExternalEvent)]
+        pub fn message(&self) {}
+
+        #[ink(message, emits = super::ExternalAnonymousEvent)]
+        pub fn anonymous(&self) {}
+    }
+}
+
+use ink::env::Event;
+
+fn main() {
+    assert_eq!(contract::__INK_EVENT_SIGNATURES.len(), 3);
+    assert!(contract::__INK_EVENT_SIGNATURES.contains(&[0x11; 32]));
+    assert!(contract::__INK_EVENT_SIGNATURES
+        .contains(&<contract::Event0 as Event>::SIGNATURE_TOPIC.unwrap()));
+    assert!(contract::__INK_EVENT_SIGNATURES
+        .contains(&<ExternalEvent as Event>::SIGNATURE_TOPIC.unwrap()));
+}
@@ -0,0 +1,23 @@
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, eoa_only)]
+        pub fn message_1(&self) {}
+
+        #[ink(message, payable)]
+        #[ink(eoa_only)]
+        pub fn message_2(&mut self) -> Result<(), ()> {
+            Ok(())
+        }
+    }
+}
+
+fn main() {}
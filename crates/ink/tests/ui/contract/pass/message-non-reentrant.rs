@@ -0,0 +1,23 @@
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, non_reentrant)]
+        pub fn message_1(&mut self) {}
+
+        #[ink(message)]
+        #[ink(non_reentrant)]
+        pub fn message_2(&mut self) -> Result<(), ()> {
+            Ok(())
+        }
+    }
+}
+
+fn main() {}
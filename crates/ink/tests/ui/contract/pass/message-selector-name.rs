@@ -0,0 +1,30 @@
+use contract::Contract;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor, selector_name = "constructor")]
+        pub fn constructor_v2() -> Self {
+            Self {}
+        }
+
+        #[ink(message, selector_name = "message")]
+        pub fn message_v2(&self) {}
+    }
+}
+
+fn main() {
+    const ID: u32 = ::ink::selector_id!("message");
+    assert_eq!(
+        <Contract as ::ink::reflect::DispatchableMessageInfo<ID>>::SELECTOR,
+        ::ink::selector_bytes!("message"),
+    );
+    const CONSTRUCTOR_ID: u32 = ::ink::selector_id!("constructor");
+    assert_eq!(
+        <Contract as ::ink::reflect::DispatchableConstructorInfo<CONSTRUCTOR_ID>>::SELECTOR,
+        ::ink::selector_bytes!("constructor"),
+    );
+}
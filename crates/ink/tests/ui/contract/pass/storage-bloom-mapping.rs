@@ -0,0 +1,32 @@
+#[ink::contract]
+mod contract {
+    use ink::storage::BloomMapping;
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Contract {
+        balances: BloomMapping<AccountId, Balance>,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> Balance {
+            if !self.balances.might_contain(owner) {
+                return 0
+            }
+            self.balances.get(owner).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn set_balance(&mut self, owner: AccountId, value: Balance) {
+            self.balances.insert(owner, &value);
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+#[ink::contract]
+mod contract {
+    use ink::{
+        prelude::vec::Vec,
+        storage::Cipher,
+    };
+
+    pub struct XorCipher;
+
+    impl Cipher for XorCipher {
+        fn encrypt(mut plaintext: Vec<u8>) -> Vec<u8> {
+            plaintext.iter_mut().for_each(|byte| *byte ^= 0x5A);
+            plaintext
+        }
+
+        fn decrypt(ciphertext: Vec<u8>) -> Result<Vec<u8>, ink::scale::Error> {
+            Ok(Self::encrypt(ciphertext))
+        }
+    }
+
+    #[ink(storage)]
+    pub struct Contract {
+        field_1: i8,
+        #[ink(encrypted = XorCipher)]
+        field_2: (u128, bool),
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {
+                field_1: 0,
+                field_2: (1, true).into(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn message(&mut self) -> u128 {
+            self.field_2.0 += 1;
+            self.field_2.0
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[ink::event(derive = "Clone,,Debug")]
+pub struct Event {
+    #[ink(topic)]
+    pub topic: [u8; 32],
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[ink::event(schema_version = 65536)]
+pub struct Event {
+    #[ink(topic)]
+    pub topic: [u8; 32],
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[ink::event]
+pub struct Event {
+    #[ink(topic(merge))]
+    pub topic: (u32, u64),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[ink::event(topic_hash = "sha3")]
+pub struct Event {
+    #[ink(topic)]
+    pub topic: [u8; 32],
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#[ink::event(derive = "Clone, Debug, PartialEq")]
+pub struct Event {
+    #[ink(topic)]
+    pub topic: [u8; 32],
+    pub field_1: u32,
+}
+
+#[ink::event]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserDerivedEvent {
+    #[ink(topic)]
+    pub topic: [u8; 32],
+    pub field_1: u32,
+}
+
+fn main() {
+    let event = Event {
+        topic: [0x42; 32],
+        field_1: 1,
+    };
+    assert_eq!(event.clone(), event);
+
+    let event = UserDerivedEvent {
+        topic: [0x42; 32],
+        field_1: 1,
+    };
+    assert_eq!(event.clone(), event);
+}
@@ -0,0 +1,25 @@
+mod versioned {
+    #[ink::event(schema_version = 2)]
+    pub struct Event {
+        #[ink(topic)]
+        pub topic: [u8; 32],
+        pub field_1: u32,
+    }
+}
+
+mod unversioned {
+    #[ink::event]
+    pub struct Event {
+        #[ink(topic)]
+        pub topic: [u8; 32],
+        pub field_1: u32,
+    }
+}
+
+fn main() {
+    // The schema version does not affect the signature topic.
+    assert_eq!(
+        <versioned::Event as ink::env::Event>::SIGNATURE_TOPIC,
+        <unversioned::Event as ink::env::Event>::SIGNATURE_TOPIC,
+    );
+}
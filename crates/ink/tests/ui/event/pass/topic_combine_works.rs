@@ -0,0 +1,14 @@
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct Pair {
+    pub first: u32,
+    pub second: u64,
+}
+
+#[ink::event]
+pub struct Event {
+    #[ink(topic(combine))]
+    pub pair: Pair,
+    pub field_1: u32,
+}
+
+fn main() {}
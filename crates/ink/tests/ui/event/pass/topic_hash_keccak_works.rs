@@ -0,0 +1,24 @@
+#[ink::event(topic_hash = "keccak")]
+pub struct Event {
+    #[ink(topic)]
+    pub topic: ink::prelude::string::String,
+    #[ink(topic)]
+    pub amount: u128,
+    #[ink(topic)]
+    pub flag: bool,
+    pub field_1: u32,
+}
+
+#[ink::event(anonymous, topic_hash = "keccak")]
+pub struct AnonymousEvent {
+    #[ink(topic)]
+    pub data: ink::prelude::vec::Vec<u8>,
+    #[ink(topic)]
+    pub memo: Option<ink::prelude::string::String>,
+    #[ink(topic)]
+    pub value: u32,
+    #[ink(topic)]
+    pub account: ink::primitives::AccountId,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[ink::storage_item(env = ink::env::DefaultEnvironment)]
+struct Contract<KEY: ink::storage::traits::StorageKey> {
+    a: u16,
+}
+
+fn main() {}
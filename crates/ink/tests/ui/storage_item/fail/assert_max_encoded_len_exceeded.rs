@@ -0,0 +1,9 @@
+#[derive(ink::storage::traits::ConstMaxEncodedLen)]
+struct Value {
+    free: u128,
+    reserved: u128,
+}
+
+ink::assert_max_encoded_len!(Value, 16);
+
+fn main() {}
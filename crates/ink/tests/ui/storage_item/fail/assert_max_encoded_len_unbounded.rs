@@ -0,0 +1,3 @@
+fn main() {
+    ink::assert_max_encoded_len!(Vec<u8>, 32);
+}
@@ -0,0 +1,24 @@
+#[derive(ink::scale::Encode, ink::storage::traits::ConstMaxEncodedLen)]
+#[codec(crate = ink::scale)]
+struct Value {
+    free: u128,
+    reserved: Option<u64>,
+    #[codec(compact)]
+    nonce: u32,
+    #[codec(skip)]
+    _cache: (),
+}
+
+#[derive(ink::scale::Encode, ink::storage::traits::ConstMaxEncodedLen)]
+#[codec(crate = ink::scale)]
+enum Status {
+    Active(Value),
+    Frozen { until: u32 },
+}
+
+ink::assert_max_encoded_len!(Value, 30);
+ink::assert_max_encoded_len!(Status, 31);
+
+fn main() {
+    ink::assert_max_encoded_len!(ink::primitives::AccountId, 32);
+}
@@ -0,0 +1,14 @@
+// `cfg(any())` is never enabled, hence neither the field nor its type exist.
+#[cfg(any())]
+struct Disabled;
+
+#[ink::storage_item]
+struct Item {
+    a: u32,
+    #[cfg(any())]
+    b: Disabled,
+}
+
+fn main() {
+    let _ = Item { a: 0 };
+}
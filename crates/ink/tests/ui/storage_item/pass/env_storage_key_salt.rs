@@ -0,0 +1,63 @@
+use ink::{
+    env::{
+        DefaultEnvironment,
+        Environment,
+        StorageKeySalt,
+    },
+    primitives::{
+        Key,
+        KeyComposer,
+    },
+    storage::{
+        traits::StorageKey,
+        Lazy,
+    },
+};
+
+#[derive(Clone)]
+pub enum PalletEnvironment {}
+
+impl Environment for PalletEnvironment {
+    const MAX_EVENT_TOPICS: usize = <DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+    const STORAGE_KEY_SALT: Key = 0xC0FFEE;
+
+    type AccountId = <DefaultEnvironment as Environment>::AccountId;
+    type Balance = <DefaultEnvironment as Environment>::Balance;
+    type Hash = <DefaultEnvironment as Environment>::Hash;
+    type Timestamp = <DefaultEnvironment as Environment>::Timestamp;
+    type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
+    type ChainExtension = <DefaultEnvironment as Environment>::ChainExtension;
+}
+
+#[ink::storage_item]
+#[derive(Default)]
+struct Data<KEY: StorageKey = ()> {
+    value: Lazy<u32>,
+}
+
+#[ink::storage_item(env = PalletEnvironment)]
+#[derive(Default)]
+struct PalletData {
+    value: Lazy<u32>,
+}
+
+fn key_of<T: StorageKey>(_: &T) -> Key {
+    T::KEY
+}
+
+fn main() {
+    let unsalted = Data::<()>::default();
+    let default = Data::<StorageKeySalt<DefaultEnvironment>>::default();
+    let pallet = Data::<StorageKeySalt<PalletEnvironment>>::default();
+
+    // The default environment leaves the storage keys unchanged.
+    assert_eq!(key_of(&default.value), key_of(&unsalted.value));
+    assert_ne!(key_of(&pallet.value), key_of(&default.value));
+
+    let value_key = KeyComposer::compute_key("PalletData", "", "value").unwrap();
+    assert_eq!(<PalletData as StorageKey>::KEY, 0xC0FFEE);
+    assert_eq!(
+        key_of(&PalletData::default().value),
+        KeyComposer::concat(value_key, 0xC0FFEE)
+    );
+}
@@ -0,0 +1,30 @@
+use ink::{
+    primitives::{
+        Key,
+        KeyComposer,
+    },
+    storage::{
+        traits::StorageKey,
+        Lazy,
+    },
+};
+
+#[ink::storage_item(key = 0xDEADBEEF)]
+#[derive(Default)]
+struct Migrated {
+    value: Lazy<u32>,
+}
+
+fn key_of<T: StorageKey>(_: &T) -> Key {
+    T::KEY
+}
+
+fn main() {
+    assert_eq!(<Migrated as StorageKey>::KEY, 0xDEADBEEF);
+
+    let value_key = KeyComposer::compute_key("Migrated", "", "value").unwrap();
+    assert_eq!(
+        key_of(&Migrated::default().value),
+        KeyComposer::concat(value_key, 0xDEADBEEF)
+    );
+}
@@ -0,0 +1,38 @@
+use ink::{
+    metadata::layout::Layout,
+    primitives::AccountId,
+    scale_info::{
+        meta_type,
+        IntoPortable,
+        Registry,
+    },
+    storage::{
+        traits::StorageLayout,
+        Mapping,
+    },
+};
+
+#[ink::storage_item]
+struct Owners {
+    owners: Mapping<u32, AccountId>,
+}
+
+fn main() {
+    let mut registry = Registry::new();
+    let layout = <Owners as StorageLayout>::layout(&0).into_portable(&mut registry);
+
+    let Layout::Struct(layout) = layout else {
+        panic!("expected a struct layout")
+    };
+    let Layout::Root(owners) = layout.fields()[0].layout() else {
+        panic!("expected a root layout")
+    };
+    assert_eq!(
+        owners.key_ty(),
+        Some(&registry.register_type(&meta_type::<u32>()))
+    );
+    assert_eq!(
+        owners.value_ty(),
+        Some(&registry.register_type(&meta_type::<AccountId>()))
+    );
+}
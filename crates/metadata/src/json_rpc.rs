@@ -0,0 +1,147 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON-RPC style descriptors of the messages of a contract.
+//!
+//! The descriptors follow the [OpenRPC](https://spec.open-rpc.org) method object, so
+//! that SDK generators can consume them without knowing the ink! metadata format. The
+//! `schema` of parameters and results is the [`TypeSpec`] of the ink! metadata, i.e.
+//! its `type` refers to the type registry of the contract metadata.
+
+use crate::{
+    ContractSpec,
+    MessageParamSpec,
+    MessageSpec,
+    Selector,
+    TypeSpec,
+};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use scale_info::form::Form;
+use serde::Serialize;
+
+/// The name of the result of a [`JsonRpcMethod`].
+const RESULT_NAME: &str = "return_value";
+
+/// Describes a contract message as an OpenRPC method object.
+///
+/// The message selector, mutability and payability are not part of OpenRPC, they are
+/// added as `x-` prefixed specification extensions.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(bound(serialize = "F::Type: Serialize, F::String: Serialize"))]
+pub struct JsonRpcMethod<'a, F: Form> {
+    /// The label of the message.
+    name: &'a F::String,
+    /// The parameters of the message.
+    params: Vec<JsonRpcContentDescriptor<'a, F>>,
+    /// The return type of the message.
+    result: JsonRpcContentDescriptor<'a, F>,
+    /// The selector of the message.
+    #[serde(rename = "x-selector")]
+    selector: &'a Selector,
+    /// If the message is allowed to mutate the contract state.
+    #[serde(rename = "x-mutates")]
+    mutates: bool,
+    /// If the message accepts any `value` from the caller.
+    #[serde(rename = "x-payable")]
+    payable: bool,
+}
+
+impl<'a, F> JsonRpcMethod<'a, F>
+where
+    F: Form,
+    TypeSpec<F>: Default,
+{
+    /// Creates the descriptor of the given `message`.
+    pub fn new(message: &'a MessageSpec<F>) -> Self {
+        Self {
+            name: message.label(),
+            params: message
+                .args()
+                .iter()
+                .map(JsonRpcContentDescriptor::from_param)
+                .collect(),
+            result: JsonRpcContentDescriptor {
+                name: RESULT_NAME,
+                schema: message.return_type().ret_type(),
+            },
+            selector: message.selector(),
+            mutates: message.mutates(),
+            payable: message.payable(),
+        }
+    }
+
+    /// Returns the label of the message.
+    pub fn name(&self) -> &F::String {
+        self.name
+    }
+
+    /// Returns the parameters of the message.
+    pub fn params(&self) -> &[JsonRpcContentDescriptor<'a, F>] {
+        &self.params
+    }
+
+    /// Returns the return type of the message.
+    pub fn result(&self) -> &JsonRpcContentDescriptor<'a, F> {
+        &self.result
+    }
+}
+
+/// Describes a parameter or the result of a [`JsonRpcMethod`] as an OpenRPC content
+/// descriptor.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(bound(serialize = "F::Type: Serialize, F::String: Serialize"))]
+pub struct JsonRpcContentDescriptor<'a, F: Form> {
+    /// The name of the parameter or result.
+    name: &'a str,
+    /// The type of the parameter or result.
+    schema: &'a TypeSpec<F>,
+}
+
+impl<'a, F> JsonRpcContentDescriptor<'a, F>
+where
+    F: Form,
+    TypeSpec<F>: Default,
+{
+    fn from_param(param: &'a MessageParamSpec<F>) -> Self {
+        Self {
+            name: param.label().as_ref(),
+            schema: param.ty(),
+        }
+    }
+
+    /// Returns the name of the parameter or result.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Returns the type of the parameter or result.
+    pub fn schema(&self) -> &TypeSpec<F> {
+        self.schema
+    }
+}
+
+impl<F> ContractSpec<F>
+where
+    F: Form,
+    TypeSpec<F>: Default,
+{
+    /// Returns a JSON-RPC style descriptor of each message of the contract, in the
+    /// order of [`ContractSpec::messages`].
+    ///
+    /// See [`JsonRpcMethod`] for the format of the descriptors.
+    pub fn json_rpc_methods(&self) -> Vec<JsonRpcMethod<'_, F>> {
+        self.messages().iter().map(JsonRpcMethod::new).collect()
+    }
+}
@@ -0,0 +1,490 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::layout::{
+    FieldLayout,
+    Layout,
+    StructLayout,
+};
+use core::fmt::Display;
+use ink_prelude::collections::BTreeSet;
+use scale_info::{
+    form::PortableForm,
+    Field,
+    PortableRegistry,
+    TypeDef,
+};
+
+/// A reason why a storage layout can't read the storage written by another one.
+///
+/// Every variant holds the path of the offending layout node, e.g.
+/// `Contract.balances:`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutMismatch {
+    /// The kind of the layout changed, e.g. a plain field became a struct.
+    KindChanged(String),
+    /// The storage key of the layout changed.
+    KeyChanged(String),
+    /// The type of the encoded cell changed.
+    TypeChanged(String),
+    /// The hashing strategy of a hash layout changed.
+    StrategyChanged(String),
+    /// The length of an array layout changed.
+    LenChanged(String),
+    /// Fields which are stored under the key of the enclosing struct were added or
+    /// removed.
+    FieldsChanged(String),
+    /// A variant of an enum was removed.
+    VariantRemoved(String),
+}
+
+impl Display for LayoutMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::KindChanged(path) => write!(f, "layout kind changed at `{path}`"),
+            Self::KeyChanged(path) => write!(f, "storage key changed at `{path}`"),
+            Self::TypeChanged(path) => write!(f, "type changed at `{path}`"),
+            Self::StrategyChanged(path) => {
+                write!(f, "hashing strategy changed at `{path}`")
+            }
+            Self::LenChanged(path) => write!(f, "array length changed at `{path}`"),
+            Self::FieldsChanged(path) => {
+                write!(f, "fields stored under the same key changed at `{path}`")
+            }
+            Self::VariantRemoved(path) => write!(f, "enum variant removed at `{path}`"),
+        }
+    }
+}
+
+/// Checks that the `new` storage layout can read the storage written with the `old`
+/// one, e.g. before upgrading a contract via `set_code_hash`.
+///
+/// The layouts are compatible if every cell of the `old` layout is decoded as the same
+/// type from the same storage key by the `new` layout. In particular, the `new`
+/// layout may
+/// - add lazy fields with a new storage key,
+/// - remove lazy fields,
+/// - add enum variants,
+/// - rename structs, enums and fields.
+///
+/// Each layout is resolved against its own type registry, e.g. `project.layout()` and
+/// `project.registry()` of an [`InkProject`][`crate::InkProject`]. Types are compared
+/// by their path and shape, hence the registries may assign different ids to them.
+pub fn storage_layout_compatible(
+    old: &Layout<PortableForm>,
+    old_registry: &PortableRegistry,
+    new: &Layout<PortableForm>,
+    new_registry: &PortableRegistry,
+) -> Result<(), LayoutMismatch> {
+    Checker {
+        old_registry,
+        new_registry,
+        assumed: BTreeSet::new(),
+    }
+    .check_layout(old, new, &mut Vec::new())
+}
+
+/// Compares an old layout with a new one, each resolved in its own type registry.
+struct Checker<'a> {
+    old_registry: &'a PortableRegistry,
+    new_registry: &'a PortableRegistry,
+    /// Pairs of type ids which are assumed to be the same type while comparing them.
+    ///
+    /// Breaks the cycle when comparing recursive types.
+    assumed: BTreeSet<(u32, u32)>,
+}
+
+impl Checker<'_> {
+    fn check_layout(
+        &mut self,
+        old: &Layout<PortableForm>,
+        new: &Layout<PortableForm>,
+        path: &mut Vec<String>,
+    ) -> Result<(), LayoutMismatch> {
+        match (old, new) {
+            (Layout::Leaf(old), Layout::Leaf(new)) => {
+                if old.key() != new.key() {
+                    return Err(LayoutMismatch::KeyChanged(path.join("")))
+                }
+                if !self.same_type(old.ty().id, new.ty().id) {
+                    return Err(LayoutMismatch::TypeChanged(path.join("")))
+                }
+                Ok(())
+            }
+            (Layout::Root(old), Layout::Root(new)) => {
+                if old.root_key() != new.root_key() {
+                    return Err(LayoutMismatch::KeyChanged(path.join("")))
+                }
+                if let (Some(old_key_ty), Some(new_key_ty)) = (old.key_ty(), new.key_ty())
+                {
+                    if !self.same_type(old_key_ty.id, new_key_ty.id) {
+                        return Err(LayoutMismatch::TypeChanged(path.join("")))
+                    }
+                }
+                self.check_layout(old.layout(), new.layout(), path)
+            }
+            (Layout::Hash(old), Layout::Hash(new)) => {
+                if old.offset() != new.offset() {
+                    return Err(LayoutMismatch::KeyChanged(path.join("")))
+                }
+                if old.strategy() != new.strategy() {
+                    return Err(LayoutMismatch::StrategyChanged(path.join("")))
+                }
+                self.check_layout(old.layout(), new.layout(), path)
+            }
+            (Layout::Array(old), Layout::Array(new)) => {
+                if old.offset() != new.offset() {
+                    return Err(LayoutMismatch::KeyChanged(path.join("")))
+                }
+                if old.len() != new.len() {
+                    return Err(LayoutMismatch::LenChanged(path.join("")))
+                }
+                self.check_layout(old.layout(), new.layout(), path)
+            }
+            (Layout::Struct(old), Layout::Struct(new)) => {
+                self.check_struct(old, new, path)
+            }
+            (Layout::Enum(old), Layout::Enum(new)) => {
+                if old.dispatch_key() != new.dispatch_key() {
+                    return Err(LayoutMismatch::KeyChanged(path.join("")))
+                }
+                path.push(format!("{}::", old.name()));
+                for (discriminant, old_variant) in old.variants() {
+                    let Some(new_variant) = new.variants().get(discriminant) else {
+                        path.push(old_variant.name().to_string());
+                        return Err(LayoutMismatch::VariantRemoved(path.join("")))
+                    };
+                    self.check_struct(old_variant, new_variant, path)?;
+                }
+                path.pop().expect("stack is not empty; qed");
+                Ok(())
+            }
+            _ => Err(LayoutMismatch::KindChanged(path.join(""))),
+        }
+    }
+
+    /// Fields with a root layout live under their own storage key, all other fields
+    /// are encoded together under the key of the struct.
+    ///
+    /// The latter must match one-to-one, while the former are matched by their key.
+    fn check_struct(
+        &mut self,
+        old: &StructLayout<PortableForm>,
+        new: &StructLayout<PortableForm>,
+        path: &mut Vec<String>,
+    ) -> Result<(), LayoutMismatch> {
+        let is_root = |field: &&FieldLayout<PortableForm>| {
+            matches!(field.layout(), Layout::Root(_))
+        };
+
+        path.push(old.name().to_string());
+        let old_packed = old.fields().iter().filter(|field| !is_root(field));
+        let new_packed = new.fields().iter().filter(|field| !is_root(field));
+        if old_packed.clone().count() != new_packed.clone().count() {
+            return Err(LayoutMismatch::FieldsChanged(path.join("")))
+        }
+        for (old_field, new_field) in old_packed.zip(new_packed) {
+            path.push(format!(".{}:", old_field.name()));
+            self.check_layout(old_field.layout(), new_field.layout(), path)?;
+            path.pop().expect("stack is not empty; qed");
+        }
+
+        for old_field in old.fields().iter().filter(is_root) {
+            let Layout::Root(old_root) = old_field.layout() else {
+                unreachable!("only root fields are selected; qed")
+            };
+            let new_root = new.fields().iter().find_map(|field| {
+                match field.layout() {
+                    Layout::Root(root) if root.root_key() == old_root.root_key() => {
+                        Some(field.layout())
+                    }
+                    _ => None,
+                }
+            });
+            if let Some(new_root) = new_root {
+                path.push(format!(".{}:", old_field.name()));
+                self.check_layout(old_field.layout(), new_root, path)?;
+                path.pop().expect("stack is not empty; qed");
+            }
+        }
+        path.pop().expect("stack is not empty; qed");
+        Ok(())
+    }
+
+    /// Returns `true` if the type `old` of the old registry has the same path and
+    /// shape as the type `new` of the new registry.
+    fn same_type(&mut self, old: u32, new: u32) -> bool {
+        if !self.assumed.insert((old, new)) {
+            return true
+        }
+        let (Some(old), Some(new)) = (
+            self.old_registry.resolve(old),
+            self.new_registry.resolve(new),
+        ) else {
+            return false
+        };
+        if old.path != new.path || old.type_params.len() != new.type_params.len() {
+            return false
+        }
+        for (old_param, new_param) in old.type_params.iter().zip(&new.type_params) {
+            let same = old_param.name == new_param.name
+                && match (&old_param.ty, &new_param.ty) {
+                    (Some(old_ty), Some(new_ty)) => self.same_type(old_ty.id, new_ty.id),
+                    (None, None) => true,
+                    _ => false,
+                };
+            if !same {
+                return false
+            }
+        }
+        self.same_type_def(&old.type_def, &new.type_def)
+    }
+
+    fn same_type_def(
+        &mut self,
+        old: &TypeDef<PortableForm>,
+        new: &TypeDef<PortableForm>,
+    ) -> bool {
+        match (old, new) {
+            (TypeDef::Composite(old), TypeDef::Composite(new)) => {
+                self.same_fields(&old.fields, &new.fields)
+            }
+            (TypeDef::Variant(old), TypeDef::Variant(new)) => {
+                old.variants.len() == new.variants.len()
+                    && old.variants.iter().zip(&new.variants).all(
+                        |(old_variant, new_variant)| {
+                            old_variant.index == new_variant.index
+                                && self
+                                    .same_fields(&old_variant.fields, &new_variant.fields)
+                        },
+                    )
+            }
+            (TypeDef::Sequence(old), TypeDef::Sequence(new)) => {
+                self.same_type(old.type_param.id, new.type_param.id)
+            }
+            (TypeDef::Array(old), TypeDef::Array(new)) => {
+                old.len == new.len && self.same_type(old.type_param.id, new.type_param.id)
+            }
+            (TypeDef::Tuple(old), TypeDef::Tuple(new)) => {
+                old.fields.len() == new.fields.len()
+                    && old
+                        .fields
+                        .iter()
+                        .zip(&new.fields)
+                        .all(|(old, new)| self.same_type(old.id, new.id))
+            }
+            (TypeDef::Primitive(old), TypeDef::Primitive(new)) => old == new,
+            (TypeDef::Compact(old), TypeDef::Compact(new)) => {
+                self.same_type(old.type_param.id, new.type_param.id)
+            }
+            (TypeDef::BitSequence(old), TypeDef::BitSequence(new)) => {
+                self.same_type(old.bit_store_type.id, new.bit_store_type.id)
+                    && self.same_type(old.bit_order_type.id, new.bit_order_type.id)
+            }
+            _ => false,
+        }
+    }
+
+    /// Fields are encoded in order, hence only their types are compared.
+    fn same_fields(
+        &mut self,
+        old: &[Field<PortableForm>],
+        new: &[Field<PortableForm>],
+    ) -> bool {
+        old.len() == new.len()
+            && old
+                .iter()
+                .zip(new)
+                .all(|(old, new)| self.same_type(old.ty.id, new.ty.id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{
+        LeafLayout,
+        RootLayout,
+    };
+    use ink_primitives::Key;
+    use scale_info::{
+        meta_type,
+        IntoPortable,
+        Registry,
+    };
+
+    fn contract(fields: Vec<FieldLayout>) -> Layout {
+        Layout::Root(RootLayout::new_empty(
+            0.into(),
+            StructLayout::new("Contract", fields),
+        ))
+    }
+
+    fn lazy<T: scale_info::TypeInfo + 'static>(key: Key) -> Layout {
+        RootLayout::new_empty(key.into(), LeafLayout::from_key::<T>(key.into())).into()
+    }
+
+    fn mapping<K, V>(key: Key) -> Layout
+    where
+        K: scale_info::TypeInfo + 'static,
+        V: scale_info::TypeInfo + 'static,
+    {
+        RootLayout::new_mapping(
+            key.into(),
+            LeafLayout::from_key::<V>(key.into()),
+            meta_type::<(K, V)>(),
+            meta_type::<K>(),
+            meta_type::<V>(),
+        )
+        .into()
+    }
+
+    /// Registers the types of `layout` in a fresh registry, as done for the metadata
+    /// of a single contract.
+    fn portable(layout: Layout) -> (Layout<PortableForm>, PortableRegistry) {
+        let mut registry = Registry::new();
+        let layout = layout.into_portable(&mut registry);
+        (layout, registry.into())
+    }
+
+    /// Returns the type id of the leaf field at `index` of a contract layout.
+    fn field_ty(contract: &Layout<PortableForm>, index: usize) -> u32 {
+        let Layout::Root(root) = contract else {
+            panic!("contract layout is a root layout")
+        };
+        let Layout::Struct(fields) = root.layout() else {
+            panic!("contract layout wraps a struct")
+        };
+        let Layout::Leaf(leaf) = fields.fields()[index].layout() else {
+            panic!("field is a leaf")
+        };
+        leaf.ty().id
+    }
+
+    fn compatible(old: Layout, new: Layout) -> Result<(), LayoutMismatch> {
+        let (old, old_registry) = portable(old);
+        let (new, new_registry) = portable(new);
+        storage_layout_compatible(&old, &old_registry, &new, &new_registry)
+    }
+
+    #[test]
+    fn adding_lazy_field_with_new_key_is_compatible() {
+        let old = contract(vec![
+            FieldLayout::new("a", LeafLayout::from_key::<u32>(0.into())),
+            FieldLayout::new("b", lazy::<u64>(1)),
+        ]);
+        let new = contract(vec![
+            FieldLayout::new("a", LeafLayout::from_key::<u32>(0.into())),
+            FieldLayout::new("c", lazy::<String>(2)),
+            FieldLayout::new("b", lazy::<u64>(1)),
+        ]);
+
+        assert_eq!(compatible(old, new), Ok(()));
+    }
+
+    #[test]
+    fn same_types_with_different_registry_ids_are_compatible() {
+        let old = contract(vec![
+            FieldLayout::new("a", LeafLayout::from_key::<Option<u32>>(0.into())),
+            FieldLayout::new("b", lazy::<Vec<(u8, bool)>>(1)),
+        ]);
+        let new = contract(vec![
+            FieldLayout::new("c", lazy::<String>(2)),
+            FieldLayout::new("a", LeafLayout::from_key::<Option<u32>>(0.into())),
+            FieldLayout::new("b", lazy::<Vec<(u8, bool)>>(1)),
+        ]);
+        let (old, old_registry) = portable(old);
+        let (new, new_registry) = portable(new);
+
+        assert_ne!(field_ty(&old, 0), field_ty(&new, 1));
+
+        assert_eq!(
+            storage_layout_compatible(&old, &old_registry, &new, &new_registry),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn changing_field_type_at_same_key_is_incompatible() {
+        let old = contract(vec![
+            FieldLayout::new("a", LeafLayout::from_key::<u32>(0.into())),
+            FieldLayout::new("b", lazy::<u64>(1)),
+        ]);
+        let new = contract(vec![
+            FieldLayout::new("a", LeafLayout::from_key::<u32>(0.into())),
+            FieldLayout::new("b", lazy::<u128>(1)),
+        ]);
+
+        assert_eq!(
+            compatible(old, new),
+            Err(LayoutMismatch::TypeChanged("Contract.b:".to_string()))
+        );
+    }
+
+    #[test]
+    fn changing_nested_type_is_incompatible() {
+        let old = contract(vec![FieldLayout::new(
+            "a",
+            LeafLayout::from_key::<Option<u32>>(0.into()),
+        )]);
+        let new = contract(vec![FieldLayout::new(
+            "a",
+            LeafLayout::from_key::<Option<u64>>(0.into()),
+        )]);
+
+        assert_eq!(
+            compatible(old, new),
+            Err(LayoutMismatch::TypeChanged("Contract.a:".to_string()))
+        );
+    }
+
+    #[test]
+    fn adding_packed_field_is_incompatible() {
+        let old = contract(vec![FieldLayout::new(
+            "a",
+            LeafLayout::from_key::<u32>(0.into()),
+        )]);
+        let new = contract(vec![
+            FieldLayout::new("a", LeafLayout::from_key::<u32>(0.into())),
+            FieldLayout::new("b", LeafLayout::from_key::<u32>(0.into())),
+        ]);
+
+        assert_eq!(
+            compatible(old, new),
+            Err(LayoutMismatch::FieldsChanged("Contract".to_string()))
+        );
+    }
+
+    #[test]
+    fn same_mapping_key_with_different_registry_ids_is_compatible() {
+        let old = contract(vec![FieldLayout::new("a", mapping::<Option<u32>, u8>(1))]);
+        let new = contract(vec![
+            FieldLayout::new("b", mapping::<Option<u64>, u8>(2)),
+            FieldLayout::new("a", mapping::<Option<u32>, u8>(1)),
+        ]);
+
+        assert_eq!(compatible(old, new), Ok(()));
+    }
+
+    #[test]
+    fn changing_mapping_key_type_is_incompatible() {
+        let old = contract(vec![FieldLayout::new("a", mapping::<u32, u8>(1))]);
+        let new = contract(vec![FieldLayout::new("a", mapping::<u64, u8>(1))]);
+
+        assert_eq!(
+            compatible(old, new),
+            Err(LayoutMismatch::TypeChanged("Contract.a:".to_string()))
+        );
+    }
+}
@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod compatible;
 #[cfg(test)]
 mod tests;
 mod validate;
 
+pub use compatible::{
+    storage_layout_compatible,
+    LayoutMismatch,
+};
 use core::fmt::Display;
 pub use validate::ValidateLayout;
 
@@ -149,6 +154,12 @@ pub struct RootLayout<F: Form = MetaForm> {
     layout: Box<Layout<F>>,
     /// The type of the encoded entity.
     ty: <F as Form>::Type,
+    /// The type of the keys, if the sub-tree is a mapping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    key_ty: Option<<F as Form>::Type>,
+    /// The type of the values, if the sub-tree is a mapping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value_ty: Option<<F as Form>::Type>,
 }
 
 impl IntoPortable for RootLayout {
@@ -159,6 +170,8 @@ impl IntoPortable for RootLayout {
             root_key: self.root_key,
             layout: Box::new(self.layout.into_portable(registry)),
             ty: registry.register_type(&self.ty),
+            key_ty: self.key_ty.map(|ty| registry.register_type(&ty)),
+            value_ty: self.value_ty.map(|ty| registry.register_type(&ty)),
         }
     }
 }
@@ -186,6 +199,30 @@ where
             root_key,
             layout: Box::new(layout.into()),
             ty,
+            key_ty: None,
+            value_ty: None,
+        }
+    }
+
+    /// Create a new root layout of a mapping from keys of type `key_ty` to values of
+    /// type `value_ty`.
+    ///
+    /// Recording the key and value types allows to decode the raw storage of the
+    /// mapping without knowing the type `ty` of the mapping itself.
+    pub fn new_mapping<L>(
+        root_key: LayoutKey,
+        layout: L,
+        ty: <F as Form>::Type,
+        key_ty: <F as Form>::Type,
+        value_ty: <F as Form>::Type,
+    ) -> Self
+    where
+        L: Into<Layout<F>>,
+    {
+        Self {
+            key_ty: Some(key_ty),
+            value_ty: Some(value_ty),
+            ..Self::new(root_key, layout, ty)
         }
     }
 
@@ -203,6 +240,16 @@ where
     pub fn ty(&self) -> &F::Type {
         &self.ty
     }
+
+    /// Returns the type of the keys, if the sub-tree is a mapping.
+    pub fn key_ty(&self) -> Option<&F::Type> {
+        self.key_ty.as_ref()
+    }
+
+    /// Returns the type of the values, if the sub-tree is a mapping.
+    pub fn value_ty(&self) -> Option<&F::Type> {
+        self.value_ty.as_ref()
+    }
 }
 
 /// A SCALE encoded cell.
@@ -691,6 +738,22 @@ impl IntoPortable for EnumLayout {
 pub enum MetadataError {
     /// Storage keys of two types intersect
     Collision(String, String),
+    /// Selectors of two constructors or two messages are equal.
+    ///
+    /// Holds the hex encoded selector and the labels of the colliding items.
+    SelectorCollision(String, Vec<String>),
+    /// More than one constructor or message is marked as default.
+    ///
+    /// Holds the labels of the items marked as default.
+    MultipleDefaults(Vec<String>),
+    /// Signature topics of two events are equal.
+    ///
+    /// Holds the paths of the colliding events.
+    SignatureTopicCollision(Vec<String>),
+    /// The contract has no constructors.
+    NoConstructors,
+    /// The contract has no messages.
+    NoMessages,
 }
 
 impl Display for MetadataError {
@@ -709,6 +772,22 @@ impl Display for MetadataError {
                     }
                 )
             }
+            Self::SelectorCollision(selector, labels) => {
+                write!(
+                    f,
+                    "selector collision occurred for `{}`: {}",
+                    selector,
+                    labels.join(", ")
+                )
+            }
+            Self::MultipleDefaults(labels) => {
+                write!(f, "only one default item is allowed: {}", labels.join(", "))
+            }
+            Self::SignatureTopicCollision(paths) => {
+                write!(f, "event signature topic collision: {}", paths.join(", "))
+            }
+            Self::NoConstructors => write!(f, "must have at least one constructor"),
+            Self::NoMessages => write!(f, "must have at least one message"),
         }
     }
 }
@@ -320,6 +320,39 @@ fn unbounded_layout_works() {
     assert_eq!(json, expected);
 }
 
+#[test]
+fn mapping_root_layout_works() {
+    let root_key = LayoutKey::from(&1);
+    let layout: Layout = RootLayout::new_mapping(
+        root_key,
+        LeafLayout::from_key::<bool>(root_key),
+        meta_type::<()>(),
+        meta_type::<u32>(),
+        meta_type::<bool>(),
+    )
+    .into();
+    let mut registry = Registry::new();
+    let compacted = layout.into_portable(&mut registry);
+    let json = serde_json::to_value(&compacted).unwrap();
+    let expected = serde_json::json! {
+        {
+            "root": {
+                "layout": {
+                    "leaf": {
+                        "key": "0x01000000",
+                        "ty": 0
+                    }
+                },
+                "root_key": "0x01000000",
+                "ty": 1,
+                "key_ty": 2,
+                "value_ty": 0
+            }
+        }
+    };
+    assert_eq!(json, expected);
+}
+
 #[test]
 fn runtime_storage_layout_works() {
     let key = LayoutKey::new(0u32);
@@ -23,8 +23,12 @@ use scale_info::form::MetaForm;
 
 /// It validates that the storage layout doesn't have conflicting storage keys.
 /// Otherwise an error with a description of the conflict is returned.
+///
+/// The values of a mapping are stored under its root key combined with their keys.
+/// Hence a mapping doesn't conflict with a single cell at its root key, e.g. the
+/// bloom filter of a `BloomMapping`.
 pub struct ValidateLayout {
-    first_entry: HashMap<Key, String>,
+    first_entry: HashMap<(Key, bool), String>,
     name_stack: Vec<String>,
 }
 
@@ -44,7 +48,7 @@ impl ValidateLayout {
     ) -> Result<(), MetadataError> {
         match layout {
             Layout::Root(root) => {
-                self.check_key(root.root_key.key())?;
+                self.check_key(root.root_key.key(), root.key_ty().is_some())?;
                 self.recursive_validate(root.layout())
             }
             Layout::Hash(hash) => self.recursive_validate(hash.layout()),
@@ -79,12 +83,12 @@ impl ValidateLayout {
         Ok(())
     }
 
-    fn check_key(&mut self, key: &Key) -> Result<(), MetadataError> {
+    fn check_key(&mut self, key: &Key, is_mapping: bool) -> Result<(), MetadataError> {
         let path = self.name_stack.join("");
-        if let Some(prev_path) = self.first_entry.get(key) {
+        if let Some(prev_path) = self.first_entry.get(&(*key, is_mapping)) {
             Err(MetadataError::Collision(prev_path.clone(), path))
         } else {
-            self.first_entry.insert(*key, path);
+            self.first_entry.insert((*key, is_mapping), path);
             Ok(())
         }
     }
@@ -103,6 +107,7 @@ mod tests {
         ValidateLayout,
     };
     use ink_primitives::Key;
+    use scale_info::meta_type;
     use std::collections::BTreeSet;
 
     #[test]
@@ -295,4 +300,70 @@ mod tests {
             valid_big_layout_tree(0, 1, 2, 3, 3)
         )
     }
+
+    fn cell_and_mapping_at(cell_key: Key, mapping_key: Key) -> Result<(), MetadataError> {
+        let layout = RootLayout::new_empty(
+            0.into(),
+            StructLayout::new(
+                "Contract",
+                vec![
+                    FieldLayout::new(
+                        "a",
+                        RootLayout::new_empty(
+                            cell_key.into(),
+                            LeafLayout::from_key::<u32>(cell_key.into()),
+                        ),
+                    ),
+                    FieldLayout::new(
+                        "b",
+                        RootLayout::new_mapping(
+                            mapping_key.into(),
+                            LeafLayout::from_key::<u8>(mapping_key.into()),
+                            meta_type::<()>(),
+                            meta_type::<u16>(),
+                            meta_type::<u8>(),
+                        ),
+                    ),
+                ],
+            ),
+        );
+
+        ValidateLayout::validate(&Layout::Root(layout))
+    }
+
+    #[test]
+    fn mapping_does_not_conflict_with_cell_at_its_root_key() {
+        assert_eq!(Ok(()), cell_and_mapping_at(1, 1));
+        assert_eq!(Ok(()), cell_and_mapping_at(1, 2));
+    }
+
+    #[test]
+    fn conflict_mappings() {
+        let layout = RootLayout::new_empty(
+            0.into(),
+            StructLayout::new(
+                "Contract",
+                ["a", "b"].map(|name| {
+                    FieldLayout::new(
+                        name,
+                        RootLayout::new_mapping(
+                            1.into(),
+                            LeafLayout::from_key::<u8>(1.into()),
+                            meta_type::<()>(),
+                            meta_type::<u16>(),
+                            meta_type::<u8>(),
+                        ),
+                    )
+                }),
+            ),
+        );
+
+        assert_eq!(
+            Err(MetadataError::Collision(
+                "Contract.a:".to_string(),
+                "Contract.b:".to_string()
+            )),
+            ValidateLayout::validate(&Layout::Root(layout))
+        )
+    }
 }
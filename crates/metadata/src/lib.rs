@@ -25,34 +25,50 @@ extern crate core;
 #[cfg(test)]
 mod tests;
 
+mod json_rpc;
 pub mod layout;
 mod specs;
+mod typescript;
 mod utils;
 
 pub use ink_primitives::LangError;
 
-pub use self::specs::{
-    ConstructorSpec,
-    ConstructorSpecBuilder,
-    ContractSpec,
-    ContractSpecBuilder,
-    DisplayName,
-    EnvironmentSpec,
-    EnvironmentSpecBuilder,
-    EventParamSpec,
-    EventParamSpecBuilder,
-    EventSpec,
-    EventSpecBuilder,
-    MessageParamSpec,
-    MessageParamSpecBuilder,
-    MessageSpec,
-    MessageSpecBuilder,
-    ReturnTypeSpec,
-    Selector,
-    TypeSpec,
+pub use self::{
+    json_rpc::{
+        JsonRpcContentDescriptor,
+        JsonRpcMethod,
+    },
+    layout::{
+        storage_layout_compatible,
+        LayoutMismatch,
+    },
+    specs::{
+        ConstructorSpec,
+        ConstructorSpecBuilder,
+        ContractSpec,
+        ContractSpecBuilder,
+        DisplayName,
+        EnvironmentSpec,
+        EnvironmentSpecBuilder,
+        EventParamSpec,
+        EventParamSpecBuilder,
+        EventSpec,
+        EventSpecBuilder,
+        ImportMessageSpec,
+        ImportSpec,
+        MessageParamSpec,
+        MessageParamSpecBuilder,
+        MessageSpec,
+        MessageSpecBuilder,
+        ReturnTypeSpec,
+        Selector,
+        TypeSpec,
+        DEFAULT_EVENT_SCHEMA_VERSION,
+    },
 };
 
 use impl_serde::serialize as serde_hex;
+use ink_prelude::collections::btree_map::BTreeMap;
 
 #[doc(hidden)]
 pub use linkme;
@@ -87,6 +103,9 @@ pub struct InkProject {
     /// The layout of the storage data structure
     layout: layout::Layout<PortableForm>,
     spec: ContractSpec<PortableForm>,
+    /// The interfaces of other contracts which are called by the contract.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    imports: Vec<ImportSpec>,
 }
 
 impl InkProject {
@@ -103,6 +122,7 @@ impl InkProject {
             layout: layout.into().into_portable(&mut registry),
             spec: spec.into().into_portable(&mut registry),
             registry: registry.into(),
+            imports: Vec::new(),
         }
     }
 
@@ -119,6 +139,26 @@ impl InkProject {
             layout,
             spec,
             registry,
+            imports: Vec::new(),
+        }
+    }
+
+    /// Sets the interfaces of other contracts which are called by the contract.
+    ///
+    /// Interfaces with the same path are only recorded once.
+    pub fn with_imports<I>(self, imports: I) -> Self
+    where
+        I: IntoIterator<Item = ImportSpec>,
+    {
+        let mut unique = Vec::<ImportSpec>::new();
+        for import in imports {
+            if unique.iter().all(|known| known.path() != import.path()) {
+                unique.push(import);
+            }
+        }
+        Self {
+            imports: unique,
+            ..self
         }
     }
 
@@ -141,6 +181,91 @@ impl InkProject {
     pub fn spec(&self) -> &ContractSpec<PortableForm> {
         &self.spec
     }
+
+    /// Returns the interfaces of other contracts which are called by the contract.
+    pub fn imports(&self) -> &[ImportSpec] {
+        &self.imports
+    }
+
+    /// Checks the structural invariants of the contract specification.
+    ///
+    /// Metadata generated by ink! always satisfies them, but metadata obtained from
+    /// elsewhere (e.g. deserialized from a file) is not guaranteed to. The checks are:
+    ///
+    /// - there is at least one constructor and one message,
+    /// - no two constructors and no two messages share a selector,
+    /// - at most one constructor and at most one message is marked as default,
+    /// - no two events share a signature topic.
+    ///
+    /// Returns all violations that were found.
+    pub fn validate(&self) -> Result<(), Vec<layout::MetadataError>> {
+        use layout::MetadataError;
+
+        let mut errors = Vec::new();
+        let constructors = self.spec.constructors();
+        let messages = self.spec.messages();
+
+        if constructors.is_empty() {
+            errors.push(MetadataError::NoConstructors);
+        }
+        if messages.is_empty() {
+            errors.push(MetadataError::NoMessages);
+        }
+
+        let constructors = constructors
+            .iter()
+            .map(|c| (c.label(), c.selector(), *c.default()))
+            .collect::<Vec<_>>();
+        let messages = messages
+            .iter()
+            .map(|m| (m.label(), m.selector(), *m.default()))
+            .collect::<Vec<_>>();
+        for items in [constructors, messages] {
+            let mut selectors: BTreeMap<&[u8], Vec<String>> = BTreeMap::new();
+            for (label, selector, _) in items.iter() {
+                selectors
+                    .entry(selector.to_bytes())
+                    .or_default()
+                    .push(label.to_string());
+            }
+            for (selector, labels) in selectors {
+                if labels.len() > 1 {
+                    let selector = serde_hex::to_hex(selector, false);
+                    errors.push(MetadataError::SelectorCollision(selector, labels));
+                }
+            }
+
+            let defaults = items
+                .iter()
+                .filter(|(_, _, default)| *default)
+                .map(|(label, _, _)| label.to_string())
+                .collect::<Vec<_>>();
+            if defaults.len() > 1 {
+                errors.push(MetadataError::MultipleDefaults(defaults));
+            }
+        }
+
+        let mut signature_topics: BTreeMap<&[u8], Vec<String>> = BTreeMap::new();
+        for event in self.spec.events() {
+            if let Some(signature_topic) = event.signature_topic() {
+                signature_topics
+                    .entry(signature_topic.as_bytes())
+                    .or_default()
+                    .push(format!("`{}::{}`", event.module_path(), event.label()));
+            }
+        }
+        for (_, paths) in signature_topics {
+            if paths.len() > 1 {
+                errors.push(MetadataError::SignatureTopicCollision(paths));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 /// Any event which derives `#[derive(ink::EventMetadata)]` and is used in the contract
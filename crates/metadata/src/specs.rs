@@ -429,6 +429,12 @@ where
         &self.selector
     }
 
+    /// Returns the selector hash of the constructor as a hex string, e.g.
+    /// `"0xa9059cbb"`.
+    pub fn selector_hex(&self) -> String {
+        self.selector.to_hex()
+    }
+
     /// Returns if the constructor is payable by the caller.
     pub fn payable(&self) -> &bool {
         &self.payable
@@ -633,6 +639,18 @@ pub struct MessageSpec<F: Form = MetaForm> {
     docs: Vec<F::String>,
     /// If the message is the default for off-chain consumers (e.g UIs).
     default: bool,
+    /// If the message rejects calls from other contracts, i.e. may only be called by
+    /// externally owned accounts.
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    eoa_only: bool,
+    /// If the message is guarded against reentrant calls.
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    reentrancy_guarded: bool,
+    /// The paths of the events the message declares to emit.
+    ///
+    /// Each path is made up of the module path and the label of the event.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    emits: Vec<String>,
 }
 
 /// Type state for builders to tell that some mandatory state has not yet been set
@@ -694,6 +712,9 @@ where
                 return_type: ReturnTypeSpec::new(TypeSpec::default()),
                 docs: Vec::new(),
                 default: false,
+                eoa_only: false,
+                reentrancy_guarded: false,
+                emits: Vec::new(),
             },
             marker: PhantomData,
         }
@@ -717,6 +738,11 @@ where
         &self.selector
     }
 
+    /// Returns the selector hash of the message as a hex string, e.g. `"0xa9059cbb"`.
+    pub fn selector_hex(&self) -> String {
+        self.selector.to_hex()
+    }
+
     /// Returns true if the message is allowed to mutate the contract state.
     pub fn mutates(&self) -> bool {
         self.mutates
@@ -745,6 +771,21 @@ where
     pub fn default(&self) -> &bool {
         &self.default
     }
+
+    /// Returns true if the message may only be called by externally owned accounts.
+    pub fn eoa_only(&self) -> bool {
+        self.eoa_only
+    }
+
+    /// Returns true if the message is guarded against reentrant calls.
+    pub fn reentrancy_guarded(&self) -> bool {
+        self.reentrancy_guarded
+    }
+
+    /// Returns the paths of the events the message declares to emit.
+    pub fn emits(&self) -> &[String] {
+        &self.emits
+    }
 }
 
 /// A builder for messages.
@@ -877,6 +918,39 @@ where
             marker: PhantomData,
         }
     }
+
+    /// Sets if the message may only be called by externally owned accounts.
+    pub fn eoa_only(self, eoa_only: bool) -> Self {
+        MessageSpecBuilder {
+            spec: MessageSpec {
+                eoa_only,
+                ..self.spec
+            },
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets if the message is guarded against reentrant calls.
+    pub fn reentrancy_guarded(self, reentrancy_guarded: bool) -> Self {
+        MessageSpecBuilder {
+            spec: MessageSpec {
+                reentrancy_guarded,
+                ..self.spec
+            },
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets the paths of the events the message declares to emit.
+    pub fn emits<E>(self, emits: E) -> Self
+    where
+        E: IntoIterator<Item = String>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.emits.is_empty());
+        this.spec.emits = emits.into_iter().collect::<Vec<_>>();
+        this
+    }
 }
 
 impl<F>
@@ -906,6 +980,8 @@ impl IntoPortable for MessageSpec {
             mutates: self.mutates,
             payable: self.payable,
             default: self.default,
+            eoa_only: self.eoa_only,
+            reentrancy_guarded: self.reentrancy_guarded,
             args: self
                 .args
                 .into_iter()
@@ -913,6 +989,7 @@ impl IntoPortable for MessageSpec {
                 .collect::<Vec<_>>(),
             return_type: self.return_type.into_portable(registry),
             docs: self.docs.into_iter().map(|s| s.into()).collect(),
+            emits: self.emits,
         }
     }
 }
@@ -934,6 +1011,23 @@ pub struct EventSpec<F: Form = MetaForm> {
     args: Vec<EventParamSpec<F>>,
     /// The event documentation.
     docs: Vec<F::String>,
+    /// The version of the event schema, bumped on breaking changes to the event.
+    #[serde(
+        default = "default_event_schema_version",
+        skip_serializing_if = "is_default_event_schema_version"
+    )]
+    schema_version: u16,
+}
+
+/// The version of an event schema which is not specified explicitly.
+pub const DEFAULT_EVENT_SCHEMA_VERSION: u16 = 1;
+
+fn default_event_schema_version() -> u16 {
+    DEFAULT_EVENT_SCHEMA_VERSION
+}
+
+fn is_default_event_schema_version(schema_version: &u16) -> bool {
+    *schema_version == DEFAULT_EVENT_SCHEMA_VERSION
 }
 
 /// The value of the signature topic for a non anonymous event.
@@ -1010,6 +1104,15 @@ where
         this
     }
 
+    /// Sets the version of the event schema.
+    ///
+    /// Defaults to [`DEFAULT_EVENT_SCHEMA_VERSION`] if not set.
+    pub fn schema_version(self, schema_version: u16) -> Self {
+        let mut this = self;
+        this.spec.schema_version = schema_version;
+        this
+    }
+
     /// Sets the input arguments of the event specification.
     pub fn docs<'a, D>(self, docs: D) -> Self
     where
@@ -1045,6 +1148,7 @@ impl IntoPortable for EventSpec {
                 .map(|arg| arg.into_portable(registry))
                 .collect::<Vec<_>>(),
             docs: self.docs.into_iter().map(|s| s.into()).collect(),
+            schema_version: self.schema_version,
         }
     }
 }
@@ -1063,6 +1167,7 @@ where
                 signature_topic: None,
                 args: Vec::new(),
                 docs: Vec::new(),
+                schema_version: DEFAULT_EVENT_SCHEMA_VERSION,
             },
         }
     }
@@ -1077,6 +1182,11 @@ where
         &self.label
     }
 
+    /// The module path of the event definition.
+    pub fn module_path(&self) -> &F::String {
+        &self.module_path
+    }
+
     /// The event arguments.
     pub fn args(&self) -> &[EventParamSpec<F>] {
         &self.args
@@ -1091,6 +1201,11 @@ where
     pub fn docs(&self) -> &[F::String] {
         &self.docs
     }
+
+    /// The version of the event schema.
+    pub fn schema_version(&self) -> u16 {
+        self.schema_version
+    }
 }
 
 /// The 4 byte selector to identify constructors and messages
@@ -1131,6 +1246,12 @@ impl Selector {
     pub fn to_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Returns the selector bytes as a `0x` prefixed hex string, as it is serialized in
+    /// the metadata.
+    pub fn to_hex(&self) -> String {
+        serde_hex::to_hex(&self.0, false)
+    }
 }
 
 /// Describes the syntactical name of a type at a given type position.
@@ -1415,15 +1536,18 @@ where
 
 /// Describes the contract message return type.
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
-#[serde(transparent)]
 #[serde(bound(
     serialize = "F::Type: Serialize, F::String: Serialize",
     deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
 ))]
+#[serde(rename_all = "camelCase")]
 #[must_use]
 pub struct ReturnTypeSpec<F: Form = MetaForm> {
-    #[serde(rename = "type")]
+    #[serde(flatten)]
     ret_type: TypeSpec<F>,
+    /// The error type `E` if the return type is a `Result<T, E>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    result_error: Option<TypeSpec<F>>,
 }
 
 impl IntoPortable for ReturnTypeSpec {
@@ -1432,6 +1556,9 @@ impl IntoPortable for ReturnTypeSpec {
     fn into_portable(self, registry: &mut Registry) -> Self::Output {
         ReturnTypeSpec {
             ret_type: self.ret_type.into_portable(registry),
+            result_error: self
+                .result_error
+                .map(|result_error| result_error.into_portable(registry)),
         }
     }
 }
@@ -1455,6 +1582,24 @@ where
     {
         Self {
             ret_type: ty.into(),
+            result_error: None,
+        }
+    }
+
+    /// Sets the error type `E` of the return type if it is a `Result<T, E>`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ink_metadata::{TypeSpec, ReturnTypeSpec};
+    /// // return type of `Result<i32, u8>`
+    /// ReturnTypeSpec::new(TypeSpec::of_type::<Result<i32, u8>>())
+    ///     .with_result_error(Some(TypeSpec::of_type::<u8>()));
+    /// ```
+    pub fn with_result_error(self, result_error: Option<TypeSpec<F>>) -> Self {
+        Self {
+            result_error,
+            ..self
         }
     }
 
@@ -1462,6 +1607,11 @@ where
     pub fn ret_type(&self) -> &TypeSpec<F> {
         &self.ret_type
     }
+
+    /// Returns the error type `E` if the return type is a `Result<T, E>`.
+    pub fn result_error(&self) -> Option<&TypeSpec<F>> {
+        self.result_error.as_ref()
+    }
 }
 
 /// Describes a pair of parameter label and type.
@@ -1865,3 +2015,64 @@ where
         self.spec
     }
 }
+
+/// Describes an interface of other contracts which is called by the contract.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ImportSpec {
+    /// The full path of the ink! trait definition.
+    path: String,
+    /// The messages of the ink! trait definition.
+    messages: Vec<ImportMessageSpec>,
+}
+
+impl ImportSpec {
+    /// Creates a new import spec for the ink! trait definition at `path`.
+    pub fn new<M>(path: impl Into<String>, messages: M) -> Self
+    where
+        M: IntoIterator<Item = ImportMessageSpec>,
+    {
+        Self {
+            path: path.into(),
+            messages: messages.into_iter().collect(),
+        }
+    }
+
+    /// Returns the full path of the ink! trait definition.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the messages of the ink! trait definition.
+    pub fn messages(&self) -> &[ImportMessageSpec] {
+        &self.messages
+    }
+}
+
+/// Describes a message of an interface of other contracts.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ImportMessageSpec {
+    /// The label of the message.
+    label: String,
+    /// The selector hash of the message.
+    selector: Selector,
+}
+
+impl ImportMessageSpec {
+    /// Creates a new import message spec.
+    pub fn new(label: impl Into<String>, selector: [u8; 4]) -> Self {
+        Self {
+            label: label.into(),
+            selector: selector.into(),
+        }
+    }
+
+    /// Returns the label of the message.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the selector hash of the message.
+    pub fn selector(&self) -> &Selector {
+        &self.selector
+    }
+}
@@ -65,6 +65,212 @@ fn spec_constructor_selector_must_serialize_to_hex() {
     assert_eq!(deserialized.selector, portable_spec.selector);
 }
 
+#[test]
+fn spec_message_selector_hex_matches_bytes() {
+    // given
+    let ms = MessageSpec::from_label("transfer")
+        .selector([0xa9, 0x05, 0x9c, 0xbb])
+        .mutates(true)
+        .payable(false)
+        .returns(ReturnTypeSpec::new(TypeSpec::with_name_str::<
+            ink_primitives::MessageResult<()>,
+        >(
+            "ink_primitives::MessageResult"
+        )))
+        .done();
+    let mut registry = Registry::new();
+    let portable_spec = ms.into_portable(&mut registry);
+
+    // when
+    let json = serde_json::to_value(&portable_spec).unwrap();
+
+    // then
+    assert_eq!(portable_spec.selector_hex(), "0xa9059cbb");
+    assert_eq!(json["selector"], portable_spec.selector_hex());
+}
+
+#[test]
+fn spec_message_eoa_only_only_serialized_if_set() {
+    // given
+    fn message(label: &'static str, eoa_only: bool) -> MessageSpec {
+        MessageSpec::from_label(label)
+            .selector([0, 0, 0, 1])
+            .mutates(true)
+            .payable(false)
+            .eoa_only(eoa_only)
+            .returns(ReturnTypeSpec::new(TypeSpec::with_name_str::<
+                ink_primitives::MessageResult<()>,
+            >(
+                "ink_primitives::MessageResult"
+            )))
+            .done()
+    }
+    let mut registry = Registry::new();
+    let admin_message = message("set_admin", true).into_portable(&mut registry);
+    let plain_message = message("flip", false).into_portable(&mut registry);
+
+    // when
+    let admin_json = serde_json::to_value(&admin_message).unwrap();
+    let plain_json = serde_json::to_value(&plain_message).unwrap();
+
+    // then
+    assert!(admin_message.eoa_only());
+    assert_eq!(admin_json["eoaOnly"], json!(true));
+    assert!(!plain_message.eoa_only());
+    assert!(plain_json.get("eoaOnly").is_none());
+
+    for (spec, json) in [(admin_message, admin_json), (plain_message, plain_json)] {
+        let deserialized: MessageSpec<PortableForm> =
+            serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, spec);
+    }
+}
+
+#[test]
+fn spec_message_result_error_only_serialized_for_results() {
+    // given
+    let result_message = MessageSpec::from_label("try_get")
+        .selector([0, 0, 0, 1])
+        .mutates(false)
+        .payable(false)
+        .returns(
+            ReturnTypeSpec::new(TypeSpec::with_name_str::<
+                ink_primitives::MessageResult<Result<u32, u8>>,
+            >("ink_primitives::MessageResult"))
+            .with_result_error(Some(TypeSpec::with_name_str::<u8>("u8"))),
+        )
+        .done();
+    let plain_message = MessageSpec::from_label("get")
+        .selector([0, 0, 0, 2])
+        .mutates(false)
+        .payable(false)
+        .returns(ReturnTypeSpec::new(TypeSpec::with_name_str::<
+            ink_primitives::MessageResult<u32>,
+        >(
+            "ink_primitives::MessageResult"
+        )))
+        .done();
+    let mut registry = Registry::new();
+    let result_message = result_message.into_portable(&mut registry);
+    let plain_message = plain_message.into_portable(&mut registry);
+    let registry: PortableRegistry = registry.into();
+
+    // when
+    let result_json = serde_json::to_value(&result_message).unwrap();
+    let plain_json = serde_json::to_value(&plain_message).unwrap();
+
+    // then
+    let result_error = result_message.return_type().result_error().unwrap();
+    assert_eq!(
+        registry.resolve(result_error.ty().id).unwrap().type_def,
+        scale_info::TypeDef::Primitive(scale_info::TypeDefPrimitive::U8)
+    );
+    assert_eq!(
+        result_json["returnType"],
+        json!({
+            "displayName": ["ink_primitives", "MessageResult"],
+            "type": result_message.return_type().ret_type().ty().id,
+            "resultError": {
+                "displayName": ["u8"],
+                "type": result_error.ty().id,
+            },
+        })
+    );
+    assert_eq!(plain_message.return_type().result_error(), None);
+    assert_eq!(
+        plain_json["returnType"],
+        json!({
+            "displayName": ["ink_primitives", "MessageResult"],
+            "type": plain_message.return_type().ret_type().ty().id,
+        })
+    );
+
+    for (spec, json) in [(result_message, result_json), (plain_message, plain_json)] {
+        let deserialized: MessageSpec<PortableForm> =
+            serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, spec);
+    }
+}
+
+#[test]
+fn spec_contract_json_rpc_methods() {
+    // given
+    let transfer = MessageSpec::from_label("transfer")
+        .selector([0, 0, 0, 1])
+        .mutates(true)
+        .payable(false)
+        .args(vec![
+            MessageParamSpec::new("to")
+                .of_type(TypeSpec::with_name_str::<ink_primitives::AccountId>(
+                    "ink_primitives::AccountId",
+                ))
+                .done(),
+            MessageParamSpec::new("value")
+                .of_type(TypeSpec::with_name_str::<u128>("u128"))
+                .done(),
+        ])
+        .returns(ReturnTypeSpec::new(TypeSpec::with_name_str::<
+            ink_primitives::MessageResult<bool>,
+        >(
+            "ink_primitives::MessageResult"
+        )))
+        .done();
+    let spec = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_label("new")
+            .selector([0, 0, 0, 0])
+            .payable(false)
+            .returns(ReturnTypeSpec::new(TypeSpec::default()))
+            .done()])
+        .messages(vec![transfer])
+        .done();
+    let mut registry = Registry::new();
+    let spec = spec.into_portable(&mut registry);
+
+    // when
+    let methods = spec.json_rpc_methods();
+    let json = serde_json::to_value(&methods).unwrap();
+
+    // then
+    let message = &spec.messages()[0];
+    let to = message.args()[0].ty().ty().id;
+    let value = message.args()[1].ty().ty().id;
+    let ret = message.return_type().ret_type().ty().id;
+    assert_eq!(methods.len(), 1);
+    assert_eq!(methods[0].name(), "transfer");
+    assert_eq!(
+        json,
+        json!([{
+            "name": "transfer",
+            "params": [
+                {
+                    "name": "to",
+                    "schema": {
+                        "type": to,
+                        "displayName": ["ink_primitives", "AccountId"],
+                    },
+                },
+                {
+                    "name": "value",
+                    "schema": {
+                        "type": value,
+                        "displayName": ["u128"],
+                    },
+                },
+            ],
+            "result": {
+                "name": "return_value",
+                "schema": {
+                    "type": ret,
+                    "displayName": ["ink_primitives", "MessageResult"],
+                },
+            },
+            "x-selector": "0x00000001",
+            "x-mutates": true,
+            "x-payable": false,
+        }])
+    );
+}
+
 #[test]
 #[should_panic(expected = "only one default message is allowed")]
 fn spec_contract_only_one_default_message_allowed() {
@@ -807,6 +1013,35 @@ fn should_trim_whitespaces_in_events_docs() {
     assert_eq!(event_spec_name, expected_event_spec);
 }
 
+#[test]
+fn event_schema_version_is_serialized_unless_default() {
+    // given
+    let event = |schema_version: Option<u16>| {
+        let builder = EventSpec::<PortableForm>::new("foobar".into())
+            .module_path("foo")
+            .signature_topic(Some([0u8; 32]));
+        match schema_version {
+            Some(schema_version) => builder.schema_version(schema_version),
+            None => builder,
+        }
+        .done()
+    };
+
+    // when
+    let versioned = serde_json::to_value(event(Some(2))).unwrap();
+    let unversioned = serde_json::to_value(event(None)).unwrap();
+
+    // then
+    assert_eq!(versioned["schema_version"], 2);
+    assert!(unversioned.get("schema_version").is_none());
+    let deserialized: EventSpec<PortableForm> =
+        serde_json::from_value(unversioned).unwrap();
+    assert_eq!(deserialized.schema_version(), DEFAULT_EVENT_SCHEMA_VERSION);
+    let deserialized: EventSpec<PortableForm> =
+        serde_json::from_value(versioned).unwrap();
+    assert_eq!(deserialized.schema_version(), 2);
+}
+
 /// Create a default environment spec with the `max_event_topics` set to `4`.
 fn environment_spec() -> EnvironmentSpec<PortableForm> {
     EnvironmentSpec::new()
@@ -980,3 +1215,47 @@ fn construct_runtime_contract_spec() {
     );
     assert_eq!(event_spec, expected_event_spec);
 }
+
+/// Helper for creating a portable ink! project with the given spec at runtime.
+fn runtime_ink_project(spec: ContractSpec<PortableForm>) -> InkProject {
+    let layout = layout::Layout::Leaf(layout::LeafLayout::new(
+        layout::LayoutKey::new(0u32),
+        0.into(),
+    ));
+    InkProject::new_portable(layout, spec, Registry::new().into())
+}
+
+#[test]
+fn validate_accepts_valid_project() {
+    let spec = ContractSpec::new()
+        .environment(environment_spec())
+        .constructors([runtime_constructor_spec()])
+        .messages([runtime_message_spec()])
+        .events([runtime_event_spec()])
+        .done();
+
+    assert_eq!(runtime_ink_project(spec).validate(), Ok(()));
+}
+
+#[test]
+fn validate_reports_selector_collision() {
+    let bar_message = MessageSpec::from_label("bar".to_string())
+        .selector(Default::default())
+        .mutates(false)
+        .payable(false)
+        .returns(ReturnTypeSpec::new(TypeSpec::default()))
+        .done();
+    let spec = ContractSpec::new()
+        .environment(environment_spec())
+        .constructors([runtime_constructor_spec()])
+        .messages([runtime_message_spec(), bar_message])
+        .done();
+
+    assert_eq!(
+        runtime_ink_project(spec).validate(),
+        Err(vec![layout::MetadataError::SelectorCollision(
+            "0x00000000".to_string(),
+            vec!["foo".to_string(), "bar".to_string()],
+        )])
+    );
+}
@@ -0,0 +1,310 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! TypeScript declarations of the messages and events of a contract.
+//!
+//! The declarations describe the decoded values, so that front-ends can type the
+//! results of their calls and the events they receive.
+
+use crate::{
+    EventSpec,
+    InkProject,
+    MessageSpec,
+};
+use core::fmt::Write as _;
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+use scale_info::{
+    form::PortableForm,
+    Field,
+    PortableRegistry,
+    TypeDef,
+    TypeDefPrimitive,
+};
+
+impl InkProject {
+    /// Returns TypeScript declarations of the messages and events of the contract.
+    ///
+    /// The messages are declared as methods of an interface named `contract_name`.
+    /// Every event is declared as an interface named after its label, and
+    /// `<contract_name>Event` is the union of all events.
+    ///
+    /// Messages return the `Ok` value of their result, as the error of a failed
+    /// dispatch is not part of their signature. Types are mapped from the type registry
+    /// as follows:
+    ///
+    /// - `bool` becomes `boolean`, `char` and `str` become `string`.
+    /// - Integers of up to 32 bits become `number`, wider integers become `bigint`.
+    /// - Sequences and arrays of bytes become `Uint8Array`, other ones become arrays.
+    /// - Tuples become TypeScript tuples, the unit type becomes `void`.
+    /// - Structs become object types, newtypes become their inner type.
+    /// - `Option<T>` becomes `T | null`, other enums become a union of their variants.
+    pub fn typescript_interface(&self, contract_name: &str) -> String {
+        let mut generator = Generator {
+            registry: self.registry(),
+            output: String::new(),
+        };
+        let spec = self.spec();
+        generator.contract(contract_name, spec.docs(), spec.messages());
+        for event in spec.events() {
+            generator.event(event);
+        }
+        generator.event_union(contract_name, spec.events());
+        generator.output
+    }
+}
+
+/// Writes the TypeScript declarations into `output`.
+struct Generator<'a> {
+    registry: &'a PortableRegistry,
+    output: String,
+}
+
+impl Generator<'_> {
+    fn contract(
+        &mut self,
+        name: &str,
+        docs: &[String],
+        messages: &[MessageSpec<PortableForm>],
+    ) {
+        self.docs("", docs);
+        let _ = writeln!(self.output, "export interface {name} {{");
+        for message in messages {
+            self.docs("  ", message.docs());
+            let params = message
+                .args()
+                .iter()
+                .map(|arg| {
+                    let ty = self.ty(arg.ty().ty().id, &mut Vec::new());
+                    ink_prelude::format!("{}: {ty}", arg.label())
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let ret_type =
+                self.message_return_type(message.return_type().ret_type().ty().id);
+            let _ = writeln!(
+                self.output,
+                "  {}({params}): {ret_type};",
+                property_name(message.label())
+            );
+        }
+        self.output.push_str("}\n");
+    }
+
+    fn event(&mut self, event: &EventSpec<PortableForm>) {
+        self.output.push('\n');
+        self.docs("", event.docs());
+        let _ = writeln!(self.output, "export interface {} {{", event.label());
+        for arg in event.args() {
+            self.docs("  ", arg.docs());
+            let ty = self.ty(arg.ty().ty().id, &mut Vec::new());
+            let _ = writeln!(self.output, "  {}: {ty};", property_name(arg.label()));
+        }
+        self.output.push_str("}\n");
+    }
+
+    fn event_union(&mut self, contract_name: &str, events: &[EventSpec<PortableForm>]) {
+        let union = if events.is_empty() {
+            String::from("never")
+        } else {
+            events
+                .iter()
+                .map(|event| event.label().as_str())
+                .collect::<Vec<_>>()
+                .join(" | ")
+        };
+        let _ = writeln!(self.output, "\nexport type {contract_name}Event = {union};");
+    }
+
+    fn docs(&mut self, indent: &str, docs: &[String]) {
+        if docs.is_empty() {
+            return
+        }
+        let _ = writeln!(self.output, "{indent}/**");
+        for line in docs {
+            let _ = writeln!(self.output, "{indent} * {}", line.trim());
+        }
+        let _ = writeln!(self.output, "{indent} */");
+    }
+
+    /// Returns the TypeScript type of the `Ok` value of the `Result` returned by a
+    /// message.
+    fn message_return_type(&self, id: u32) -> String {
+        let ok_id = self.registry.resolve(id).and_then(|ty| {
+            match &ty.type_def {
+                TypeDef::Variant(def) if ty.path.ident().as_deref() == Some("Result") => {
+                    def.variants
+                        .iter()
+                        .find(|variant| variant.name == "Ok")
+                        .and_then(|variant| variant.fields.first())
+                        .map(|field| field.ty.id)
+                }
+                _ => None,
+            }
+        });
+        self.ty(ok_id.unwrap_or(id), &mut Vec::new())
+    }
+
+    /// Returns the TypeScript type of the type with the given `id`.
+    ///
+    /// `visiting` holds the types currently being resolved, recursive types are
+    /// declared as `unknown` where they refer to themselves.
+    fn ty(&self, id: u32, visiting: &mut Vec<u32>) -> String {
+        let Some(ty) = self.registry.resolve(id) else {
+            return String::from("unknown")
+        };
+        if visiting.contains(&id) {
+            return String::from("unknown")
+        }
+        visiting.push(id);
+        let ts_type = match &ty.type_def {
+            TypeDef::Primitive(primitive) => String::from(primitive_type(primitive)),
+            TypeDef::Compact(def) => self.ty(def.type_param.id, visiting),
+            TypeDef::Sequence(def) => self.array_type(def.type_param.id, visiting),
+            TypeDef::Array(def) => self.array_type(def.type_param.id, visiting),
+            TypeDef::BitSequence(_) => String::from("Uint8Array"),
+            TypeDef::Tuple(def) => {
+                if def.fields.is_empty() {
+                    String::from("void")
+                } else {
+                    let fields = def
+                        .fields
+                        .iter()
+                        .map(|field| self.ty(field.id, visiting))
+                        .collect::<Vec<_>>();
+                    ink_prelude::format!("[{}]", fields.join(", "))
+                }
+            }
+            TypeDef::Composite(def) => self.fields_type(&def.fields, visiting),
+            TypeDef::Variant(def) => {
+                let option_value = def
+                    .variants
+                    .iter()
+                    .find(|variant| variant.name == "Some")
+                    .and_then(|variant| variant.fields.first());
+                match option_value {
+                    Some(field) if ty.path.ident().as_deref() == Some("Option") => {
+                        ink_prelude::format!("{} | null", self.ty(field.ty.id, visiting))
+                    }
+                    _ if def.variants.is_empty() => String::from("never"),
+                    _ => {
+                        def.variants
+                            .iter()
+                            .map(|variant| {
+                                if variant.fields.is_empty() {
+                                    ink_prelude::format!("\"{}\"", variant.name)
+                                } else {
+                                    let fields =
+                                        self.fields_type(&variant.fields, visiting);
+                                    ink_prelude::format!(
+                                        "{{ {}: {fields} }}",
+                                        property_name(&variant.name)
+                                    )
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .join(" | ")
+                    }
+                }
+            }
+        };
+        visiting.pop();
+        ts_type
+    }
+
+    fn array_type(&self, elem_id: u32, visiting: &mut Vec<u32>) -> String {
+        let is_byte = matches!(
+            self.registry.resolve(elem_id).map(|ty| &ty.type_def),
+            Some(TypeDef::Primitive(TypeDefPrimitive::U8))
+        );
+        if is_byte {
+            return String::from("Uint8Array")
+        }
+        let elem = self.ty(elem_id, visiting);
+        if elem.contains(' ') {
+            ink_prelude::format!("({elem})[]")
+        } else {
+            ink_prelude::format!("{elem}[]")
+        }
+    }
+
+    /// Returns the TypeScript type of the fields of a struct or an enum variant.
+    fn fields_type(
+        &self,
+        fields: &[Field<PortableForm>],
+        visiting: &mut Vec<u32>,
+    ) -> String {
+        match fields {
+            [] => String::from("null"),
+            [field] if field.name.is_none() => self.ty(field.ty.id, visiting),
+            fields if fields.iter().all(|field| field.name.is_some()) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| {
+                        let name = field.name.as_deref().unwrap_or_default();
+                        ink_prelude::format!(
+                            "{}: {}",
+                            property_name(name),
+                            self.ty(field.ty.id, visiting)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                ink_prelude::format!("{{ {} }}", fields.join("; "))
+            }
+            fields => {
+                let fields = fields
+                    .iter()
+                    .map(|field| self.ty(field.ty.id, visiting))
+                    .collect::<Vec<_>>();
+                ink_prelude::format!("[{}]", fields.join(", "))
+            }
+        }
+    }
+}
+
+fn primitive_type(primitive: &TypeDefPrimitive) -> &'static str {
+    match primitive {
+        TypeDefPrimitive::Bool => "boolean",
+        TypeDefPrimitive::Char | TypeDefPrimitive::Str => "string",
+        TypeDefPrimitive::U8
+        | TypeDefPrimitive::U16
+        | TypeDefPrimitive::U32
+        | TypeDefPrimitive::I8
+        | TypeDefPrimitive::I16
+        | TypeDefPrimitive::I32 => "number",
+        TypeDefPrimitive::U64
+        | TypeDefPrimitive::U128
+        | TypeDefPrimitive::U256
+        | TypeDefPrimitive::I64
+        | TypeDefPrimitive::I128
+        | TypeDefPrimitive::I256 => "bigint",
+    }
+}
+
+/// Returns `name` as a TypeScript property name, quoting it if it is not a valid
+/// identifier, e.g. for the `Trait::message` labels of trait messages.
+fn property_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        String::from(name)
+    } else {
+        ink_prelude::format!("\"{name}\"")
+    }
+}
@@ -55,10 +55,26 @@ pub use self::{
 pub enum LangError {
     /// Failed to read execution input for the dispatchable.
     CouldNotReadInput = 1u32,
+    /// A `non_reentrant` message was called while a `non_reentrant` message of the
+    /// same contract was already being executed further up the call stack.
+    ReentrantCall = 2u32,
+    /// An `eoa_only` message was called by a contract instead of an externally owned
+    /// account.
+    CallerNotOrigin = 3u32,
 }
 
 /// The `Result` type for ink! messages.
 pub type MessageResult<T> = ::core::result::Result<T, LangError>;
 
+/// The return type of an ink! message which always reverts with the error `E`.
+///
+/// Messages returning `Revert<E>` have no success value: the dispatch reverts the
+/// transaction and returns the encoded error as revert data. This is useful for
+/// guard stubs, e.g. for messages of an upgradeable contract which are not supported
+/// anymore.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ::scale::Encode, ::scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub struct Revert<E>(pub E);
+
 /// The `Result` type for ink! constructors.
 pub type ConstructorResult<T> = ::core::result::Result<T, LangError>;
@@ -0,0 +1,324 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A value which is encrypted before it is written to the contract storage.
+//!
+//! This is the storage type of the fields of an ink! storage struct which are annotated
+//! with `#[ink(encrypted = C)]`.
+
+use core::{
+    fmt::{
+        Debug,
+        Formatter,
+    },
+    marker::PhantomData,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+};
+use ink_prelude::vec::Vec;
+use scale::{
+    Decode,
+    DecodeAll,
+    Encode,
+    Error,
+    Input,
+    Output,
+};
+
+/// Encrypts and decrypts the encoded values of [`Encrypted`] storage fields.
+///
+/// # Note
+///
+/// The cipher runs inside of the contract. Keys must therefore be provided by the
+/// runtime, e.g. by a confidential runtime through a chain extension, rather than being
+/// part of the contract code.
+pub trait Cipher {
+    /// Returns the encrypted `plaintext`.
+    fn encrypt(plaintext: Vec<u8>) -> Vec<u8>;
+
+    /// Returns the decrypted `ciphertext`.
+    ///
+    /// # Errors
+    ///
+    /// If the `ciphertext` could not be decrypted.
+    fn decrypt(ciphertext: Vec<u8>) -> Result<Vec<u8>, Error>;
+}
+
+/// A value of type `V` which is encrypted with the cipher `C` before it is written to
+/// the contract storage.
+///
+/// The value is encoded and encrypted as a whole, hence `V` must be a
+/// [`Packed`](crate::traits::Packed) type.
+///
+/// # Example
+///
+/// ```rust
+/// #[ink::contract]
+/// mod vault {
+///     use ink::{
+///         prelude::vec::Vec,
+///         storage::Cipher,
+///     };
+///
+///     /// A trivial cipher, do not use this in production.
+///     pub struct XorCipher;
+///
+///     impl Cipher for XorCipher {
+///         fn encrypt(mut plaintext: Vec<u8>) -> Vec<u8> {
+///             plaintext.iter_mut().for_each(|byte| *byte ^= 0x5A);
+///             plaintext
+///         }
+///
+///         fn decrypt(ciphertext: Vec<u8>) -> Result<Vec<u8>, ink::scale::Error> {
+///             Ok(Self::encrypt(ciphertext))
+///         }
+///     }
+///
+///     #[ink(storage)]
+///     pub struct Vault {
+///         #[ink(encrypted = XorCipher)]
+///         secret: u32,
+///     }
+///
+///     impl Vault {
+///         #[ink(constructor)]
+///         pub fn new(secret: u32) -> Self {
+///             Self {
+///                 secret: secret.into(),
+///             }
+///         }
+///
+///         #[ink(message)]
+///         pub fn secret(&self) -> u32 {
+///             *self.secret
+///         }
+///     }
+/// }
+/// ```
+pub struct Encrypted<V, C> {
+    value: V,
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<V, C> Encrypted<V, C> {
+    /// Creates a new encrypted storage value.
+    pub const fn new(value: V) -> Self {
+        Self {
+            value,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the decrypted value.
+    pub fn into_inner(self) -> V {
+        self.value
+    }
+}
+
+impl<V, C> From<V> for Encrypted<V, C> {
+    fn from(value: V) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<V, C> Deref for Encrypted<V, C> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<V, C> DerefMut for Encrypted<V, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<V, C> Default for Encrypted<V, C>
+where
+    V: Default,
+{
+    fn default() -> Self {
+        Self::new(V::default())
+    }
+}
+
+impl<V, C> Clone for Encrypted<V, C>
+where
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<V, C> PartialEq for Encrypted<V, C>
+where
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<V, C> Eq for Encrypted<V, C> where V: Eq {}
+
+impl<V, C> Debug for Encrypted<V, C>
+where
+    V: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Encrypted").field(&self.value).finish()
+    }
+}
+
+impl<V, C> Encode for Encrypted<V, C>
+where
+    V: Encode,
+    C: Cipher,
+{
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        C::encrypt(self.value.encode()).encode_to(dest)
+    }
+}
+
+impl<V, C> Decode for Encrypted<V, C>
+where
+    V: Decode,
+    C: Cipher,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let plaintext = C::decrypt(Vec::<u8>::decode(input)?)?;
+        V::decode_all(&mut &plaintext[..]).map(Self::new)
+    }
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use crate::traits::StorageLayout;
+    use ink_metadata::layout::{
+        Layout,
+        LayoutKey,
+        LeafLayout,
+    };
+    use ink_primitives::Key;
+    use scale_info::{
+        build::Fields,
+        meta_type,
+        Path,
+        Type,
+        TypeInfo,
+        TypeParameter,
+    };
+
+    impl<V, C> TypeInfo for Encrypted<V, C>
+    where
+        V: TypeInfo + 'static,
+        C: 'static,
+    {
+        type Identity = Self;
+
+        fn type_info() -> Type {
+            Type::builder()
+                .path(Path::new("Encrypted", module_path!()))
+                .type_params([TypeParameter::new("V", Some(meta_type::<V>()))])
+                .docs(&["The encoded value, encrypted with its cipher."])
+                .composite(Fields::unnamed().field(|f| f.ty::<Vec<u8>>()))
+        }
+    }
+
+    impl<V, C> StorageLayout for Encrypted<V, C>
+    where
+        V: TypeInfo + 'static,
+        C: 'static,
+    {
+        fn layout(key: &Key) -> Layout {
+            Layout::Leaf(LeafLayout::from_key::<Self>(LayoutKey::from(key)))
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A trivial cipher, do not use this in production.
+    struct XorCipher;
+
+    impl Cipher for XorCipher {
+        fn encrypt(mut plaintext: Vec<u8>) -> Vec<u8> {
+            plaintext.iter_mut().for_each(|byte| *byte ^= 0x5A);
+            plaintext
+        }
+
+        fn decrypt(ciphertext: Vec<u8>) -> Result<Vec<u8>, Error> {
+            Ok(Self::encrypt(ciphertext))
+        }
+    }
+
+    #[test]
+    fn stored_bytes_are_encrypted() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let secret = (42u128, true);
+            let value: Encrypted<_, XorCipher> = Encrypted::new(secret);
+            ink_env::set_contract_storage(&0x42u32, &value);
+
+            let stored = ink_env::get_contract_storage::<_, Vec<u8>>(&0x42u32)
+                .unwrap()
+                .unwrap();
+            assert_ne!(stored, secret.encode());
+            assert_eq!(stored, XorCipher::encrypt(secret.encode()));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn encrypted_value_round_trips() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let value: Encrypted<_, XorCipher> = Encrypted::new((42u128, true));
+            ink_env::set_contract_storage(&0x42u32, &value);
+
+            let loaded =
+                ink_env::get_contract_storage::<_, Encrypted<_, XorCipher>>(&0x42u32)
+                    .unwrap();
+            assert_eq!(loaded, Some(value));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn failed_decryption_is_a_decoding_error() {
+        struct FailingCipher;
+
+        impl Cipher for FailingCipher {
+            fn encrypt(plaintext: Vec<u8>) -> Vec<u8> {
+                plaintext
+            }
+
+            fn decrypt(_: Vec<u8>) -> Result<Vec<u8>, Error> {
+                Err("decryption failed".into())
+            }
+        }
+
+        let encoded = Encrypted::<u32, FailingCipher>::new(1).encode();
+        assert!(Encrypted::<u32, FailingCipher>::decode(&mut &encoded[..]).is_err());
+    }
+}
@@ -0,0 +1,467 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A mapping with a bloom filter companion, built on top of [Mapping].
+//!
+//! # Note
+//!
+//! This mapping doesn't actually "own" any data.
+//! Instead it is just a simple wrapper around the contract storage facilities.
+
+use core::cell::Cell;
+use ink_env::hash::{
+    Blake2x256,
+    HashOutput,
+};
+use ink_primitives::Key;
+use ink_storage_traits::{
+    AutoKey,
+    Packed,
+    Storable,
+    StorableHint,
+    StorageKey,
+};
+use scale::Encode;
+
+use crate::{
+    Lazy,
+    Mapping,
+};
+
+/// The size of the bloom filter in bytes.
+const BLOOM_FILTER_BYTES: usize = 256;
+
+/// The number of bits of the bloom filter.
+const BLOOM_FILTER_BITS: usize = BLOOM_FILTER_BYTES * 8;
+
+/// The number of bits set in the bloom filter for every inserted key.
+const BLOOM_FILTER_HASHES: usize = 3;
+
+/// The bits of the bloom filter.
+type BloomFilter = [u8; BLOOM_FILTER_BYTES];
+
+/// A [Mapping] accompanied by an in-storage bloom filter over its keys.
+///
+/// The bloom filter allows to cheaply rule out keys which were never inserted,
+/// without reading the storage cell of their value.
+///
+/// # Important
+///
+/// [BloomMapping] requires its own pre-defined storage key where to store values. By
+/// default, it is automatically calculated using [`AutoKey`](crate::traits::AutoKey)
+/// during compilation. However, anyone can specify a storage key using
+/// [`ManualKey`](crate::traits::ManualKey).
+///
+/// # False Positives
+///
+/// [`BloomMapping::might_contain`] never reports a key which was inserted as absent.
+/// However, it may report a key which was never inserted as present. The probability
+/// of such a false positive grows with the number of inserted keys.
+///
+/// Removing a key does not clear its bits from the bloom filter, so removed keys keep
+/// being reported as possibly present.
+///
+/// Hence, the bloom filter pays off for contracts doing many lookups of keys which are
+/// probably absent. For mappings with many entries or mostly successful lookups, a plain
+/// [Mapping] is preferable, as every insert also has to write the bloom filter.
+///
+/// # Storage Layout
+///
+/// At given [StorageKey] `K`, the bits of the bloom filter are stored.
+/// Each value is then stored under a combination of the [BloomMapping]
+/// key `K` and its key, exactly like in a [Mapping].
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct BloomMapping<K, V: Packed, KeyType: StorageKey = AutoKey> {
+    /// The bits of the bloom filter, only accessed via `fn get_filter()` and
+    /// `fn set_filter()`.
+    filter: Lazy<BloomFilter, KeyType>,
+    /// Caches `filter`, which only changes upon inserting a key.
+    #[cfg_attr(feature = "std", codec(skip))]
+    filter_cached: CachedFilter,
+    /// We use a [Mapping] to store all values.
+    entries: Mapping<K, V, KeyType>,
+}
+
+#[derive(Debug)]
+struct CachedFilter(Cell<Option<BloomFilter>>);
+
+impl<K, V, KeyType> Default for BloomMapping<K, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, KeyType> Storable for BloomMapping<K, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    #[inline]
+    fn encode<T: scale::Output + ?Sized>(&self, _dest: &mut T) {}
+
+    #[inline]
+    fn decode<I: scale::Input>(_input: &mut I) -> Result<Self, scale::Error> {
+        Ok(Default::default())
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        0
+    }
+}
+
+impl<K, V, Key, InnerKey> StorableHint<Key> for BloomMapping<K, V, InnerKey>
+where
+    V: Packed,
+    Key: StorageKey,
+    InnerKey: StorageKey,
+{
+    type Type = BloomMapping<K, V, Key>;
+    type PreferredKey = InnerKey;
+}
+
+impl<K, V, KeyType> StorageKey for BloomMapping<K, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    const KEY: Key = KeyType::KEY;
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use crate::traits::StorageLayout;
+    use ink_metadata::layout::{
+        FieldLayout,
+        Layout,
+        StructLayout,
+    };
+
+    impl<K, V, KeyType> StorageLayout for BloomMapping<K, V, KeyType>
+    where
+        K: scale_info::TypeInfo + 'static,
+        V: Packed + StorageLayout + scale_info::TypeInfo + 'static,
+        KeyType: StorageKey + scale_info::TypeInfo + 'static,
+    {
+        fn layout(key: &Key) -> Layout {
+            Layout::Struct(StructLayout::new(
+                "BloomMapping",
+                [
+                    FieldLayout::new(
+                        "filter",
+                        <Lazy<BloomFilter, KeyType> as StorageLayout>::layout(key),
+                    ),
+                    FieldLayout::new(
+                        "entries",
+                        <Mapping<K, V, KeyType> as StorageLayout>::layout(key),
+                    ),
+                ],
+            ))
+        }
+    }
+};
+
+impl<K, V, KeyType> BloomMapping<K, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    /// Creates a new empty `BloomMapping`.
+    pub const fn new() -> Self {
+        Self {
+            filter: Lazy::new(),
+            filter_cached: CachedFilter(Cell::new(None)),
+            entries: Mapping::new(),
+        }
+    }
+}
+
+impl<K, V, KeyType> ::core::fmt::Debug for BloomMapping<K, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("BloomMapping")
+            .field("key", &KeyType::KEY)
+            .finish()
+    }
+}
+
+impl<K, V, KeyType> BloomMapping<K, V, KeyType>
+where
+    K: Encode,
+    V: Packed,
+    KeyType: StorageKey,
+{
+    /// Returns the bits of the bloom filter.
+    ///
+    /// The bloom filter is cached; subsequent calls (without inserting into the mapping)
+    /// won't trigger additional storage reads.
+    fn get_filter(&self) -> BloomFilter {
+        if let Some(filter) = self.filter_cached.0.get() {
+            return filter
+        }
+        let filter = self.filter.get().unwrap_or([0; BLOOM_FILTER_BYTES]);
+        self.filter_cached.0.set(Some(filter));
+        filter
+    }
+
+    /// Overwrite the bits of the bloom filter. Writes directly to contract storage.
+    fn set_filter(&mut self, filter: &BloomFilter) {
+        self.filter.set(filter);
+        self.filter_cached.0.set(Some(*filter));
+    }
+
+    /// Returns the positions of the bloom filter bits belonging to `key`.
+    fn bit_positions<Q>(key: &Q) -> [usize; BLOOM_FILTER_HASHES]
+    where
+        Q: scale::EncodeLike<K>,
+    {
+        let mut hash = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_encoded::<Blake2x256, _>(key, &mut hash);
+        core::array::from_fn(|i| {
+            let bits = u16::from_le_bytes([hash[2 * i], hash[2 * i + 1]]);
+            usize::from(bits) % BLOOM_FILTER_BITS
+        })
+    }
+
+    /// Returns `true` if all bits of `key` are set in the bloom filter.
+    fn filter_contains<Q>(&self, key: &Q) -> bool
+    where
+        Q: scale::EncodeLike<K>,
+    {
+        let filter = self.get_filter();
+        Self::bit_positions(key)
+            .into_iter()
+            .all(|bit| filter[bit / 8] & (1 << (bit % 8)) != 0)
+    }
+
+    /// Returns `false` if the given `key` was certainly never inserted.
+    ///
+    /// Returns `true` if the `key` might have been inserted. This may be a false
+    /// positive, see the [type level documentation](BloomMapping) for details.
+    ///
+    /// Never reads the storage cell of the value at `key`.
+    #[inline]
+    pub fn might_contain<Q>(&self, key: Q) -> bool
+    where
+        Q: scale::EncodeLike<K>,
+    {
+        self.filter_contains(&key)
+    }
+
+    /// Insert the given `value` to the contract storage.
+    ///
+    /// Returns the size in bytes of the pre-existing value at the specified key if any.
+    ///
+    /// # Panics
+    ///
+    /// Traps if encoding the `key` together with the `value` doesn't fit into the static
+    /// buffer.
+    #[inline]
+    pub fn insert<Q, R>(&mut self, key: Q, value: &R) -> Option<u32>
+    where
+        Q: scale::EncodeLike<K>,
+        R: Storable + scale::EncodeLike<V>,
+    {
+        let mut filter = self.get_filter();
+        let mut changed = false;
+        for bit in Self::bit_positions(&key) {
+            let mask = 1 << (bit % 8);
+            changed |= filter[bit / 8] & mask == 0;
+            filter[bit / 8] |= mask;
+        }
+        if changed {
+            self.set_filter(&filter);
+        }
+
+        self.entries.insert(key, value)
+    }
+
+    /// Get the `value` at `key` from the contract storage.
+    ///
+    /// Returns `None` if no `value` exists at the given `key`. Keys ruled out by the
+    /// bloom filter don't trigger a read of their storage cell.
+    ///
+    /// # Panics
+    ///
+    /// Traps if the the encoded `key` or `value` doesn't fit into the static buffer.
+    #[inline]
+    pub fn get<Q>(&self, key: Q) -> Option<V>
+    where
+        Q: scale::EncodeLike<K>,
+    {
+        if !self.filter_contains(&key) {
+            return None
+        }
+        self.entries.get(key)
+    }
+
+    /// Checks if a value is stored at the given `key` in the contract storage.
+    ///
+    /// Returns `false` if no `value` exists at the given `key`. Keys ruled out by the
+    /// bloom filter don't trigger a read of their storage cell.
+    #[inline]
+    pub fn contains<Q>(&self, key: Q) -> bool
+    where
+        Q: scale::EncodeLike<K>,
+    {
+        self.filter_contains(&key) && self.entries.contains(key)
+    }
+
+    /// Clears the value at `key` from storage.
+    ///
+    /// The bloom filter keeps reporting `key` as possibly present.
+    #[inline]
+    pub fn remove<Q>(&self, key: Q)
+    where
+        Q: scale::EncodeLike<K>,
+    {
+        self.entries.remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::ManualKey;
+
+    #[test]
+    fn empty_mapping_contains_nothing() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mapping: BloomMapping<u32, u8> = BloomMapping::new();
+
+            for key in 0..100u32 {
+                assert!(!mapping.might_contain(key));
+                assert_eq!(mapping.get(key), None);
+                assert!(!mapping.contains(key));
+            }
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn might_contain_never_false_negatives() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut mapping: BloomMapping<u32, u32> = BloomMapping::new();
+
+            for key in 0..200u32 {
+                mapping.insert(key, &(key * 2));
+            }
+
+            for key in 0..200u32 {
+                assert!(mapping.might_contain(key));
+                assert_eq!(mapping.get(key), Some(key * 2));
+            }
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn absent_keys_are_mostly_ruled_out() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut mapping: BloomMapping<u32, u8> = BloomMapping::new();
+
+            for key in 0..10u32 {
+                mapping.insert(key, &1);
+            }
+
+            let false_positives = (10..1010u32)
+                .filter(|key| mapping.might_contain(key))
+                .count();
+            assert!(false_positives < 10, "{false_positives} false positives");
+            for key in 10..1010u32 {
+                assert_eq!(mapping.get(key), None);
+            }
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn removed_keys_might_still_be_contained() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut mapping: BloomMapping<u8, u8> = BloomMapping::new();
+
+            mapping.insert(1, &2);
+            mapping.remove(1);
+
+            assert!(mapping.might_contain(1));
+            assert!(!mapping.contains(1));
+            assert_eq!(mapping.get(1), None);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn filter_is_persisted_in_storage() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut mapping: BloomMapping<u8, u8, ManualKey<123>> = BloomMapping::new();
+            mapping.insert(1, &2);
+
+            let other: BloomMapping<u8, u8, ManualKey<123>> = BloomMapping::new();
+            assert!(other.might_contain(1));
+            assert_eq!(other.get(1), Some(2));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn layout_covers_filter_and_entries() {
+        use crate::traits::StorageLayout;
+        use ink_metadata::layout::{
+            Layout,
+            ValidateLayout,
+        };
+
+        let layout = <BloomMapping<u32, u8, ManualKey<123>> as StorageLayout>::layout(&0);
+        assert!(ValidateLayout::validate(&layout).is_ok());
+
+        let Layout::Struct(layout) = &layout else {
+            panic!("expected a struct layout, got {layout:?}")
+        };
+        assert_eq!(*layout.name(), "BloomMapping");
+
+        let roots = layout
+            .fields()
+            .iter()
+            .map(|field| {
+                let Layout::Root(root) = field.layout() else {
+                    panic!("expected a root layout, got {:?}", field.layout())
+                };
+                (
+                    *field.name(),
+                    *root.root_key().key(),
+                    root.key_ty().is_some(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(roots, vec![("filter", 123, false), ("entries", 123, true)]);
+    }
+}
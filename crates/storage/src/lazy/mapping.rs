@@ -26,6 +26,7 @@ use crate::traits::{
     StorageKey,
 };
 use core::marker::PhantomData;
+use ink_prelude::vec::Vec;
 use ink_primitives::Key;
 use ink_storage_traits::Storable;
 use scale::{
@@ -180,6 +181,29 @@ where
         Ok(self.insert(key, value))
     }
 
+    /// Insert each of the given `(key, value)` `entries` to the contract storage.
+    ///
+    /// Entries are inserted in iteration order, hence a later entry overwrites an
+    /// earlier entry with the same key.
+    ///
+    /// This is a convenience over calling [`Mapping::insert`] for each entry, it still
+    /// issues one storage write per entry.
+    ///
+    /// # Panics
+    ///
+    /// Traps if encoding a `key` together with its `value` doesn't fit into the static
+    /// buffer.
+    pub fn insert_all<I, Q, R>(&mut self, entries: I)
+    where
+        I: IntoIterator<Item = (Q, R)>,
+        Q: scale::EncodeLike<K>,
+        R: Storable + scale::EncodeLike<V>,
+    {
+        for (key, value) in entries {
+            self.insert(key, &value);
+        }
+    }
+
     /// Get the `value` at `key` from the contract storage.
     ///
     /// Returns `None` if no `value` exists at the given `key`.
@@ -226,10 +250,34 @@ where
         self.get(key).map(Ok)
     }
 
+    /// Get the `value` at each of the given `keys` from the contract storage.
+    ///
+    /// The returned values are in the same order as the `keys`: the value at position
+    /// `i` is the value at the `i`-th key, or `None` if no value exists at that key.
+    /// Duplicate keys yield the same value once per occurrence.
+    ///
+    /// This is a convenience over calling [`Mapping::get`] for each key, it still issues
+    /// one storage read per key.
+    ///
+    /// # Panics
+    ///
+    /// Traps if the the encoded `key` or `value` doesn't fit into the static buffer.
+    pub fn get_many<I>(&self, keys: I) -> Vec<Option<V>>
+    where
+        I: IntoIterator,
+        I::Item: scale::EncodeLike<K>,
+    {
+        keys.into_iter().map(|key| self.get(key)).collect()
+    }
+
     /// Removes the `value` at `key`, returning the previous `value` at `key` from
     /// storage.
     ///
-    /// Returns `None` if no `value` exists at the given `key`.
+    /// Reading and clearing the `value` is done in a single storage operation, which is
+    /// cheaper than a [`Mapping::get`] followed by a [`Mapping::remove`].
+    ///
+    /// Returns `None` if no `value` exists at the given `key`, in which case nothing is
+    /// cleared.
     ///
     /// # Panics
     ///
@@ -308,6 +356,56 @@ where
         ink_env::contains_contract_storage(&(&KeyType::KEY, key)).is_some()
     }
 
+    /// Applies `f` to the `value` at `key` if it exists, otherwise inserts `default`.
+    ///
+    /// The `value` is read at most once and written back exactly once.
+    ///
+    /// # Panics
+    ///
+    /// Traps if the encoded `key` or `value` doesn't fit into the static buffer.
+    #[inline]
+    pub fn update_or_insert<Q, F>(&mut self, key: Q, default: V, f: F)
+    where
+        Q: scale::EncodeLike<K>,
+        F: FnOnce(&mut V),
+    {
+        let storage_key = &(&KeyType::KEY, key);
+        let value = match ink_env::get_contract_storage::<_, V>(storage_key)
+            .unwrap_or_else(|error| panic!("Failed to get value in Mapping: {error:?}"))
+        {
+            Some(mut value) => {
+                f(&mut value);
+                value
+            }
+            None => default,
+        };
+        ink_env::set_contract_storage(storage_key, &value);
+    }
+
+    /// Applies `f` to the `value` at `key`, or to the value built by `default` if there
+    /// is none, and writes the result back.
+    ///
+    /// Unlike [`Mapping::update_or_insert`], `f` is applied to the default value as well.
+    /// This makes read-modify-write updates such as incrementing a counter a single call.
+    ///
+    /// # Panics
+    ///
+    /// Traps if the encoded `key` or `value` doesn't fit into the static buffer.
+    #[inline]
+    pub fn entry<Q, D, F>(&mut self, key: Q, default: D, f: F)
+    where
+        Q: scale::EncodeLike<K>,
+        D: FnOnce() -> V,
+        F: FnOnce(&mut V),
+    {
+        let storage_key = &(&KeyType::KEY, key);
+        let mut value = ink_env::get_contract_storage::<_, V>(storage_key)
+            .unwrap_or_else(|error| panic!("Failed to get value in Mapping: {error:?}"))
+            .unwrap_or_else(default);
+        f(&mut value);
+        ink_env::set_contract_storage(storage_key, &value);
+    }
+
     /// Clears the value at `key` from storage.
     #[inline]
     pub fn remove<Q>(&self, key: Q)
@@ -355,6 +453,87 @@ where
     const KEY: Key = KeyType::KEY;
 }
 
+#[cfg(feature = "std")]
+impl<K, V, KeyType> Mapping<K, V, KeyType>
+where
+    K: Encode + scale::Decode + Ord,
+    V: Packed,
+    KeyType: StorageKey,
+{
+    /// Returns all key-value pairs of the mapping, sorted by key.
+    ///
+    /// This is intended for differential testing, e.g. when fuzzing: take a snapshot
+    /// before and after an operation and compare them with [`MappingChange::diff`].
+    ///
+    /// # Note
+    ///
+    /// Only available in the off-chain environment, where the storage keys of the
+    /// executed contract are known.
+    pub fn snapshot(&self) -> Vec<(K, V)> {
+        let root_key = <Key as Encode>::encode(&KeyType::KEY);
+        let mut entries: Vec<(K, V)> = ink_env::test::contract_storage_keys()
+            .into_iter()
+            .filter_map(|storage_key| {
+                let mut encoded_key = storage_key.strip_prefix(&root_key[..])?;
+                let key = <K as scale::DecodeAll>::decode_all(&mut encoded_key).ok()?;
+                let value = self.get(&key)?;
+                Some((key, value))
+            })
+            .collect();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        entries
+    }
+}
+
+/// A change of a single entry between two [`Mapping::snapshot`]s.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MappingChange<K, V> {
+    /// An entry was inserted at the key.
+    Inserted(K, V),
+    /// The entry at the key was removed, holding the removed value.
+    Removed(K, V),
+    /// The value at the key was updated, holding the value before and after.
+    Updated(K, V, V),
+}
+
+#[cfg(feature = "std")]
+impl<K, V> MappingChange<K, V>
+where
+    K: Ord + Clone,
+    V: PartialEq + Clone,
+{
+    /// Returns the changes between the `before` and the `after` snapshot of a
+    /// [`Mapping`], sorted by key.
+    ///
+    /// Keys whose value did not change are not reported.
+    pub fn diff(before: &[(K, V)], after: &[(K, V)]) -> Vec<Self> {
+        use std::collections::{
+            BTreeMap,
+            BTreeSet,
+        };
+        let before: BTreeMap<&K, &V> = before.iter().map(|(k, v)| (k, v)).collect();
+        let after: BTreeMap<&K, &V> = after.iter().map(|(k, v)| (k, v)).collect();
+        let keys: BTreeSet<&K> = before.keys().chain(after.keys()).copied().collect();
+        keys.into_iter()
+            .filter_map(|key| {
+                match (before.get(key), after.get(key)) {
+                    (None, Some(&value)) => {
+                        Some(Self::Inserted(key.clone(), value.clone()))
+                    }
+                    (Some(&value), None) => {
+                        Some(Self::Removed(key.clone(), value.clone()))
+                    }
+                    (Some(&old), Some(&new)) if old != new => {
+                        Some(Self::Updated(key.clone(), old.clone(), new.clone()))
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
 #[cfg(feature = "std")]
 const _: () = {
     use crate::traits::StorageLayout;
@@ -371,10 +550,12 @@ const _: () = {
         KeyType: StorageKey + scale_info::TypeInfo + 'static,
     {
         fn layout(_: &Key) -> Layout {
-            Layout::Root(RootLayout::new(
+            Layout::Root(RootLayout::new_mapping(
                 LayoutKey::from(&KeyType::KEY),
                 <V as StorageLayout>::layout(&KeyType::KEY),
                 scale_info::meta_type::<Self>(),
+                scale_info::meta_type::<K>(),
+                scale_info::meta_type::<V>(),
             ))
         }
     }
@@ -422,6 +603,78 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn get_many_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut mapping: Mapping<u8, u8> = Mapping::new();
+            mapping.insert(1, &10);
+            mapping.insert(3, &30);
+
+            assert_eq!(
+                mapping.get_many([3, 2, 1, 3, 4]),
+                [Some(30), None, Some(10), Some(30), None]
+            );
+            assert!(mapping.get_many(core::iter::empty::<u8>()).is_empty());
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn insert_all_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut mapping: Mapping<u32, u128> = Mapping::new();
+
+            mapping.insert_all((0..100).map(|i| (i, u128::from(i) * 2)));
+
+            for i in 0..100 {
+                assert_eq!(mapping.get(i), Some(u128::from(i) * 2));
+            }
+            assert_eq!(mapping.get(100), None);
+
+            mapping.insert_all([(7, 1), (7, 2)]);
+            assert_eq!(mapping.get(7), Some(2));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn snapshot_diff_reports_changed_key() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut mapping: Mapping<u8, u8> = Mapping::new();
+            let mut other: Mapping<u8, u8, ManualKey<123>> = Mapping::new();
+            mapping.insert(1, &10);
+            mapping.insert(2, &20);
+            other.insert(3, &30);
+            let before = mapping.snapshot();
+            assert_eq!(before, [(1, 10), (2, 20)]);
+
+            mapping.insert(3, &30);
+            let after = mapping.snapshot();
+
+            assert_eq!(
+                MappingChange::diff(&before, &after),
+                [MappingChange::Inserted(3, 30)]
+            );
+
+            mapping.insert(1, &11);
+            mapping.remove(2);
+            assert_eq!(
+                MappingChange::diff(&after, &mapping.snapshot()),
+                [
+                    MappingChange::Updated(1, 10, 11),
+                    MappingChange::Removed(2, 20)
+                ]
+            );
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
     #[test]
     fn insert_and_take_work() {
         ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
@@ -446,6 +699,95 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn take_clears_value_in_a_single_storage_operation() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let contract = ink_env::test::callee::<ink_env::DefaultEnvironment>();
+            let storage_rw = || {
+                ink_env::test::get_contract_storage_rw::<ink_env::DefaultEnvironment>(
+                    &contract,
+                )
+            };
+            let mut mapping: Mapping<u8, u8> = Mapping::new();
+            mapping.insert(1, &2);
+
+            let (reads_before, writes_before) = storage_rw();
+            assert_eq!(mapping.take(1), Some(2));
+            let (reads_after, writes_after) = storage_rw();
+            assert_eq!(reads_after - reads_before, 0);
+            assert_eq!(writes_after - writes_before, 1);
+
+            assert_eq!(mapping.get(1), None);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn take_empty_value_does_not_clear() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let contract = ink_env::test::callee::<ink_env::DefaultEnvironment>();
+            let storage_rw = || {
+                ink_env::test::get_contract_storage_rw::<ink_env::DefaultEnvironment>(
+                    &contract,
+                )
+            };
+            let mapping: Mapping<u8, u8> = Mapping::new();
+
+            let (_, writes_before) = storage_rw();
+            assert_eq!(mapping.take(1), None);
+            let (_, writes_after) = storage_rw();
+            assert_eq!(writes_after, writes_before);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn update_or_insert_applies_f_if_present() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut mapping: Mapping<u8, u8> = Mapping::new();
+            mapping.insert(1, &2);
+
+            mapping.update_or_insert(1, 0, |value| *value += 40);
+            assert_eq!(mapping.get(1), Some(42));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn update_or_insert_inserts_default_if_absent() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut mapping: Mapping<u8, u8> = Mapping::new();
+
+            mapping.update_or_insert(1, 42, |_| panic!("must not be called"));
+            assert_eq!(mapping.get(1), Some(42));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn entry_increments_absent_and_present_values() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut mapping: Mapping<u8, u32> = Mapping::new();
+
+            mapping.entry(1, || 0, |counter| *counter += 1);
+            assert_eq!(mapping.get(1), Some(1));
+
+            mapping.entry(1, || panic!("must not be called"), |counter| *counter += 1);
+            assert_eq!(mapping.get(1), Some(2));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
     #[test]
     fn can_clear_entries() {
         ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
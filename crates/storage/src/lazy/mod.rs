@@ -18,12 +18,21 @@
 //! These low-level collections are not aware of the elements they manage thus
 //! extra care has to be taken when operating directly on them.
 
+mod bloom_mapping;
 mod mapping;
 mod vec;
 
 #[doc(inline)]
 pub use self::mapping::Mapping;
-pub use self::vec::StorageVec;
+#[cfg(feature = "std")]
+pub use self::mapping::MappingChange;
+pub use self::{
+    bloom_mapping::BloomMapping,
+    vec::{
+        Paginated,
+        StorageVec,
+    },
+};
 
 use crate::traits::{
     AutoKey,
@@ -200,6 +209,26 @@ where
 
         Ok(())
     }
+
+    /// Reads the `value` from the contract storage, or initializes it with the result
+    /// of `f` if no `value` exists.
+    ///
+    /// `f` is only called and the `value` is only written if no `value` exists yet.
+    ///
+    /// # Panics
+    ///
+    /// Traps if the encoded `value` doesn't fit into the static buffer.
+    pub fn get_or_insert_with<F>(&mut self, f: F) -> V
+    where
+        F: FnOnce() -> V,
+    {
+        if let Some(value) = self.get() {
+            return value;
+        }
+        let value = f();
+        self.set(&value);
+        value
+    }
 }
 
 impl<V, KeyType> Lazy<V, KeyType>
@@ -216,6 +245,24 @@ where
             _ => Default::default(),
         }
     }
+
+    /// Applies `f` to the `value` in the contract storage and writes the result back.
+    ///
+    /// If no `value` exists, `f` is applied to the default value for the storage type.
+    /// This takes a single storage read and a single storage write, an empty cell is
+    /// not decoded at all.
+    ///
+    /// # Panics
+    ///
+    /// Traps if the encoded `value` doesn't fit into the static buffer.
+    pub fn modify<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut V),
+    {
+        let mut value = self.get_or_default();
+        f(&mut value);
+        self.set(&value);
+    }
 }
 
 impl<V, KeyType> Storable for Lazy<V, KeyType>
@@ -319,6 +366,83 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn get_or_insert_with_initializes_only_once() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let contract = ink_env::test::callee::<ink_env::DefaultEnvironment>();
+            let storage_rw = || {
+                ink_env::test::get_contract_storage_rw::<ink_env::DefaultEnvironment>(
+                    &contract,
+                )
+            };
+            let mut storage: Lazy<u8> = Lazy::new();
+            let mut calls = 0;
+
+            assert_eq!(
+                storage.get_or_insert_with(|| {
+                    calls += 1;
+                    2
+                }),
+                2
+            );
+            assert_eq!(storage.get(), Some(2));
+
+            let (_, writes_before) = storage_rw();
+            assert_eq!(
+                storage.get_or_insert_with(|| {
+                    calls += 1;
+                    3
+                }),
+                2
+            );
+            let (_, writes_after) = storage_rw();
+            assert_eq!(writes_after, writes_before);
+            assert_eq!(calls, 1);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn modify_increments_from_empty() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let contract = ink_env::test::callee::<ink_env::DefaultEnvironment>();
+            let storage_rw = || {
+                ink_env::test::get_contract_storage_rw::<ink_env::DefaultEnvironment>(
+                    &contract,
+                )
+            };
+            let mut storage: Lazy<u64> = Lazy::new();
+
+            let (reads_before, writes_before) = storage_rw();
+            storage.modify(|value| *value += 1);
+            let (reads_after, writes_after) = storage_rw();
+
+            assert_eq!(reads_after - reads_before, 1);
+            assert_eq!(writes_after - writes_before, 1);
+            assert_eq!(storage.get(), Some(1));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn modify_increments_set_value() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut storage: Lazy<u64> = Lazy::new();
+            storage.set(&41);
+
+            storage.modify(|value| *value += 1);
+
+            assert_eq!(storage.get(), Some(42));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
     #[test]
     fn gets_returns_none_if_no_value_was_set() {
         ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
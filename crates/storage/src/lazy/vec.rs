@@ -20,6 +20,7 @@
 //! Instead it is just a simple wrapper around the contract storage facilities.
 
 use core::cell::Cell;
+use ink_prelude::vec::Vec;
 use ink_primitives::Key;
 use ink_storage_traits::{
     AutoKey,
@@ -36,6 +37,19 @@ use crate::{
     Mapping,
 };
 
+/// A page of elements, e.g. returned by [`StorageVec::page`].
+///
+/// Returning a page instead of the whole collection keeps the output of a message
+/// bounded, callers follow the `next` cursor to fetch the remaining elements.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Paginated<T> {
+    /// The elements of this page.
+    pub items: Vec<T>,
+    /// The cursor of the following page, or `None` if this is the last page.
+    pub next: Option<u32>,
+}
+
 /// A vector of values (elements) directly on contract storage.
 ///
 /// # Important
@@ -70,10 +84,10 @@ use crate::{
 ///
 /// # Caveats
 ///
-/// Iterators are not provided. [StorageVec] is expected to be used to
-/// store a lot elements, where iterating through the elements would be
-/// rather inefficient (naturally, it is still possible to manually
-/// iterate over the elements using a loop).
+/// [StorageVec] is expected to be used to store a lot elements, where
+/// iterating through the elements would be rather inefficient. The
+/// iterator returned by [`StorageVec::iter`] reads one element per step,
+/// so every element visited costs a storage read.
 ///
 /// For the same reason, operations which would require re-ordering
 /// stored elements are not supported. Examples include inserting and
@@ -373,6 +387,41 @@ where
         self.elements.try_get(index)
     }
 
+    /// Returns an iterator over the elements of the vector, from front to back.
+    ///
+    /// Elements are read from storage lazily, one at a time, as the iterator advances.
+    /// Use `.rev()` to iterate from back to front. Elements that were cleared from
+    /// storage are skipped.
+    ///
+    /// # Panics
+    ///
+    /// * If encoding an element exceeds the static buffer size.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = V> + '_ {
+        (0..self.len()).filter_map(move |index| self.get(index))
+    }
+
+    /// Returns the page of at most `limit` elements starting at index `start`.
+    ///
+    /// The returned [`Paginated::next`] is the index to pass as `start` to get the
+    /// following page, or `None` if this is the last page. Elements that were cleared
+    /// from storage are skipped, hence a page can contain less than `limit` elements
+    /// even if it is not the last one. A `limit` of zero yields an empty last page, so
+    /// that following `next` always terminates.
+    ///
+    /// # Panics
+    ///
+    /// * If decoding an element exceeds the static buffer size.
+    pub fn page(&self, start: u32, limit: u32) -> Paginated<V> {
+        let len = self.len();
+        let end = start.saturating_add(limit).min(len);
+        let items = (start.min(end)..end)
+            .filter_map(|index| self.get(index))
+            .collect();
+        let next = (limit > 0 && end < len).then_some(end);
+
+        Paginated { items, next }
+    }
+
     /// Set the `value` at given `index`.
     ///
     /// # Panics
@@ -418,6 +467,110 @@ where
         self.elements.try_insert(index, value)
     }
 
+    /// Removes the element at `index` from the vector and returns it.
+    ///
+    /// The removed element is replaced by the last element of the vector. This does
+    /// not preserve ordering, but is O(1): only the cell at `index`, the last cell and
+    /// the length are written, instead of shifting all elements after `index`.
+    ///
+    /// Removing the last element behaves like [`StorageVec::pop`].
+    ///
+    /// Returns `None` if `index` is out of bounds, leaving the vector unchanged.
+    /// Returns `None` as well if the element at `index` was already cleared from
+    /// storage; the vector still shrinks by one in this case.
+    ///
+    /// # Panics
+    ///
+    /// * If the value overgrows the static buffer size.
+    pub fn swap_remove(&mut self, index: u32) -> Option<V>
+    where
+        V: EncodeLike,
+    {
+        let len = self.len();
+        if index >= len {
+            return None;
+        }
+
+        let last = len.checked_sub(1).unwrap();
+        if index == last {
+            return self.pop();
+        }
+
+        let removed = self.elements.get(index);
+        match self.elements.take(last) {
+            Some(last_value) => {
+                self.elements.insert(index, &last_value);
+            }
+            None => self.elements.remove(index),
+        }
+        self.set_len(last);
+
+        removed
+    }
+
+    /// Removes up to `n` elements from the front of the vector and returns them in
+    /// order, like dequeuing them from a queue.
+    ///
+    /// If the vector holds fewer than `n` elements, all of them are removed. Elements
+    /// that were already cleared from storage are skipped in the returned `Vec`, the
+    /// vector still shrinks by their count.
+    ///
+    /// # Warning
+    ///
+    /// The remaining elements are moved to the front of the vector; complexity is
+    /// O(len). Use [`StorageVec::swap_remove`] or [`StorageVec::pop`] if the order of
+    /// the elements does not matter.
+    ///
+    /// # Panics
+    ///
+    /// * If the value overgrows the static buffer size.
+    pub fn take_front(&mut self, n: u32) -> Vec<V>
+    where
+        V: EncodeLike,
+    {
+        let len = self.len();
+        let n = n.min(len);
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let taken = (0..n).filter_map(|i| self.elements.take(i)).collect();
+        for i in n..len {
+            match self.elements.take(i) {
+                Some(value) => {
+                    self.elements.insert(i - n, &value);
+                }
+                None => self.elements.remove(i - n),
+            }
+        }
+        self.set_len(len - n);
+
+        taken
+    }
+
+    /// Shortens the vector to `len` elements, deleting the elements from `len` on from
+    /// storage.
+    ///
+    /// Has no effect if `len` is greater than or equal to the length of the vector, in
+    /// which case nothing is written to storage.
+    ///
+    /// # Warning
+    ///
+    /// This iterates through all deleted elements; complexity is O(n) in the number of
+    /// deleted elements. It might not be possible to truncate large vectors within a
+    /// single block!
+    pub fn truncate(&mut self, len: u32) {
+        let old_len = self.len();
+        if len >= old_len {
+            return;
+        }
+
+        for i in len..old_len {
+            self.elements.remove(i);
+        }
+        self.set_len(len);
+    }
+
     /// Delete all elements from storage.
     ///
     /// # Warning
@@ -425,10 +578,7 @@ where
     /// This iterates through all elements in the vector; complexity is O(n).
     /// It might not be possible to clear large vectors within a single block!
     pub fn clear(&mut self) {
-        for i in 0..self.len() {
-            self.elements.remove(i);
-        }
-        self.set_len(0);
+        self.truncate(0);
     }
 
     /// Clears the value of the element at `index`. It doesn't change the length of the
@@ -605,6 +755,62 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn truncate_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let contract = ink_env::test::callee::<ink_env::DefaultEnvironment>();
+            let writes = || {
+                ink_env::test::get_contract_storage_rw::<ink_env::DefaultEnvironment>(
+                    &contract,
+                )
+                .1
+            };
+            let mut array: StorageVec<u8> = (0..10).collect();
+
+            let writes_before = writes();
+            array.truncate(4);
+
+            // One write for each cleared cell and one for the length.
+            assert_eq!(writes() - writes_before, 10 - 4 + 1);
+            assert_eq!(array.len(), 4);
+            assert_eq!(array.iter().collect::<Vec<_>>(), [0, 1, 2, 3]);
+            assert_eq!((4..10).map(|i| array.get(i)).collect::<Vec<_>>(), [None; 6]);
+
+            array.push(&4);
+            assert_eq!(array.len(), 5);
+            assert_eq!(array.pop(), Some(4));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn truncate_to_len_or_more_is_a_no_op() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let contract = ink_env::test::callee::<ink_env::DefaultEnvironment>();
+            let writes = || {
+                ink_env::test::get_contract_storage_rw::<ink_env::DefaultEnvironment>(
+                    &contract,
+                )
+                .1
+            };
+            let mut array: StorageVec<u8> = (0..3).collect();
+
+            let writes_before = writes();
+            array.truncate(3);
+            array.truncate(u32::MAX);
+            StorageVec::<u8, ManualKey<1>>::new().truncate(0);
+
+            assert_eq!(writes(), writes_before);
+            assert_eq!(array.len(), 3);
+            assert_eq!(array.iter().collect::<Vec<_>>(), [0, 1, 2]);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
     #[test]
     fn clear_at_works() {
         ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
@@ -723,6 +929,242 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn iter_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let array: StorageVec<u32> = (0..100).collect();
+
+            assert_eq!(array.iter().count(), 100);
+            assert!(array.iter().eq(0..100));
+            assert!(array.iter().rev().eq((0..100).rev()));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn iter_skips_cleared_elements() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u8> = (0..4).collect();
+
+            array.clear_at(1);
+            assert_eq!(array.iter().collect::<Vec<_>>(), [0, 2, 3]);
+            assert_eq!(array.iter().rev().collect::<Vec<_>>(), [3, 2, 0]);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn swap_remove_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u8> = (0..5).collect();
+
+            assert_eq!(array.swap_remove(1), Some(1));
+            assert_eq!(array.len(), 4);
+            assert_eq!(array.get(1), Some(4));
+            assert_eq!(array.get(4), None);
+
+            assert_eq!(array.swap_remove(0), Some(0));
+            assert_eq!(array.len(), 3);
+            assert_eq!(
+                (0..array.len()).map(|i| array.get(i)).collect::<Vec<_>>(),
+                [Some(3), Some(4), Some(2)]
+            );
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn swap_remove_last_behaves_like_pop() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut popped: StorageVec<u8, ManualKey<1>> = (0..3).collect();
+            let mut swapped: StorageVec<u8, ManualKey<2>> = (0..3).collect();
+
+            for _ in 0..4 {
+                assert_eq!(
+                    swapped.swap_remove(swapped.len().saturating_sub(1)),
+                    popped.pop()
+                );
+                assert_eq!(swapped.len(), popped.len());
+                assert_eq!(swapped.peek(), popped.peek());
+            }
+            assert!(swapped.is_empty());
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn swap_remove_out_of_bounds_returns_none() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u8> = StorageVec::new();
+            assert_eq!(array.swap_remove(0), None);
+
+            (0..3).for_each(|i| array.push(&i));
+            assert_eq!(array.swap_remove(3), None);
+            assert_eq!(array.swap_remove(u32::MAX), None);
+            assert_eq!(array.len(), 3);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn swap_remove_of_cleared_element_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u8> = (0..3).collect();
+
+            array.clear_at(2);
+            assert_eq!(array.swap_remove(0), Some(0));
+            assert_eq!(array.len(), 2);
+            assert_eq!(array.get(0), None);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn swap_remove_writes_less_than_shifting() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let contract = ink_env::test::callee::<ink_env::DefaultEnvironment>();
+            let writes = || {
+                ink_env::test::get_contract_storage_rw::<ink_env::DefaultEnvironment>(
+                    &contract,
+                )
+                .1
+            };
+
+            // Remove the first element by shifting all following elements.
+            let mut shifted: StorageVec<u32, ManualKey<1>> = (0..10).collect();
+            let writes_before = writes();
+            for i in 0..shifted.len() - 1 {
+                let next = shifted.get(i + 1).unwrap();
+                shifted.set(i, &next);
+            }
+            shifted.pop();
+            let shift_writes = writes() - writes_before;
+
+            // Remove the first element by swapping in the last element.
+            let mut swapped: StorageVec<u32, ManualKey<2>> = (0..10).collect();
+            let writes_before = writes();
+            swapped.swap_remove(0);
+            let swap_writes = writes() - writes_before;
+
+            // One write for the removed cell, the last cell and the length each.
+            assert_eq!(swap_writes, 3);
+            assert!(swap_writes < shift_writes);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn page_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u32> = StorageVec::new();
+            (0..250).for_each(|i| array.push(&i));
+
+            let first = array.page(0, 100);
+            assert_eq!(first.items, (0..100).collect::<Vec<_>>());
+            assert_eq!(first.next, Some(100));
+
+            let second = array.page(first.next.unwrap(), 100);
+            assert_eq!(second.items, (100..200).collect::<Vec<_>>());
+            assert_eq!(second.next, Some(200));
+
+            let last = array.page(second.next.unwrap(), 100);
+            assert_eq!(last.items, (200..250).collect::<Vec<_>>());
+            assert_eq!(last.next, None);
+
+            assert_eq!(array.page(300, 100).items, Vec::<u32>::new());
+
+            let empty = array.page(100, 0);
+            assert_eq!(empty.items, Vec::<u32>::new());
+            assert_eq!(empty.next, None);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn take_front_less_than_len_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u8> = (0..5).collect();
+
+            assert_eq!(array.take_front(2), [0, 1]);
+            assert_eq!(array.len(), 3);
+            assert_eq!(
+                (0..5).map(|i| array.get(i)).collect::<Vec<_>>(),
+                [Some(2), Some(3), Some(4), None, None]
+            );
+
+            array.push(&5);
+            assert_eq!(array.take_front(1), [2]);
+            assert!(array.take_front(0).is_empty());
+            assert_eq!(array.pop(), Some(5));
+            assert_eq!(array.len(), 2);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn take_front_len_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u8> = (0..3).collect();
+
+            assert_eq!(array.take_front(3), [0, 1, 2]);
+            assert!(array.is_empty());
+            assert_eq!((0..3).map(|i| array.get(i)).collect::<Vec<_>>(), [None; 3]);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn take_front_more_than_len_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u8> = (0..3).collect();
+
+            assert_eq!(array.take_front(u32::MAX), [0, 1, 2]);
+            assert!(array.is_empty());
+            assert!(array.take_front(1).is_empty());
+            assert!(StorageVec::<u8>::new().take_front(1).is_empty());
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn take_front_skips_cleared_elements() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u8> = (0..4).collect();
+
+            array.clear_at(1);
+            array.clear_at(3);
+            assert_eq!(array.take_front(2), [0]);
+            assert_eq!(array.len(), 2);
+            assert_eq!(array.get(0), Some(2));
+            assert_eq!(array.get(1), None);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
     #[test]
     #[should_panic(
         expected = "assertion failed: cached_len.is_none() || self.len.get() == cached_len"
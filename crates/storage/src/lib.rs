@@ -50,9 +50,23 @@ pub use ink_storage_traits as traits;
 #[allow(dead_code)]
 pub(crate) mod lazy;
 
+mod encrypted;
+
+#[doc(inline)]
+pub use self::encrypted::{
+    Cipher,
+    Encrypted,
+};
+
 #[doc(inline)]
 pub use self::lazy::{
+    BloomMapping,
     Lazy,
     Mapping,
+    Paginated,
     StorageVec,
 };
+
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use self::lazy::MappingChange;
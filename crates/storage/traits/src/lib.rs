@@ -33,6 +33,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod impls;
+mod max_encoded_len;
 mod storage;
 
 #[cfg(feature = "std")]
@@ -46,6 +47,7 @@ pub use self::{
         ManualKey,
         ResolverKey,
     },
+    max_encoded_len::ConstMaxEncodedLen,
     storage::{
         decode_all,
         AutoStorableHint,
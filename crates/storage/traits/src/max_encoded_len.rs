@@ -0,0 +1,148 @@
+// Copyright (C) Use Ink (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::marker::PhantomData;
+use ink_prelude::boxed::Box;
+use ink_primitives::{
+    AccountId,
+    Hash,
+};
+use scale::Compact;
+
+/// The maximum length of the SCALE encoding of a type, known at compile time.
+///
+/// This is the `const` counterpart of [`scale::MaxEncodedLen`], which allows to bound
+/// the encoded length of e.g. the values of a `Mapping` in a `const` context. It can be
+/// derived for `struct` and `enum` types whose fields implement it.
+pub trait ConstMaxEncodedLen {
+    /// The upper bound of the length of the SCALE encoding of `Self` in bytes.
+    const MAX_ENCODED_LEN: usize;
+}
+
+macro_rules! impl_fixed_len {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl ConstMaxEncodedLen for $ty {
+                const MAX_ENCODED_LEN: usize = core::mem::size_of::<$ty>();
+            }
+        )*
+    };
+}
+impl_fixed_len!(bool, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl ConstMaxEncodedLen for AccountId {
+    const MAX_ENCODED_LEN: usize = 32;
+}
+
+impl ConstMaxEncodedLen for Hash {
+    const MAX_ENCODED_LEN: usize = 32;
+}
+
+macro_rules! impl_compact_len {
+    ( $( $ty:ty => $len:literal ),* $(,)? ) => {
+        $(
+            impl ConstMaxEncodedLen for Compact<$ty> {
+                const MAX_ENCODED_LEN: usize = $len;
+            }
+        )*
+    };
+}
+impl_compact_len!(u8 => 2, u16 => 4, u32 => 5, u64 => 9, u128 => 17);
+
+impl<T> ConstMaxEncodedLen for PhantomData<T> {
+    const MAX_ENCODED_LEN: usize = 0;
+}
+
+impl<T: ConstMaxEncodedLen, const N: usize> ConstMaxEncodedLen for [T; N] {
+    const MAX_ENCODED_LEN: usize = T::MAX_ENCODED_LEN.saturating_mul(N);
+}
+
+impl<T: ConstMaxEncodedLen> ConstMaxEncodedLen for Box<T> {
+    const MAX_ENCODED_LEN: usize = T::MAX_ENCODED_LEN;
+}
+
+impl<T: ConstMaxEncodedLen> ConstMaxEncodedLen for Option<T> {
+    const MAX_ENCODED_LEN: usize = T::MAX_ENCODED_LEN.saturating_add(1);
+}
+
+impl<T: ConstMaxEncodedLen, E: ConstMaxEncodedLen> ConstMaxEncodedLen for Result<T, E> {
+    const MAX_ENCODED_LEN: usize = if T::MAX_ENCODED_LEN > E::MAX_ENCODED_LEN {
+        T::MAX_ENCODED_LEN.saturating_add(1)
+    } else {
+        E::MAX_ENCODED_LEN.saturating_add(1)
+    };
+}
+
+macro_rules! impl_tuple_len {
+    ( $( $ty:ident ),* ) => {
+        impl<$( $ty: ConstMaxEncodedLen ),*> ConstMaxEncodedLen for ( $( $ty, )* ) {
+            const MAX_ENCODED_LEN: usize = 0usize $( .saturating_add($ty::MAX_ENCODED_LEN) )*;
+        }
+    };
+}
+impl_tuple_len!();
+impl_tuple_len!(A);
+impl_tuple_len!(A, B);
+impl_tuple_len!(A, B, C);
+impl_tuple_len!(A, B, C, D);
+impl_tuple_len!(A, B, C, D, E);
+impl_tuple_len!(A, B, C, D, E, F);
+impl_tuple_len!(A, B, C, D, E, F, G);
+impl_tuple_len!(A, B, C, D, E, F, G, H);
+impl_tuple_len!(A, B, C, D, E, F, G, H, I);
+impl_tuple_len!(A, B, C, D, E, F, G, H, I, J);
+impl_tuple_len!(A, B, C, D, E, F, G, H, I, J, K);
+impl_tuple_len!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::MaxEncodedLen;
+
+    macro_rules! assert_matches_scale {
+        ( $( $ty:ty ),* $(,)? ) => {
+            $(
+                assert_eq!(
+                    <$ty as ConstMaxEncodedLen>::MAX_ENCODED_LEN,
+                    <$ty as MaxEncodedLen>::max_encoded_len(),
+                    "`{}`",
+                    stringify!($ty),
+                );
+            )*
+        };
+    }
+
+    #[test]
+    fn matches_scale_max_encoded_len() {
+        assert_matches_scale!(
+            bool,
+            u8,
+            i128,
+            Compact<u8>,
+            Compact<u16>,
+            Compact<u32>,
+            Compact<u64>,
+            Compact<u128>,
+            PhantomData<u32>,
+            [u16; 3],
+            Box<u32>,
+            Option<u64>,
+            Result<u8, [u8; 4]>,
+            (),
+            (u8, Option<u32>, [u64; 2]),
+            AccountId,
+            Hash,
+        );
+    }
+}
@@ -0,0 +1,89 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod call_depth {
+    use ink::env::{
+        call::{
+            build_call,
+            ExecutionInput,
+            Selector,
+        },
+        CallFlags,
+        ReturnErrorCode,
+    };
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct CallDepth {}
+
+    impl CallDepth {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Calls this message of the contract itself, until the call depth limit of the
+        /// runtime is reached.
+        ///
+        /// `depth` is the call depth of this call. Returns the deepest call depth at
+        /// which a call returned without trapping.
+        #[ink(message)]
+        pub fn recurse(&self, depth: u32) -> u32 {
+            let result = build_call::<Environment>()
+                .call(self.env().account_id())
+                .call_flags(CallFlags::ALLOW_REENTRY)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("recurse")))
+                        .push_arg(depth.checked_add(1).unwrap()),
+                )
+                .returns::<u32>()
+                .try_invoke();
+            match result {
+                Ok(reached) => reached.expect("the nested call must be dispatched"),
+                // The nested call trapped, because it exceeded the call depth limit.
+                Err(ink::env::Error::ReturnError(ReturnErrorCode::CalleeTrapped)) => {
+                    depth
+                }
+                Err(err) => panic!("the nested call failed with {err:?}"),
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn recursion_stops_at_call_depth_limit(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = CallDepthRef::new();
+            let contract = client
+                .instantiate("call-depth", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("call-depth instantiate failed");
+            let call_builder = contract.call_builder::<CallDepth>();
+
+            // when
+            let call_res = client
+                .call(&ink_e2e::alice(), &call_builder.recurse(1))
+                .submit()
+                .await
+                .expect("the call must not fail at the call depth limit");
+
+            // then
+            let reached = call_res
+                .message_result()
+                .expect("Calling `recurse` failed to dispatch");
+            assert!(reached > 1, "the contract must be able to call itself");
+            call_res.assert_recursion_depth(&contract.account_id, reached as usize);
+
+            Ok(())
+        }
+    }
+}
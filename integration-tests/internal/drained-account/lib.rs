@@ -0,0 +1,66 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod drained_account {
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct DrainedAccount {
+        value: u32,
+    }
+
+    impl DrainedAccount {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Increments the stored value by one.
+        #[ink(message)]
+        pub fn inc(&mut self) {
+            self.value = self.value.checked_add(1).unwrap();
+        }
+
+        /// Returns the stored value.
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            self.value
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn call_from_drained_account_fails(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = DrainedAccountRef::new();
+            let contract = client
+                .instantiate("drained-account", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("drained-account instantiate failed");
+            let mut call_builder = contract.call_builder::<DrainedAccount>();
+
+            // when
+            client
+                .with_drained_account(&ink_e2e::alice(), &call_builder.inc())
+                .await
+                .expect("dry-running `inc` failed");
+
+            // then
+            let get = client
+                .call(&ink_e2e::alice(), &call_builder.get())
+                .dry_run()
+                .await?;
+            assert_eq!(get.return_value(), 0, "the rejected call changed the state");
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,101 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod dry_run_gas {
+    use ink::storage::Mapping;
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct DryRunGas {
+        values: Mapping<u32, u64>,
+    }
+
+    impl DryRunGas {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Writes `n` storage cells and returns the sum of their values.
+        ///
+        /// The gas required by this message grows with `n`.
+        #[ink(message)]
+        pub fn heavy(&mut self, n: u32) -> u64 {
+            let mut sum = 0u64;
+            for i in 0..n {
+                let value = self
+                    .values
+                    .get(i)
+                    .unwrap_or_default()
+                    .wrapping_add(u64::from(i));
+                self.values.insert(i, &value);
+                sum = sum.wrapping_add(value);
+            }
+            sum
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn heavy_message_succeeds_with_gas_from_dry_run<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = DryRunGasRef::new();
+            let contract = client
+                .instantiate("dry-run-gas", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("dry-run-gas instantiate failed");
+            let mut call_builder = contract.call_builder::<DryRunGas>();
+
+            // when
+            let call_res = client
+                .call(&ink_e2e::alice(), &call_builder.heavy(100))
+                .gas_limit_from_dry_run()
+                .submit()
+                .await
+                .expect("the call must not run out of gas");
+
+            // then
+            let expected: u64 = (0..100).sum();
+            assert_eq!(call_res.return_value(), expected);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn margin_of_gas_from_dry_run_can_be_overridden<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = DryRunGasRef::new();
+            let contract = client
+                .instantiate("dry-run-gas", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("dry-run-gas instantiate failed");
+            let mut call_builder = contract.call_builder::<DryRunGas>();
+
+            // when
+            let call_res = client
+                .call(&ink_e2e::alice(), &call_builder.heavy(50))
+                .gas_limit_from_dry_run_with_margin(50)
+                .submit()
+                .await
+                .expect("the call must not run out of gas");
+
+            // then
+            let expected: u64 = (0..50).sum();
+            assert_eq!(call_res.return_value(), expected);
+
+            Ok(())
+        }
+    }
+}
@@ -7,6 +7,10 @@ pub mod flipper {
         value: bool,
     }
 
+    #[derive(Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub struct FlipperError;
+
     impl Flipper {
         /// Creates a new flipper smart contract initialized with the given value.
         #[ink(constructor)]
@@ -26,6 +30,25 @@ pub mod flipper {
             self.value = !self.value;
         }
 
+        /// Flips the current value of the Flipper's boolean if the block timestamp has
+        /// not yet reached the `deadline`.
+        #[ink(message)]
+        pub fn flip_before(&mut self, deadline: Timestamp) -> Result<(), FlipperError> {
+            if self.env().block_timestamp() >= deadline {
+                return Err(FlipperError)
+            }
+            self.flip();
+            Ok(())
+        }
+
+        /// Flips the current value of the Flipper's boolean and then returns an error,
+        /// which reverts the call and discards the flip.
+        #[ink(message)]
+        pub fn flip_then_fail(&mut self) -> Result<(), FlipperError> {
+            self.flip();
+            Err(FlipperError)
+        }
+
         /// Returns the current value of the Flipper's boolean.
         #[ink(message)]
         pub fn get(&self) -> bool {
@@ -37,6 +60,26 @@ pub mod flipper {
         pub fn get_contract_balance(&self) -> Balance {
             self.env().balance()
         }
+
+        /// Panics unconditionally, which traps the contract without any return data.
+        #[ink(message)]
+        pub fn trap(&self) {
+            panic!("trapped on purpose")
+        }
+
+        /// Returns an error unconditionally, which reverts the call with the encoded
+        /// error as return data.
+        #[ink(message)]
+        pub fn revert(&self) -> Result<(), FlipperError> {
+            Err(FlipperError)
+        }
+
+        /// Reverts unconditionally with the encoded error as return data, e.g. as a
+        /// guard stub for a message which is not supported anymore.
+        #[ink(message)]
+        pub fn guard(&self) -> ink::Revert<FlipperError> {
+            ink::Revert(FlipperError)
+        }
     }
 
     #[cfg(all(test, feature = "e2e-tests"))]
@@ -146,6 +189,481 @@ pub mod flipper {
             Ok(())
         }
 
+        /// Tests runtime call dry-run scenario:
+        /// - create an account with few funds
+        /// - dry-run a transfer of more funds than the account holds
+        /// - assert that the transfer fails with a decoded dispatch error
+        /// - assert that the account's balance did not change
+        #[ink_e2e::test(backend(runtime_only))]
+        async fn runtime_call_dry_run_reports_insufficient_funds() -> E2EResult<()> {
+            // given
+            let origin = client
+                .create_and_fund_account(&ink_e2e::alice(), 1_000_000_000_000)
+                .await;
+            let balance_before = client
+                .free_balance(origin.public_key().0.into())
+                .await
+                .expect("getting balance failed");
+
+            // when
+            let call_data = vec![
+                Value::unnamed_variant(
+                    "Id",
+                    [Value::from_bytes(ink_e2e::bob().public_key().0)],
+                ),
+                Value::u128(u128::MAX / 2),
+            ];
+            let dry_run = client
+                .runtime_call_dry_run(
+                    &origin,
+                    "Balances",
+                    "transfer_allow_death",
+                    call_data,
+                )
+                .await
+                .expect("runtime call dry-run failed");
+
+            // then
+            assert!(
+                matches!(
+                    dry_run.result,
+                    Err(ink_e2e::subxt::error::DispatchError::Token(
+                        ink_e2e::subxt::error::TokenError::FundsUnavailable
+                    ))
+                ),
+                "unexpected dry-run result: {:?}",
+                dry_run.result
+            );
+            assert!(dry_run.weight.ref_time() > 0);
+
+            let balance_after = client
+                .free_balance(origin.public_key().0.into())
+                .await
+                .expect("getting balance failed");
+            assert_eq!(balance_before, balance_after);
+
+            Ok(())
+        }
+
+        /// Tests the classification of failed calls:
+        /// - deploy the flipper contract
+        /// - dry-run a message which panics
+        /// - dry-run a message which returns an `Err`
+        /// - assert that the first one is a bare trap without data and the second one
+        ///   reverted with the encoded error
+        #[ink_e2e::test(backend(runtime_only))]
+        async fn revert_data_is_classified<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let contract = client
+                .instantiate(
+                    "e2e-runtime-only-backend",
+                    &ink_e2e::alice(),
+                    &mut FlipperRef::new(false),
+                )
+                .submit()
+                .await
+                .expect("deploy failed");
+            let call_builder = contract.call_builder::<Flipper>();
+
+            // when
+            let trap_res = client
+                .call(&ink_e2e::alice(), &call_builder.trap())
+                .dry_run()
+                .await?;
+            let revert_res = client
+                .call(&ink_e2e::alice(), &call_builder.revert())
+                .dry_run()
+                .await?;
+
+            // then
+            assert!(trap_res.is_bare_trap());
+            assert_eq!(trap_res.revert_data(), None);
+
+            assert!(!revert_res.is_bare_trap());
+            let revert_data = revert_res
+                .revert_data()
+                .expect("call must revert with data");
+            let decoded: ink::MessageResult<Result<(), FlipperError>> =
+                ink::scale::Decode::decode(&mut &revert_data[..])
+                    .expect("revert data must decode");
+            assert_eq!(decoded, Ok(Err(FlipperError)));
+
+            Ok(())
+        }
+
+        /// Tests messages returning `ink::Revert`:
+        /// - deploy the flipper contract
+        /// - dry-run a message which returns `ink::Revert`
+        /// - assert that it reverted with the encoded error
+        #[ink_e2e::test(backend(runtime_only))]
+        async fn revert_marker_reverts_with_error<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let contract = client
+                .instantiate(
+                    "e2e-runtime-only-backend",
+                    &ink_e2e::alice(),
+                    &mut FlipperRef::new(false),
+                )
+                .submit()
+                .await
+                .expect("deploy failed");
+            let call_builder = contract.call_builder::<Flipper>();
+
+            // when
+            let guard_res = client
+                .call(&ink_e2e::alice(), &call_builder.guard())
+                .dry_run()
+                .await?;
+
+            // then
+            assert!(!guard_res.is_bare_trap());
+            let revert_data =
+                guard_res.revert_data().expect("call must revert with data");
+            let decoded: ink::MessageResult<ink::Revert<FlipperError>> =
+                ink::scale::Decode::decode(&mut &revert_data[..])
+                    .expect("revert data must decode");
+            assert_eq!(decoded, Ok(ink::Revert(FlipperError)));
+
+            Ok(())
+        }
+
+        /// Tests that returning an `Err` discards storage changes:
+        /// - deploy the flipper contract with initial value `false`
+        /// - submit a message which flips the flipper and then returns an `Err`
+        /// - assert that the value is still `false`
+        #[ink_e2e::test(backend(runtime_only))]
+        async fn err_discards_storage_changes<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let contract = client
+                .instantiate(
+                    "e2e-runtime-only-backend",
+                    &ink_e2e::alice(),
+                    &mut FlipperRef::new(false),
+                )
+                .submit()
+                .await
+                .expect("deploy failed");
+            let mut call_builder = contract.call_builder::<Flipper>();
+
+            // when
+            let flip_res = client
+                .call(&ink_e2e::bob(), &call_builder.flip_then_fail())
+                .submit()
+                .await
+                .expect("flip_then_fail failed")
+                .return_value();
+
+            // then
+            assert_eq!(flip_res, Err(FlipperError));
+
+            let get_res = client
+                .call(&ink_e2e::bob(), &call_builder.get())
+                .dry_run()
+                .await?;
+            assert!(!get_res.return_value());
+
+            Ok(())
+        }
+
+        /// Tests controlling the block time:
+        /// - deploy the flipper contract with initial value `false`
+        /// - set a block time of 12 seconds and a deadline two blocks ahead
+        /// - flip the flipper one block before the deadline, which succeeds
+        /// - try to flip the flipper at the deadline, which fails
+        #[ink_e2e::test(backend(runtime_only))]
+        async fn set_block_time_works<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            const BLOCK_TIME: u64 = 12_000;
+            let contract = client
+                .instantiate(
+                    "e2e-runtime-only-backend",
+                    &ink_e2e::alice(),
+                    &mut FlipperRef::new(false),
+                )
+                .submit()
+                .await
+                .expect("deploy failed");
+            let mut call_builder = contract.call_builder::<Flipper>();
+
+            client.set_block_time(BLOCK_TIME);
+            let now = client.build_blocks(1);
+            let deadline = now + 2 * BLOCK_TIME;
+
+            // when
+            assert_eq!(client.build_blocks(1), deadline - BLOCK_TIME);
+            let before_deadline = client
+                .call(&ink_e2e::bob(), &call_builder.flip_before(deadline))
+                .submit()
+                .await
+                .expect("flip_before failed")
+                .return_value();
+
+            assert_eq!(client.build_blocks(1), deadline);
+            let at_deadline = client
+                .call(&ink_e2e::bob(), &call_builder.flip_before(deadline))
+                .dry_run()
+                .await?
+                .return_value();
+
+            // then
+            assert_eq!(before_deadline, Ok(()));
+            assert_eq!(at_deadline, Err(FlipperError));
+
+            let get_res = client
+                .call(&ink_e2e::bob(), &call_builder.get())
+                .dry_run()
+                .await?;
+            assert!(get_res.return_value());
+
+            Ok(())
+        }
+
+        /// Tests rolling back the sandbox state:
+        /// - deploy the flipper contract with initial value `false`
+        /// - take a snapshot, then flip the flipper
+        /// - restore the snapshot
+        /// - assert that the value is `false` again
+        #[ink_e2e::test(backend(runtime_only))]
+        async fn restore_undoes_changes_since_snapshot() -> E2EResult<()> {
+            // given
+            let contract = client
+                .instantiate(
+                    "e2e-runtime-only-backend",
+                    &ink_e2e::alice(),
+                    &mut FlipperRef::new(false),
+                )
+                .submit()
+                .await
+                .expect("deploy failed");
+            let mut call_builder = contract.call_builder::<Flipper>();
+            let snapshot = client.snapshot();
+
+            let _flip_res = client
+                .call(&ink_e2e::bob(), &call_builder.flip())
+                .submit()
+                .await
+                .expect("flip failed");
+            let get_res = client
+                .call(&ink_e2e::bob(), &call_builder.get())
+                .dry_run()
+                .await?;
+            assert!(get_res.return_value());
+
+            // when
+            client.restore(snapshot);
+
+            // then
+            let get_res = client
+                .call(&ink_e2e::bob(), &call_builder.get())
+                .dry_run()
+                .await?;
+            assert!(!get_res.return_value());
+
+            Ok(())
+        }
+
+        /// Tests instantiating uploaded code multiple times:
+        /// - upload the flipper contract once
+        /// - instantiate two flippers with initial value `false` from the code hash
+        /// - flip the first flipper
+        /// - assert that the flippers have distinct addresses and independent values
+        #[ink_e2e::test(backend(runtime_only))]
+        async fn instantiate_from_code_hash_works<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let code = client
+                .upload("e2e-runtime-only-backend", &ink_e2e::alice())
+                .submit()
+                .await
+                .expect("upload failed");
+            let mut constructor = FlipperRef::new(false);
+            let first = client
+                .instantiate_from_code_hash(&code, &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("first instantiate failed");
+            let second = client
+                .instantiate_from_code_hash(&code, &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("second instantiate failed");
+            assert_ne!(first.account_id, second.account_id);
+
+            // when
+            let mut first_builder = first.call_builder::<Flipper>();
+            let _flip_res = client
+                .call(&ink_e2e::bob(), &first_builder.flip())
+                .submit()
+                .await
+                .expect("flip failed");
+
+            // then
+            let first_get = client
+                .call(&ink_e2e::bob(), &first_builder.get())
+                .dry_run()
+                .await?;
+            let second_get = client
+                .call(&ink_e2e::bob(), &second.call_builder::<Flipper>().get())
+                .dry_run()
+                .await?;
+            assert!(first_get.return_value());
+            assert!(!second_get.return_value());
+
+            Ok(())
+        }
+
+        /// Tests funding an arbitrary account:
+        /// - set the balance of `charlie` to a specific amount
+        /// - assert that the free balance of `charlie` is that amount
+        #[ink_e2e::test(backend(runtime_only))]
+        async fn set_balance_works<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let account: AccountId = ink_e2e::charlie().public_key().0.into();
+            let balance = 123_456_789_000_000;
+
+            // when
+            client
+                .set_balance(account, balance)
+                .await
+                .expect("setting balance failed");
+
+            // then
+            let free_balance = client
+                .free_balance(account)
+                .await
+                .expect("getting balance failed");
+            assert_eq!(free_balance, balance);
+
+            Ok(())
+        }
+
+        /// Tests deploying a pre-built contract artifact:
+        /// - copy the `.contract` bundle of the flipper to a fixture file
+        /// - deploy the flipper from the fixture with initial value `false`
+        /// - get the flipper's value
+        /// - assert that the value is `false`
+        #[ink_e2e::test(backend(runtime_only))]
+        async fn instantiate_from_artifact_works<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let bundle = ink_e2e::build_root_and_contract_dependencies()[0]
+                .with_extension("contract");
+            let fixture = std::env::temp_dir().join("flipper_fixture.contract");
+            std::fs::copy(bundle, &fixture)?;
+
+            // when
+            let contract = client
+                .instantiate_from_artifact(
+                    &fixture,
+                    &ink_e2e::alice(),
+                    &mut FlipperRef::new(false),
+                )
+                .submit()
+                .await
+                .expect("deploy failed");
+            let call_builder = contract.call_builder::<Flipper>();
+
+            // then
+            let get_res = client
+                .call(&ink_e2e::bob(), &call_builder.get())
+                .dry_run()
+                .await?;
+            assert!(!get_res.return_value());
+
+            Ok(())
+        }
+
+        /// Tests that a failing test with `dump_storage_on_failure` still fails:
+        /// - deploy the flipper contract with initial value `false`
+        /// - flip the flipper
+        /// - assert that the value is `false`, which fails and logs the flipper's storage
+        #[ink_e2e::test(backend(runtime_only), dump_storage_on_failure)]
+        #[should_panic(expected = "assertion failed")]
+        async fn dump_storage_on_failure_works<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let contract = client
+                .instantiate(
+                    "e2e-runtime-only-backend",
+                    &ink_e2e::alice(),
+                    &mut FlipperRef::new(false),
+                )
+                .submit()
+                .await
+                .expect("deploy failed");
+            let mut call_builder = contract.call_builder::<Flipper>();
+
+            // when
+            let _flip_res = client
+                .call(&ink_e2e::bob(), &call_builder.flip())
+                .submit()
+                .await
+                .expect("flip failed");
+
+            // then
+            let get_res = client
+                .call(&ink_e2e::bob(), &call_builder.get())
+                .dry_run()
+                .await?;
+            assert!(!get_res.return_value());
+
+            Ok(())
+        }
+
+        /// Tests the storage dump logged by `dump_storage_on_failure`:
+        /// - deploy the flipper contract with initial value `false`
+        /// - flip the flipper
+        /// - assert that the dump contains the flipped value
+        #[ink_e2e::test(backend(runtime_only))]
+        async fn storage_dump_works<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let contract = client
+                .instantiate(
+                    "e2e-runtime-only-backend",
+                    &ink_e2e::alice(),
+                    &mut FlipperRef::new(false),
+                )
+                .submit()
+                .await
+                .expect("deploy failed");
+            let mut call_builder = contract.call_builder::<Flipper>();
+
+            // when
+            let _flip_res = client
+                .call(&ink_e2e::bob(), &call_builder.flip())
+                .submit()
+                .await
+                .expect("flip failed");
+
+            // then
+            let dump =
+                ink_e2e::contracts_storage_dump::<ink::env::DefaultEnvironment, _>(
+                    &mut client,
+                )
+                .await;
+            assert!(
+                dump.starts_with("storage of contract \"e2e-runtime-only-backend\" at ")
+            );
+            assert!(dump.ends_with(":\n  root: 0x01"));
+
+            Ok(())
+        }
+
         /// Just instantiate a contract using non-default runtime.
         #[ink_e2e::test(backend(runtime_only(sandbox = ink_e2e::DefaultSandbox)))]
         async fn custom_runtime<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
@@ -0,0 +1,44 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::forwarder::{
+    Forwarder,
+    ForwarderRef,
+};
+
+/// A contract which forwards calls to the `set_admin` message of another contract.
+#[ink::contract]
+mod forwarder {
+    use ink::env::call::{
+        build_call,
+        ExecutionInput,
+        Selector,
+    };
+
+    #[ink(storage)]
+    pub struct Forwarder {
+        target: AccountId,
+    }
+
+    impl Forwarder {
+        #[ink(constructor)]
+        pub fn new(target: AccountId) -> Self {
+            Self { target }
+        }
+
+        /// Tries to call the `set_admin` message of the target contract.
+        ///
+        /// Returns `true` if the call was rejected because the caller is a contract.
+        #[ink(message)]
+        pub fn set_admin(&mut self, admin: AccountId) -> bool {
+            let result = build_call::<Environment>()
+                .call(self.target)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("set_admin")))
+                        .push_arg(admin),
+                )
+                .returns::<()>()
+                .try_invoke();
+            matches!(result, Ok(Err(ink::LangError::CallerNotOrigin)))
+        }
+    }
+}
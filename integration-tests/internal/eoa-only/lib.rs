@@ -0,0 +1,104 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod eoa_only {
+    #[ink(storage)]
+    pub struct EoaOnly {
+        admin: AccountId,
+    }
+
+    impl EoaOnly {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                admin: Self::env().caller(),
+            }
+        }
+
+        /// Hands over the admin role.
+        ///
+        /// Can only be called by externally owned accounts, calls from other
+        /// contracts are rejected.
+        #[ink(message, eoa_only)]
+        pub fn set_admin(&mut self, admin: AccountId) {
+            self.admin = admin;
+        }
+
+        /// Returns the current admin.
+        #[ink(message)]
+        pub fn admin(&self) -> AccountId {
+            self.admin
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use forwarder::ForwarderRef;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn contract_caller_is_rejected<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = EoaOnlyRef::new();
+            let contract = client
+                .instantiate("eoa-only", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("eoa-only instantiate failed");
+            let mut call_builder = contract.call_builder::<EoaOnly>();
+
+            let mut constructor = ForwarderRef::new(contract.account_id);
+            let forwarder = client
+                .instantiate("forwarder", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("forwarder instantiate failed");
+            let mut forwarder_call_builder =
+                forwarder.call_builder::<forwarder::Forwarder>();
+
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+
+            // when
+            let set_admin = forwarder_call_builder.set_admin(bob);
+            let rejected = client
+                .call(&ink_e2e::alice(), &set_admin)
+                .submit()
+                .await
+                .expect("Calling `set_admin` through the forwarder failed")
+                .return_value();
+
+            // then
+            assert!(rejected, "the call from a contract must be rejected");
+            let admin = client
+                .call(&ink_e2e::alice(), &call_builder.admin())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(admin, ink_e2e::account_id(ink_e2e::AccountKeyring::Alice));
+
+            // when
+            let set_admin = call_builder.set_admin(charlie);
+            client
+                .call(&ink_e2e::alice(), &set_admin)
+                .submit()
+                .await
+                .expect("Calling `set_admin` directly failed");
+
+            // then
+            let admin = client
+                .call(&ink_e2e::alice(), &call_builder.admin())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(admin, charlie);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,80 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod min_value {
+    /// The minimum endowment required to instantiate the contract.
+    pub const MIN_VALUE: Balance = 1_000_000;
+
+    #[ink(storage)]
+    pub struct MinValue {
+        endowment: Balance,
+    }
+
+    impl MinValue {
+        /// Creates the contract, which requires an endowment of at least
+        /// [`MIN_VALUE`].
+        #[ink(constructor, min_value = 1_000_000)]
+        pub fn new() -> Self {
+            Self {
+                endowment: Self::env().transferred_value(),
+            }
+        }
+
+        /// Returns the endowment transferred on instantiation.
+        #[ink(message)]
+        pub fn endowment(&self) -> Balance {
+            self.endowment
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn instantiate_below_min_value_fails<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // when
+            let mut constructor = MinValueRef::new();
+            let result = client
+                .instantiate("min-value", &ink_e2e::alice(), &mut constructor)
+                .value(MIN_VALUE - 1)
+                .submit()
+                .await;
+
+            // then
+            assert!(result.is_err(), "instantiation below the minimum must fail");
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn instantiate_at_min_value_works<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // when
+            let mut constructor = MinValueRef::new();
+            let contract = client
+                .instantiate("min-value", &ink_e2e::alice(), &mut constructor)
+                .value(MIN_VALUE)
+                .submit()
+                .await
+                .expect("instantiate failed");
+
+            // then
+            let call_builder = contract.call_builder::<MinValue>();
+            let endowment = client
+                .call(&ink_e2e::alice(), &call_builder.endowment())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(endowment, MIN_VALUE);
+
+            Ok(())
+        }
+    }
+}
@@ -182,12 +182,63 @@ mod mother {
             }
         }
 
+        /// Appends the given message to the log, then reverts.
+        ///
+        /// The log is left unchanged, since all storage changes of a reverted call
+        /// are rolled back.
+        #[ink(message)]
+        pub fn log_and_revert(&mut self, message: String) -> Result<(), Failure> {
+            self.log.push(&message);
+            Err(Failure::Revert("Reverting after logging!".to_string()))
+        }
+
         /// Prints the specified string into node's debug log.
         #[ink(message)]
         pub fn debug_log(&mut self, _message: String) {
             ink::env::debug_println!("debug_log: {}", _message);
         }
 
+        /// Appends the given message to the log, unless the call is a dry-run.
+        ///
+        /// Returns `true` if the message was appended.
+        #[ink(message)]
+        pub fn log_unless_dry_run(&mut self, message: String) -> bool {
+            if self.env().is_dry_run() {
+                return false
+            }
+            self.log.push(&message);
+            true
+        }
+
+        /// Appends the given message to the log, unless the caller is another contract.
+        ///
+        /// Returns `true` if the message was appended.
+        #[ink(message)]
+        pub fn log_unless_called_by_contract(&mut self, message: String) -> bool {
+            if !self.env().caller_is_origin() {
+                return false
+            }
+            self.log.push(&message);
+            true
+        }
+
+        /// Returns the tip paid by the origin of the transaction.
+        #[ink(message)]
+        pub fn transaction_tip(&self) -> Balance {
+            self.env().transaction_tip()
+        }
+
+        /// Returns `true` if the runtime is known to be at least `spec_version`.
+        ///
+        /// An unknown runtime version, which is always the case on `pallet-contracts`,
+        /// is treated as too old.
+        #[ink(message)]
+        pub fn runtime_at_least(&self, spec_version: u32) -> bool {
+            self.env()
+                .runtime_version()
+                .is_some_and(|version| version.spec_version >= spec_version)
+        }
+
         /// Mutates the input string to return "Hello, { name }"
         #[ink(message)]
         pub fn mut_hello_world(&self, mut message: String) -> String {
@@ -241,6 +292,56 @@ mod mother {
             let _ = contract.revert_or_trap(Some(Failure::Panic));
         }
 
+        #[ink::test]
+        fn log_unless_dry_run_works() {
+            let mut contract = Mother::default();
+
+            ink::env::test::set_dry_run(true);
+            assert!(!contract.log_unless_dry_run("dry".to_string()));
+            assert_eq!(contract.log.len(), 0);
+
+            ink::env::test::set_dry_run(false);
+            assert!(contract.log_unless_dry_run("real".to_string()));
+            assert_eq!(contract.log.len(), 1);
+            assert_eq!(contract.log.get(0), Some("real".to_string()));
+        }
+
+        #[ink::test]
+        fn log_unless_called_by_contract_works() {
+            let mut contract = Mother::default();
+
+            ink::env::test::set_caller_is_origin(false);
+            assert!(!contract.log_unless_called_by_contract("contract".to_string()));
+            assert_eq!(contract.log.len(), 0);
+
+            ink::env::test::set_caller_is_origin(true);
+            assert!(contract.log_unless_called_by_contract("origin".to_string()));
+            assert_eq!(contract.log.len(), 1);
+            assert_eq!(contract.log.get(0), Some("origin".to_string()));
+        }
+
+        #[ink::test]
+        fn transaction_tip_works() {
+            let contract = Mother::default();
+            assert_eq!(contract.transaction_tip(), 0);
+
+            ink::env::test::set_transaction_tip::<ink::env::DefaultEnvironment>(42);
+            assert_eq!(contract.transaction_tip(), 42);
+        }
+
+        #[ink::test]
+        fn runtime_at_least_works() {
+            let contract = Mother::default();
+            assert!(!contract.runtime_at_least(0));
+
+            ink::env::test::set_runtime_version(ink::env::RuntimeVersion {
+                spec_name: "node".to_string(),
+                spec_version: 100,
+            });
+            assert!(contract.runtime_at_least(100));
+            assert!(!contract.runtime_at_least(101));
+        }
+
         #[ink::test]
         fn mut_works() {
             let contract = Mother::default();
@@ -248,4 +349,40 @@ mod mother {
             assert_eq!("Hello, Alice", res)
         }
     }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn reverted_call_leaves_storage_unchanged(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = MotherRef::new_default();
+            let contract = client
+                .instantiate("mother", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<Mother>();
+
+            // when
+            let log_and_revert = call_builder.log_and_revert("reverted".to_string());
+
+            // then
+            client
+                .assert_no_state_change_on_revert(
+                    "mother",
+                    &ink_e2e::alice(),
+                    &log_and_revert,
+                )
+                .await?;
+
+            Ok(())
+        }
+    }
 }
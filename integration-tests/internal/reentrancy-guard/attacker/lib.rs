@@ -0,0 +1,45 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::attacker::{
+    Attacker,
+    AttackerRef,
+};
+
+/// A contract which tries to re-enter the contract that calls it.
+#[ink::contract]
+mod attacker {
+    use ink::env::call::{
+        build_call,
+        ExecutionInput,
+        Selector,
+    };
+
+    #[ink(storage)]
+    pub struct Attacker {
+        target: AccountId,
+    }
+
+    impl Attacker {
+        #[ink(constructor)]
+        pub fn new(target: AccountId) -> Self {
+            Self { target }
+        }
+
+        /// Tries to re-enter the `call_back` message of the target contract.
+        ///
+        /// Returns `true` if the reentrant call was rejected by the reentrancy guard
+        /// of the target.
+        #[ink(message)]
+        pub fn callback(&self) -> bool {
+            let result = build_call::<Environment>()
+                .call(self.target)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("call_back")))
+                        .push_arg(self.env().account_id()),
+                )
+                .returns::<bool>()
+                .try_invoke();
+            matches!(result, Ok(Err(ink::LangError::ReentrantCall)))
+        }
+    }
+}
@@ -0,0 +1,108 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod reentrancy_guard {
+    use ink::env::{
+        call::{
+            build_call,
+            ExecutionInput,
+            Selector,
+        },
+        CallFlags,
+    };
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct ReentrancyGuard {
+        calls: u32,
+    }
+
+    impl ReentrancyGuard {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Counts the call, then calls the `callback` message of `callee`.
+        ///
+        /// The callee is allowed to re-enter this contract, but not this message.
+        #[ink(message, non_reentrant)]
+        pub fn call_back(&mut self, callee: AccountId) -> bool {
+            self.calls = self.calls.checked_add(1).unwrap();
+            build_call::<Environment>()
+                .call(callee)
+                .call_flags(CallFlags::ALLOW_REENTRY)
+                .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "callback"
+                ))))
+                .returns::<bool>()
+                .invoke()
+        }
+
+        /// Returns the number of calls to `call_back`.
+        #[ink(message)]
+        pub fn calls(&self) -> u32 {
+            self.calls
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use attacker::AttackerRef;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn reentrant_call_is_rejected<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = ReentrancyGuardRef::new();
+            let contract = client
+                .instantiate("reentrancy-guard", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("reentrancy-guard instantiate failed");
+            let mut call_builder = contract.call_builder::<ReentrancyGuard>();
+
+            let mut constructor = AttackerRef::new(contract.account_id);
+            let attacker = client
+                .instantiate("attacker", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("attacker instantiate failed");
+
+            // when
+            let call_back = call_builder.call_back(attacker.account_id);
+            let rejected = client
+                .call(&ink_e2e::alice(), &call_back)
+                .submit()
+                .await
+                .expect("Calling `call_back` failed")
+                .return_value();
+
+            // then
+            assert!(rejected, "the reentrant call must be rejected");
+
+            // the guard is released again once the outer call returned
+            let rejected = client
+                .call(&ink_e2e::alice(), &call_back)
+                .submit()
+                .await
+                .expect("Calling `call_back` a second time failed")
+                .return_value();
+            assert!(rejected, "the reentrant call must be rejected");
+
+            let calls = client
+                .call(&ink_e2e::alice(), &call_builder.calls())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(calls, 2);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,57 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod storage_cfg_fields {
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct StorageCfgFields {
+        value: u32,
+        /// Only part of the storage if the `extra-field` feature is enabled.
+        #[cfg(feature = "extra-field")]
+        extra: u32,
+    }
+
+    impl StorageCfgFields {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            let value = self.value;
+            #[cfg(feature = "extra-field")]
+            let value = value.saturating_add(self.extra);
+            value
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::{
+            metadata::layout::Layout,
+            storage::traits::StorageLayout,
+        };
+
+        /// Run the tests with and without the `extra-field` feature to cover both
+        /// layouts.
+        #[test]
+        fn layout_only_contains_enabled_fields() {
+            let Layout::Struct(layout) = <StorageCfgFields as StorageLayout>::layout(&0)
+            else {
+                panic!("expected the layout of a struct")
+            };
+            let fields = layout
+                .fields()
+                .iter()
+                .map(|field| *field.name())
+                .collect::<Vec<_>>();
+
+            #[cfg(feature = "extra-field")]
+            assert_eq!(fields, ["value", "extra"]);
+            #[cfg(not(feature = "extra-field"))]
+            assert_eq!(fields, ["value"]);
+        }
+    }
+}
@@ -124,6 +124,22 @@ pub mod give_me {
             assert_eq!(contract_new_balance, 10);
         }
 
+        #[ink::test]
+        fn balance_delta_equals_transferred_value() {
+            use ink::codegen::Env;
+            // given
+            let accounts = default_accounts();
+            let give_me = create_contract(100);
+            set_balance(accounts.eve, 100);
+            set_sender(accounts.eve);
+
+            // when
+            ink::env::pay_with_call!(give_me.was_it_ten(), 10);
+
+            // then
+            assert_eq!(give_me.env().balance_delta_since_call_start(), 10);
+        }
+
         #[ink::test]
         #[should_panic(expected = "payment was not ten")]
         fn test_transferred_value_must_fail() {
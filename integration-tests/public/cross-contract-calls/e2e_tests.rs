@@ -177,3 +177,48 @@ async fn flip_and_get_v2<Client: E2EBackend>(mut client: Client) -> E2EResult<()
 
     Ok(())
 }
+
+#[ink_e2e::test]
+async fn get_batched<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+    // given
+    let other_contract_code = client
+        .upload("other-contract", &ink_e2e::alice())
+        .submit()
+        .await
+        .expect("other_contract upload failed");
+
+    let mut constructor = CrossContractCallsRef::new_v1(other_contract_code.code_hash);
+    let contract = client
+        .instantiate("cross-contract-calls", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("cross-contract-calls instantiate failed");
+    let mut call_builder = contract.call_builder::<CrossContractCalls>();
+
+    // when
+    let result = client
+        .call(&ink_e2e::alice(), &call_builder.get_batched())
+        .dry_run()
+        .await?
+        .return_value();
+
+    // then
+    assert_eq!(result, vec![true, true]);
+
+    // when
+    client
+        .call(&ink_e2e::alice(), &call_builder.flip_and_get_v1())
+        .submit()
+        .await
+        .expect("Calling `flip_and_get` failed");
+    let result = client
+        .call(&ink_e2e::alice(), &call_builder.get_batched())
+        .dry_run()
+        .await?
+        .return_value();
+
+    // then
+    assert_eq!(result, vec![false, false]);
+
+    Ok(())
+}
@@ -2,7 +2,10 @@
 
 #[ink::contract]
 mod cross_contract_calls {
-    use ink::codegen::TraitCallBuilder;
+    use ink::{
+        codegen::TraitCallBuilder,
+        prelude::vec::Vec,
+    };
     use other_contract::OtherContractRef;
 
     #[ink(storage)]
@@ -109,6 +112,20 @@ mod cross_contract_calls {
             self.other_contract.flip();
             self.other_contract.get()
         }
+
+        /// Batches two calls of `get` on the other contract and returns both results.
+        ///
+        /// This demonstrates how to combine several calls returning the same type with
+        /// `build_multicall`.
+        #[ink(message)]
+        pub fn get_batched(&self) -> Vec<bool> {
+            let call_builder = self.other_contract.call();
+
+            ink::env::call::build_multicall::<Environment, bool>()
+                .push(call_builder.get().params())
+                .push(call_builder.get().params())
+                .invoke()
+        }
     }
 }
 
@@ -249,12 +249,12 @@ mod erc20 {
             if let Some(from) = expected_from {
                 expected_topics.push(encoded_into_hash(from));
             } else {
-                expected_topics.push(Hash::CLEAR_HASH);
+                expected_topics.push(Hash::from(ink::env::event::NONE_TOPIC));
             }
             if let Some(to) = expected_to {
                 expected_topics.push(encoded_into_hash(to));
             } else {
-                expected_topics.push(Hash::CLEAR_HASH);
+                expected_topics.push(Hash::from(ink::env::event::NONE_TOPIC));
             }
             expected_topics.push(encoded_into_hash(value));
 
@@ -563,6 +563,56 @@ mod erc20 {
             Ok(())
         }
 
+        #[ink_e2e::test]
+        async fn e2e_fuzz_transfer(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // given
+            let total_supply = 1_000_000_000;
+            let mut constructor = Erc20Ref::new(total_supply);
+            let erc20 = client
+                .instantiate("erc20", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = erc20.call_builder::<Erc20>();
+            let alice_account = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob_account = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let mut accounts = vec![alice_account, bob_account];
+
+            // when
+            let errors = client
+                .fuzz(&ink_e2e::alice(), 42, 20, |rng| {
+                    let to = if rng.next_bool() {
+                        *rng.choose(&[alice_account, bob_account])
+                    } else {
+                        AccountId::from(rng.bytes())
+                    };
+                    if !accounts.contains(&to) {
+                        accounts.push(to);
+                    }
+                    // Exceeds the balance of Alice in about half of the transfers.
+                    let value = rng.below(2 * total_supply);
+                    call_builder.transfer(to, value)
+                })
+                .await?;
+
+            // then
+            assert!(!errors.is_empty());
+            assert!(errors
+                .iter()
+                .all(|err| matches!(err, Error::InsufficientBalance)));
+            let mut balances = 0;
+            for account in accounts {
+                balances += client
+                    .call(&ink_e2e::alice(), &call_builder.balance_of(account))
+                    .dry_run()
+                    .await?
+                    .return_value();
+            }
+            assert_eq!(balances, total_supply);
+
+            Ok(())
+        }
+
         #[ink_e2e::test]
         async fn e2e_allowances<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
             // given
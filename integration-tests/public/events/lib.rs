@@ -7,8 +7,30 @@ pub struct AnonymousEvent {
     pub field_1: u32,
 }
 
+#[ink::event(topic_hash = "keccak")]
+pub struct KeccakTopicEvent {
+    #[ink(topic)]
+    pub name: ink::prelude::string::String,
+}
+
+/// A struct whose SCALE encoding is hashed into a single topic.
+#[derive(Clone)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct Position {
+    pub x: u32,
+    pub y: u32,
+}
+
+#[ink::event]
+pub struct CombinedTopicEvent {
+    #[ink(topic(combine))]
+    pub position: Position,
+}
+
 #[ink::contract]
 pub mod events {
+    use ink::prelude::string::String;
+
     #[ink(storage)]
     pub struct Events {
         value: bool,
@@ -27,6 +49,12 @@ pub mod events {
         value: bool,
     }
 
+    #[ink(event)]
+    pub struct InlineOptionAccountTopic {
+        #[ink(topic)]
+        account: Option<AccountId>,
+    }
+
     #[ink(event)]
     #[ink(anonymous)]
     pub struct InlineAnonymousEvent {
@@ -65,6 +93,14 @@ pub mod events {
                 .emit_event(InlineCustomFlipped { value: self.value })
         }
 
+        /// Flips the current value of the boolean twice, emitting an inline event
+        /// followed by a foreign event.
+        #[ink(message)]
+        pub fn flip_with_inline_then_foreign_event(&mut self) {
+            self.flip_with_inline_event();
+            self.flip_with_foreign_event();
+        }
+
         /// Emit an event with a 32 byte topic.
         #[ink(message)]
         pub fn emit_32_byte_topic_event(&self, maybe_hash: Option<[u8; 32]>) {
@@ -83,6 +119,12 @@ pub mod events {
             })
         }
 
+        /// Emit an event with an optional account id topic.
+        #[ink(message)]
+        pub fn emit_option_account_topic_event(&self, account: Option<AccountId>) {
+            self.env().emit_event(InlineOptionAccountTopic { account })
+        }
+
         /// Emit a inline and standalone anonymous events
         #[ink(message)]
         pub fn emit_anonymous_events(&self, topic: [u8; 32]) {
@@ -91,6 +133,19 @@ pub mod events {
             self.env()
                 .emit_event(super::AnonymousEvent { topic, field_1: 42 });
         }
+
+        /// Emit an event with a topic combining all fields of `position`.
+        #[ink(message)]
+        pub fn emit_combined_topic_event(&self, position: super::Position) {
+            self.env()
+                .emit_event(super::CombinedTopicEvent { position });
+        }
+
+        /// Emit an event with a keccak hashed topic.
+        #[ink(message)]
+        pub fn emit_keccak_topic_event(&self, name: String) {
+            self.env().emit_event(super::KeccakTopicEvent { name });
+        }
     }
 
     /// Implementing the trait from the `event_def_unused` crate includes all defined
@@ -110,7 +165,7 @@ pub mod events {
         #[test]
         fn collects_specs_for_all_linked_and_used_events() {
             let event_specs = ink::metadata::collect_events();
-            assert_eq!(8, event_specs.len());
+            assert_eq!(11, event_specs.len());
 
             assert!(event_specs
                 .iter()
@@ -133,6 +188,15 @@ pub mod events {
             assert!(event_specs
                 .iter()
                 .any(|evt| evt.label() == &"InlineAnonymousEvent"));
+            assert!(event_specs
+                .iter()
+                .any(|evt| evt.label() == &"InlineOptionAccountTopic"));
+            assert!(event_specs
+                .iter()
+                .any(|evt| evt.label() == &"KeccakTopicEvent"));
+            assert!(event_specs
+                .iter()
+                .any(|evt| evt.label() == &"CombinedTopicEvent"));
 
             // The event is not used in the code by being included in the metadata
             // because we implement trait form `event_def_unused` crate.
@@ -177,7 +241,7 @@ pub mod events {
         }
 
         #[ink::test]
-        fn option_topic_none_encoded_as_0() {
+        fn option_topic_none_encoded_as_sentinel() {
             let events = Events::new(false);
             events.emit_32_byte_topic_event(None);
 
@@ -193,11 +257,50 @@ pub mod events {
             let expected_topics = vec![
                 signature_topic,
                 [0x42; 32].to_vec(),
-                [0x00; 32].to_vec(), // None is encoded as 0x00
+                ink::env::event::NONE_TOPIC.to_vec(),
             ];
             assert_eq!(expected_topics, event.topics);
         }
 
+        #[ink::test]
+        fn option_account_id_topic_some_encoded_as_account_id() {
+            let events = Events::new(false);
+            let account = AccountId::from([0x00; 32]);
+            events.emit_option_account_topic_event(Some(account));
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(1, emitted_events.len());
+            let event = &emitted_events[0];
+
+            let signature_topic =
+                <InlineOptionAccountTopic as ink::env::Event>::SIGNATURE_TOPIC
+                    .map(|topic| topic.to_vec())
+                    .unwrap();
+
+            let expected_topics = vec![signature_topic, [0x00; 32].to_vec()];
+            assert_eq!(expected_topics, event.topics);
+        }
+
+        #[ink::test]
+        fn option_account_id_topic_none_encoded_as_sentinel() {
+            let events = Events::new(false);
+            events.emit_option_account_topic_event(None);
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(1, emitted_events.len());
+            let event = &emitted_events[0];
+
+            let signature_topic =
+                <InlineOptionAccountTopic as ink::env::Event>::SIGNATURE_TOPIC
+                    .map(|topic| topic.to_vec())
+                    .unwrap();
+
+            let expected_topics =
+                vec![signature_topic, ink::env::event::NONE_TOPIC.to_vec()];
+            assert_eq!(expected_topics, event.topics);
+            assert_ne!(event.topics[1], [0x00; 32].to_vec());
+        }
+
         #[ink::test]
         fn custom_signature_topic() {
             let mut events = Events::new(false);
@@ -233,6 +336,48 @@ pub mod events {
                 <InlineAnonymousEvent as ink::env::Event>::SIGNATURE_TOPIC;
             assert_eq!(None, signature_topic);
         }
+
+        #[ink::test]
+        fn keccak_topic_hash() {
+            let events = Events::new(false);
+            // Topic values are hashed even if their encoding fits into a topic.
+            let names = ["ink", "a name which does not fit into a single topic"];
+            for name in names {
+                events.emit_keccak_topic_event(String::from(name));
+            }
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(names.len(), emitted_events.len());
+            for (name, event) in names.iter().zip(&emitted_events) {
+                let mut expected_topic = [0u8; 32];
+                ink::env::hash_bytes::<ink::env::hash::Keccak256>(
+                    name.as_bytes(),
+                    &mut expected_topic,
+                );
+                assert_eq!(event.topics.len(), 2);
+                assert_eq!(event.topics[1], expected_topic);
+            }
+        }
+
+        #[ink::test]
+        fn combined_topic_hashes_encoding() {
+            let events = Events::new(false);
+            let position = crate::Position { x: 1, y: 2 };
+            events.emit_combined_topic_event(position.clone());
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(1, emitted_events.len());
+            let event = &emitted_events[0];
+
+            // The encoding of `position` fits into a topic, but is hashed nevertheless.
+            let mut expected_topic = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(
+                &ink::scale::Encode::encode(&position),
+                &mut expected_topic,
+            );
+            assert_eq!(event.topics.len(), 2);
+            assert_eq!(event.topics[1], expected_topic);
+        }
     }
 
     #[cfg(all(test, feature = "e2e-tests"))]
@@ -330,6 +475,82 @@ pub mod events {
             Ok(())
         }
 
+        #[ink_e2e::test]
+        async fn emits_events_in_order<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = EventsRef::new(false);
+            let contract = client
+                .instantiate("events", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<Events>();
+
+            // when
+            let flip = call_builder.flip_with_inline_then_foreign_event();
+            let flip_res = client
+                .call(&ink_e2e::bob(), &flip)
+                .submit()
+                .await
+                .expect("flip failed");
+
+            // then
+            flip_res.assert_events_in_order(&[
+                &|event| event.is::<InlineFlipped>(),
+                &|event| event.is::<event_def::ForeignFlipped>(),
+            ]);
+
+            let wrong_order =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    flip_res.assert_events_in_order(&[
+                        &|event| event.is::<event_def::ForeignFlipped>(),
+                        &|event| event.is::<InlineFlipped>(),
+                    ])
+                }));
+            assert!(wrong_order.is_err(), "events in wrong order must not match");
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn assert_event_emitted_finds_non_last_event<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = EventsRef::new(false);
+            let contract = client
+                .instantiate("events", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<Events>();
+
+            // when
+            let flip = call_builder.flip_with_inline_then_foreign_event();
+            let flip_res = client
+                .call(&ink_e2e::bob(), &flip)
+                .submit()
+                .await
+                .expect("flip failed");
+
+            // then
+            let inline_flipped = flip_res.assert_event_emitted::<InlineFlipped>();
+            assert!(inline_flipped.value);
+
+            let not_emitted =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    flip_res.assert_event_emitted::<InlineCustomFlipped>()
+                }));
+            assert!(
+                not_emitted.is_err(),
+                "event that was not emitted must not match"
+            );
+
+            Ok(())
+        }
+
         #[ink_e2e::test]
         async fn emits_event_with_option_topic_none<Client: E2EBackend>(
             mut client: Client,
@@ -370,7 +591,7 @@ pub mod events {
             let expected_topics = vec![
                 signature_topic,
                 [0x42; 32].into(),
-                [0x00; 32].into(), // None is encoded as 0x00
+                ink::env::event::NONE_TOPIC.into(),
             ];
             assert_eq!(expected_topics, contract_event.topics);
 
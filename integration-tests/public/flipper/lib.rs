@@ -50,6 +50,22 @@ pub mod flipper {
             flipper.flip();
             assert!(flipper.get());
         }
+
+        #[test]
+        fn messages_table_works() {
+            use ink::reflect::ContractMessages;
+
+            let selectors: Vec<_> = Flipper::__ink_messages()
+                .iter()
+                .map(|message| message.selector)
+                .collect();
+            assert_eq!(
+                selectors,
+                vec![ink::selector_bytes!("flip"), ink::selector_bytes!("get")]
+            );
+            assert!(Flipper::MESSAGES[0].mutates);
+            assert!(!Flipper::MESSAGES[1].mutates);
+        }
     }
 
     #[cfg(all(test, feature = "e2e-tests"))]
@@ -90,6 +106,101 @@ pub mod flipper {
             Ok(())
         }
 
+        #[ink_e2e::test]
+        async fn predicted_address_matches_deployed_one<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = FlipperRef::new(false);
+            let mut instantiate =
+                client.instantiate("flipper", &ink_e2e::alice(), &mut constructor);
+            let predicted = instantiate.predict_address();
+
+            // when
+            let contract = instantiate.submit().await.expect("instantiate failed");
+
+            // then
+            assert_eq!(contract.account_id, predicted);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn dry_run_matches_submit<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = FlipperRef::new(false);
+            let contract = client
+                .instantiate("flipper", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<Flipper>();
+
+            // when
+            // `flip` only depends on the contract storage, hence executes the same
+            // in the dry-run and when submitted.
+            let flip = call_builder.flip();
+            client
+                .call(&ink_e2e::bob(), &flip)
+                .assert_dry_run_matches_submit()
+                .await
+                .expect("flip failed");
+
+            // then
+            let get = call_builder.get();
+            let get_res = client
+                .call(&ink_e2e::bob(), &get)
+                .assert_dry_run_matches_submit()
+                .await
+                .expect("get failed");
+            assert!(get_res.return_value());
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn dry_run_at_queries_historical_state(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = FlipperRef::new(false);
+            let contract = client
+                .instantiate("flipper", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let instantiated_at = contract.events.block_hash();
+            let mut call_builder = contract.call_builder::<Flipper>();
+
+            // when
+            let flip = call_builder.flip();
+            let flip_res = client
+                .call(&ink_e2e::bob(), &flip)
+                .submit()
+                .await
+                .expect("flip failed");
+            let flipped_at = flip_res.events.block_hash();
+
+            // then
+            let get = call_builder.get();
+            let get_res = client.call(&ink_e2e::bob(), &get).dry_run().await?;
+            assert!(get_res.return_value());
+
+            let old_get_res = client
+                .call_dry_run_at(instantiated_at, &ink_e2e::bob(), &get, 0, None)
+                .await?;
+            assert!(!old_get_res.return_value());
+
+            let new_get_res = client
+                .call_dry_run_at(flipped_at, &ink_e2e::bob(), &get, 0, None)
+                .await?;
+            assert!(new_get_res.return_value());
+
+            Ok(())
+        }
+
         #[ink_e2e::test]
         async fn default_works<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
             // given
@@ -829,6 +829,17 @@ mod multisig {
             assert_eq!(contract.transaction_list.transactions.len(), 0);
         }
 
+        #[ink::test(cases = [(1, 1), (2, 1), (3, 2), (MAX_OWNERS, MAX_OWNERS)])]
+        fn valid_requirement_is_accepted(owners: u32, requirement: u32) {
+            ensure_requirement_is_valid(owners, requirement);
+        }
+
+        #[ink::test(cases = [(0, 0), (1, 0), (1, 2), (MAX_OWNERS + 1, 1)])]
+        #[should_panic]
+        fn invalid_requirement_is_rejected(owners: u32, requirement: u32) {
+            ensure_requirement_is_valid(owners, requirement);
+        }
+
         #[ink::test]
         #[should_panic]
         fn empty_owner_construction_fails() {
@@ -315,12 +315,12 @@ mod erc20 {
             if let Some(from) = expected_from {
                 expected_topics.push(encoded_into_hash(from));
             } else {
-                expected_topics.push(Hash::CLEAR_HASH);
+                expected_topics.push(Hash::from(ink::env::event::NONE_TOPIC));
             }
             if let Some(to) = expected_to {
                 expected_topics.push(encoded_into_hash(to));
             } else {
-                expected_topics.push(Hash::CLEAR_HASH);
+                expected_topics.push(Hash::from(ink::env::event::NONE_TOPIC));
             }
             expected_topics.push(encoded_into_hash(value));
 
@@ -546,4 +546,50 @@ mod erc20 {
             ink::env::test::set_caller::<Environment>(sender);
         }
     }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        /// Calls the trait messages through the typed call builder of the contract.
+        #[ink_e2e::test]
+        async fn e2e_trait_transfer<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let total_supply = 1_000_000_000;
+            let mut constructor = Erc20Ref::new(total_supply);
+            let erc20 = client
+                .instantiate("trait_erc20", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = erc20.call_builder::<Erc20>();
+
+            // when
+            let bob_account = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let transfer_to_bob = 500_000_000u128;
+            let transfer = call_builder.transfer(bob_account, transfer_to_bob);
+            let transfer_res = client
+                .call(&ink_e2e::alice(), &transfer)
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let balance_of = call_builder.balance_of(bob_account);
+            let balance_of_res = client
+                .call(&ink_e2e::alice(), &balance_of)
+                .dry_run()
+                .await?;
+
+            // then
+            assert_eq!(transfer_res.return_value(), Ok(()));
+            assert_eq!(transfer_to_bob, balance_of_res.return_value(), "balance_of");
+
+            Ok(())
+        }
+    }
 }
@@ -134,5 +134,41 @@ pub mod incrementer {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn set_code_changes_code_hash(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            // Given
+            let mut constructor = IncrementerRef::new();
+            let contract = client
+                .instantiate("incrementer", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<Incrementer>();
+
+            let new_code_hash = client
+                .upload("updated_incrementer", &ink_e2e::alice())
+                .submit()
+                .await
+                .expect("uploading `updated_incrementer` failed")
+                .code_hash;
+
+            // When
+            let set_code = call_builder.set_code(new_code_hash);
+            let _set_code_result = client
+                .call(&ink_e2e::alice(), &set_code)
+                .submit()
+                .await
+                .expect("`set_code` failed");
+
+            // Then
+            client
+                .assert_code_hash(&contract.account_id, new_code_hash)
+                .await;
+
+            Ok(())
+        }
     }
 }